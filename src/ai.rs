@@ -1,14 +1,64 @@
 use anyhow::{anyhow, Result};
 use async_openai::{
-    types::{ChatCompletionRequestMessage, CreateChatCompletionRequestArgs},
+    error::OpenAIError,
+    types::{
+        ChatCompletionRequestAssistantMessageArgs, ChatCompletionRequestMessage,
+        CreateChatCompletionRequestArgs, CreateChatCompletionResponse, FinishReason,
+    },
     Client,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::env;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
 
+use crate::config::{AiSettings, Config, Platform};
 use crate::review::Review;
+use crate::text;
 
+// Built-in model, also used for the rest of the session when the configured
+// one isn't available to the API key
+const DEFAULT_MODEL: &str = "gpt-4.1-nano";
+const MAX_TOKENS: u32 = 500;
+// Room on top of the character limit's token count, since languages other than
+// English often need more than one token per four characters
+const LIMIT_TOKEN_MARGIN: u32 = 64;
+// Budget for the retry when a response is cut off at MAX_TOKENS. Budgets set
+// with --ai-max-tokens or from a character limit are kept for the retry.
+const RETRY_MAX_TOKENS: u32 = 1500;
+// Smallest budget that fits a complete short reply
+const MIN_USEFUL_TOKENS: u32 = 40;
+// Typical length of a drafted reply, for estimates. Most stay well under the budget.
+const ESTIMATED_OUTPUT_TOKENS: u64 = 120;
+
+// USD per million input and output tokens, only used for cost estimates.
+// Prices change, so these are a rough guide rather than a bill.
+const MODEL_PRICING: &[(&str, f64, f64)] = &[
+    ("gpt-4.1-nano", 0.10, 0.40),
+    ("gpt-4.1-mini", 0.40, 1.60),
+    ("gpt-4.1", 2.00, 8.00),
+    ("gpt-4o-mini", 0.15, 0.60),
+    ("gpt-4o", 2.50, 10.00),
+];
+
+// Approximate cost in USD, or None for models missing from MODEL_PRICING.
+// Dated snapshots like "gpt-4o-mini-2024-07-18" use their base model's price.
+pub fn estimated_cost(model: &str, input_tokens: u64, output_tokens: u64) -> Option<f64> {
+    MODEL_PRICING
+        .iter()
+        .filter(|(name, _, _)| model.starts_with(name))
+        .max_by_key(|(name, _, _)| name.len())
+        .map(|(_, input_price, output_price)| {
+            (input_tokens as f64 * input_price + output_tokens as f64 * output_price) / 1_000_000.0
+        })
+}
+
+// Fields missing from an AI config file fall back to the defaults below
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AIConfig {
     pub openai_api_key: String,
     pub model: String,
@@ -16,24 +66,264 @@ pub struct AIConfig {
     pub support_email: String,
     pub custom_prompt: Option<String>,
     pub supporting_info: Option<String>,
+    // Extra instructions merged into the prompt for reviews with a given star rating
+    pub rating_prompts: HashMap<i32, String>,
+    pub min_response_chars: Option<usize>,
+    // Replies used instead of the AI when it's disabled, by star rating.
+    // `{reviewer}`, `{title}` and `{support_email}` are filled in.
+    pub fallback_responses: HashMap<i32, String>,
+    // Pre-approved replies offered by 'k', keyed by a rating ("1") or an
+    // inclusive range ("1-2"). Placeholders are filled like fallback_responses.
+    pub approved_responses: HashMap<String, Vec<String>>,
+    // Per-territory replacements keyed by the review's territory code, e.g. "DEU"
+    pub territory_overrides: HashMap<String, TerritoryOverride>,
+    // Token budget for each reply. Unset, it follows the platform's character limit.
+    pub max_tokens: Option<u32>,
+    // Whether prompts ask the AI to point reviewers at the support email and
+    // include `custom_prompt`, e.g. a Reddit plug
+    pub promotions: bool,
+    // Reviews rated below this never get the promotional lines
+    pub promotion_min_rating: Option<i32>,
+    // The store replies are written for when a review doesn't record one
+    #[serde(skip)]
+    pub platform: Option<Platform>,
+}
+
+// Fields left out use the global value
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TerritoryOverride {
+    pub support_email: Option<String>,
+    pub custom_prompt: Option<String>,
 }
 
 impl Default for AIConfig {
     fn default() -> Self {
         Self {
             openai_api_key: env::var("OPENAI_API_KEY").unwrap_or_default(),
-            model: "gpt-4.1-nano".to_string(),
+            model: DEFAULT_MODEL.to_string(),
             keywords: vec!["relationships".to_string(), "couples".to_string(), "love".to_string(), "partner".to_string(), "connection".to_string()],
             support_email: "candleappteam@gmail.com".to_string(),
             custom_prompt: Some("Try to encourage users to join our Reddit at r/candleapp when possible.".to_string()),
             supporting_info: None,
+            rating_prompts: HashMap::new(),
+            min_response_chars: None,
+            fallback_responses: HashMap::new(),
+            approved_responses: HashMap::new(),
+            territory_overrides: HashMap::new(),
+            max_tokens: None,
+            promotions: true,
+            promotion_min_rating: None,
+            platform: None,
         }
     }
 }
 
+impl AIConfig {
+    pub fn fallback_response(&self, review: &Review) -> Option<String> {
+        let template = self.fallback_responses.get(&review.rating)?;
+        Some(self.fill_placeholders(template, review))
+    }
+
+    // Every approved reply whose rating range covers the review, lowest range first
+    pub fn approved_responses_for(&self, review: &Review) -> Vec<String> {
+        let mut ranges: Vec<_> = self
+            .approved_responses
+            .iter()
+            .filter_map(|(key, responses)| Some((parse_rating_range(key)?, responses)))
+            .filter(|((low, high), _)| (*low..=*high).contains(&review.rating))
+            .collect();
+        ranges.sort_by_key(|(range, _)| *range);
+        ranges
+            .into_iter()
+            .flat_map(|(_, responses)| responses)
+            .map(|template| self.fill_placeholders(template, review))
+            .collect()
+    }
+
+    fn fill_placeholders(&self, template: &str, review: &Review) -> String {
+        template
+            .replace("{reviewer}", &review.reviewer_nickname)
+            .replace("{title}", review.title.as_deref().unwrap_or(""))
+            .replace("{support_email}", self.support_email_for(review))
+    }
+
+    // Territory codes are matched case-insensitively
+    fn territory_override(&self, review: &Review) -> Option<&TerritoryOverride> {
+        self.territory_overrides
+            .iter()
+            .find(|(territory, _)| territory.eq_ignore_ascii_case(review.territory.trim()))
+            .map(|(_, territory_override)| territory_override)
+    }
+
+    pub fn support_email_for(&self, review: &Review) -> &str {
+        self.territory_override(review)
+            .and_then(|territory_override| territory_override.support_email.as_deref())
+            .unwrap_or(&self.support_email)
+    }
+
+    // Google Play's 350 character limit needs far fewer than MAX_TOKENS, and a
+    // smaller budget steers the model towards a reply that fits
+    pub fn max_tokens_for(&self, review: &Review) -> u32 {
+        if let Some(max_tokens) = self.max_tokens {
+            return max_tokens;
+        }
+        match review
+            .platform
+            .or(self.platform)
+            .and_then(|p| p.character_limit())
+        {
+            Some(limit) => (limit as u32).div_ceil(4) + LIMIT_TOKEN_MARGIN,
+            None => MAX_TOKENS,
+        }
+    }
+
+    // Only the built-in MAX_TOKENS grows for a retry. A configured budget or
+    // one sized to the store's limit would just allow a reply that can't be
+    // submitted.
+    pub fn retry_max_tokens_for(&self, review: &Review) -> u32 {
+        let max_tokens = self.max_tokens_for(review);
+        if max_tokens == MAX_TOKENS && self.max_tokens.is_none() {
+            RETRY_MAX_TOKENS
+        } else {
+            max_tokens
+        }
+    }
+
+    // Settings that keep the AI from drafting a reply the store will take, each
+    // with a suggested fix. The checks are rough, at about four characters per token.
+    pub fn limit_conflicts(&self) -> Vec<String> {
+        let limit = self.platform.and_then(|p| p.character_limit());
+        let mut conflicts = Vec::new();
+
+        if let (Some(min), Some(limit)) = (self.min_response_chars, limit) {
+            if min > limit {
+                conflicts.push(format!(
+                    "The minimum response length of {} characters is over the store's {} character limit, so no reply can be submitted. Lower --min-response-chars to at most {}.",
+                    min, limit, limit
+                ));
+            }
+        }
+
+        if let Some(max_tokens) = self.max_tokens {
+            let min_tokens = self.min_response_chars.map_or(MIN_USEFUL_TOKENS, |min| {
+                (min as u32).div_ceil(4).max(MIN_USEFUL_TOKENS)
+            });
+            if max_tokens < min_tokens {
+                conflicts.push(format!(
+                    "An AI budget of {} tokens is too small for a complete reply, so drafts will be cut off and retried. Raise --ai-max-tokens to at least {} or leave it unset.",
+                    max_tokens, min_tokens
+                ));
+            } else if let Some(limit) = limit {
+                let limit_tokens = (limit as u32).div_ceil(4) + LIMIT_TOKEN_MARGIN;
+                if max_tokens > limit_tokens * 2 {
+                    conflicts.push(format!(
+                        "An AI budget of {} tokens allows replies several times over the store's {} character limit, so drafts will often need shortening. Leave --ai-max-tokens unset or lower it to about {}.",
+                        max_tokens, limit, limit_tokens
+                    ));
+                }
+            }
+        }
+
+        conflicts
+    }
+
+    pub fn promotes_to(&self, review: &Review) -> bool {
+        self.promotions
+            && self
+                .promotion_min_rating
+                .is_none_or(|min_rating| review.rating >= min_rating)
+    }
+
+    pub fn custom_prompt_for(&self, review: &Review) -> Option<&str> {
+        self.territory_override(review)
+            .and_then(|territory_override| territory_override.custom_prompt.as_deref())
+            .or(self.custom_prompt.as_deref())
+    }
+
+    // AI settings come from the optional AI config file, with command line and
+    // environment overrides applied on top
+    pub fn for_config(config: &Config) -> Result<Self> {
+        let mut ai_config = match &config.ai_config_path {
+            Some(path) => Self::from_file(path)?,
+            None => Self::default().with_settings(&config.ai_settings),
+        };
+
+        if config.min_response_chars.is_some() {
+            ai_config.min_response_chars = config.min_response_chars;
+        }
+        if let Some(api_key) = &config.openai_api_key {
+            ai_config.openai_api_key = api_key.clone();
+        }
+        if let Some(model) = &config.ai_model {
+            ai_config.model = model.clone();
+        }
+        if config.ai_max_tokens.is_some() {
+            ai_config.max_tokens = config.ai_max_tokens;
+        }
+        if config.no_promotions {
+            ai_config.promotions = false;
+        }
+        if config.promotion_min_rating.is_some() {
+            ai_config.promotion_min_rating = config.promotion_min_rating;
+        }
+        ai_config.platform = Some(config.platform);
+
+        Ok(ai_config)
+    }
+
+    // The [ai] section of rustpond.toml
+    fn with_settings(mut self, settings: &AiSettings) -> Self {
+        if let Some(model) = &settings.model {
+            self.model = model.clone();
+        }
+        if let Some(keywords) = &settings.keywords {
+            self.keywords = keywords.clone();
+        }
+        if let Some(support_email) = &settings.support_email {
+            self.support_email = support_email.clone();
+        }
+        self
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read AI config {}: {}", path.display(), e))?;
+        let ai_config: Self = serde_json::from_str(&content)
+            .map_err(|e| anyhow!("Failed to parse AI config {}: {}", path.display(), e))?;
+
+        if let Some(key) = ai_config
+            .approved_responses
+            .keys()
+            .find(|key| parse_rating_range(key).is_none())
+        {
+            return Err(anyhow!(
+                "Invalid approved_responses rating '{}' in {}. Use a rating like \"1\" or a range like \"1-2\"",
+                key,
+                path.display()
+            ));
+        }
+
+        Ok(ai_config)
+    }
+}
+
+// "3" is just 3 stars and "1-2" covers both ends
+fn parse_rating_range(key: &str) -> Option<(i32, i32)> {
+    let (low, high) = key.split_once('-').unwrap_or((key, key));
+    let low: i32 = low.trim().parse().ok()?;
+    let high: i32 = high.trim().parse().ok()?;
+    (1 <= low && low <= high && high <= 5).then_some((low, high))
+}
+
 pub struct AIResponseGenerator {
     client: Client<async_openai::config::OpenAIConfig>,
     config: AIConfig,
+    // Why the configured model was dropped for DEFAULT_MODEL, once that happens
+    model_fallback: OnceLock<String>,
+    // Tokens reported by OpenAI across every request so far
+    input_tokens_used: AtomicU64,
+    output_tokens_used: AtomicU64,
 }
 
 impl AIResponseGenerator {
@@ -46,53 +336,246 @@ impl AIResponseGenerator {
             async_openai::config::OpenAIConfig::new().with_api_key(&config.openai_api_key)
         );
 
-        Ok(Self { client, config })
+        Ok(Self {
+            client,
+            config,
+            model_fallback: OnceLock::new(),
+            input_tokens_used: AtomicU64::new(0),
+            output_tokens_used: AtomicU64::new(0),
+        })
+    }
+
+    // Rough input and output tokens for drafting a reply to each review, at
+    // about four characters per token
+    pub fn estimate_tokens(&self, reviews: &[Review], reply_language: Option<&str>) -> (u64, u64) {
+        let input_chars: usize = reviews
+            .iter()
+            .map(|review| {
+                self.build_system_prompt(review).chars().count()
+                    + self
+                        .build_user_prompt(review, reply_language)
+                        .chars()
+                        .count()
+            })
+            .sum();
+        let output_tokens: u64 = reviews
+            .iter()
+            .map(|review| ESTIMATED_OUTPUT_TOKENS.min(self.config.max_tokens_for(review) as u64))
+            .sum();
+        (input_chars.div_ceil(4) as u64, output_tokens)
+    }
+
+    // Input and output tokens OpenAI reported so far
+    pub fn tokens_used(&self) -> (u64, u64) {
+        (
+            self.input_tokens_used.load(Ordering::Relaxed),
+            self.output_tokens_used.load(Ordering::Relaxed),
+        )
+    }
+
+    // Explains the switch to the default model, if the configured one was unavailable
+    pub fn model_fallback(&self) -> Option<&str> {
+        self.model_fallback.get().map(String::as_str)
+    }
+
+    pub fn model(&self) -> &str {
+        match self.model_fallback.get() {
+            Some(_) => DEFAULT_MODEL,
+            None => &self.config.model,
+        }
+    }
+
+    // `reply_language` asks for a reply in a specific language, e.g. the reviewer's
+    // original language when the review text is a Google Play translation
+    pub async fn generate_response(
+        &self,
+        review: &Review,
+        reply_language: Option<&str>,
+    ) -> Result<String> {
+        let messages = self.base_messages(review, reply_language);
+        self.complete(messages, review).await
+    }
+
+    // Asks the model to rewrite its previous reply so it fits within `limit` characters
+    pub async fn shorten_response(
+        &self,
+        review: &Review,
+        reply_language: Option<&str>,
+        previous: &str,
+        limit: usize,
+    ) -> Result<String> {
+        let instruction = format!(
+            "That response is {} characters long. Rewrite it in at most {} characters, keeping the same tone and language.",
+            previous.chars().count(),
+            limit
+        );
+        self.rewrite_response(review, reply_language, previous, &instruction)
+            .await
+    }
+
+    // Transforms `previous` (which may have been edited by hand) following `instruction`
+    pub async fn rewrite_response(
+        &self,
+        review: &Review,
+        reply_language: Option<&str>,
+        previous: &str,
+        instruction: &str,
+    ) -> Result<String> {
+        let mut messages = self.base_messages(review, reply_language);
+        messages.push(ChatCompletionRequestMessage::Assistant(
+            ChatCompletionRequestAssistantMessageArgs::default()
+                .content(previous)
+                .build()?,
+        ));
+        messages.push(ChatCompletionRequestMessage::User(
+            async_openai::types::ChatCompletionRequestUserMessage {
+                content: format!("{} Reply with only the response text.", instruction).into(),
+                name: None,
+            },
+        ));
+        self.complete(messages, review).await
     }
 
-    pub async fn generate_response(&self, review: &Review) -> Result<String> {
-        let system_prompt = self.build_system_prompt();
-        let user_prompt = self.build_user_prompt(review);
+    fn base_messages(
+        &self,
+        review: &Review,
+        reply_language: Option<&str>,
+    ) -> Vec<ChatCompletionRequestMessage> {
+        let system_prompt = self.build_system_prompt(review);
+        let user_prompt = self.build_user_prompt(review, reply_language);
 
-        let request = CreateChatCompletionRequestArgs::default()
-            .model(&self.config.model)
-            .messages([
-                ChatCompletionRequestMessage::System(async_openai::types::ChatCompletionRequestSystemMessage {
+        vec![
+            ChatCompletionRequestMessage::System(
+                async_openai::types::ChatCompletionRequestSystemMessage {
                     content: system_prompt.into(),
                     name: None,
-                }),
-                ChatCompletionRequestMessage::User(async_openai::types::ChatCompletionRequestUserMessage {
+                },
+            ),
+            ChatCompletionRequestMessage::User(
+                async_openai::types::ChatCompletionRequestUserMessage {
                     content: user_prompt.into(),
                     name: None,
-                }),
-            ])
-            .max_tokens(500u32)
-            .temperature(0.7)
-            .build()?;
+                },
+            ),
+        ]
+    }
 
-        let response = self.client.chat().create(request).await?;
+    async fn complete(
+        &self,
+        messages: Vec<ChatCompletionRequestMessage>,
+        review: &Review,
+    ) -> Result<String> {
+        let mut retries = Retries::new(
+            self.config.max_tokens_for(review),
+            self.config.retry_max_tokens_for(review),
+        );
+        loop {
+            let response = self.create(&messages, retries.max_tokens).await?;
+
+            let choice = response
+                .choices
+                .first()
+                .ok_or_else(|| anyhow!("No response content from OpenAI"))?;
+            let content = choice
+                .message
+                .content
+                .as_deref()
+                .map(text::strip_ai_wrapping)
+                .unwrap_or_default();
 
-        let content = response
-            .choices
-            .first()
-            .and_then(|choice| choice.message.content.as_ref())
-            .ok_or_else(|| anyhow!("No response content from OpenAI"))?;
+            if content.trim().is_empty() {
+                use std::io::Write;
+                if let Ok(mut log_file) = std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open("debug.log")
+                {
+                    writeln!(
+                        log_file,
+                        "WARNING: OpenAI returned an empty response (finish reason {:?}){}",
+                        choice.finish_reason,
+                        if retries.retried_empty {
+                            ""
+                        } else {
+                            ", retrying"
+                        }
+                    )
+                    .ok();
+                }
+            }
 
-        Ok(content.clone())
+            if let Some(content) = retries.check(content, choice.finish_reason)? {
+                return Ok(content);
+            }
+        }
     }
 
-    fn build_system_prompt(&self) -> String {
+    async fn create(
+        &self,
+        messages: &[ChatCompletionRequestMessage],
+        max_tokens: u32,
+    ) -> Result<CreateChatCompletionResponse> {
+        loop {
+            let model = self.model();
+            let request = CreateChatCompletionRequestArgs::default()
+                .model(model)
+                .messages(messages.to_vec())
+                .max_tokens(max_tokens)
+                .temperature(0.7)
+                .build()?;
+
+            match self.client.chat().create(request).await {
+                Ok(response) => {
+                    if let Some(usage) = &response.usage {
+                        self.input_tokens_used
+                            .fetch_add(usage.prompt_tokens as u64, Ordering::Relaxed);
+                        self.output_tokens_used
+                            .fetch_add(usage.completion_tokens as u64, Ordering::Relaxed);
+                    }
+                    return Ok(response);
+                }
+                Err(e) if model_unavailable(&e) => {
+                    let message = format!(
+                        "The AI model '{}' isn't available to your OpenAI API key. It may have been retired or need extra access. Set --ai-model or \"model\" in the AI config to one your key can use, such as gpt-4.1-nano, gpt-4.1-mini or gpt-4o-mini",
+                        model
+                    );
+                    // Nothing better to fall back to
+                    if model == DEFAULT_MODEL {
+                        return Err(anyhow!("{}", message));
+                    }
+                    self.model_fallback
+                        .set(format!(
+                            "{}. Using {} for the rest of this session.",
+                            message, DEFAULT_MODEL
+                        ))
+                        .ok();
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    fn build_system_prompt(&self, review: &Review) -> String {
         let keywords_text = if !self.config.keywords.is_empty() {
             format!("\n- Naturally incorporate these keywords when relevant: {}", self.config.keywords.join(", "))
         } else {
             String::new()
         };
 
-        let support_text = format!("\n- Encourage users to email {} for additional feedback or feature requests", self.config.support_email);
-
-        let custom_instructions = if let Some(ref custom) = self.config.custom_prompt {
-            format!("\n- Additional instructions: {}", custom)
+        // Without promotions the support email and custom prompt are left out
+        let promotes = self.config.promotes_to(review);
+        let support_text = if promotes {
+            format!(
+                "\n- Encourage users to email {} for additional feedback or feature requests",
+                self.config.support_email_for(review)
+            )
         } else {
-            String::new()
+            "\n- Don't promote anything or point the user to other channels; keep the reply purely empathetic".to_string()
+        };
+
+        let custom_instructions = match self.config.custom_prompt_for(review) {
+            Some(custom) if promotes => format!("\n- Additional instructions: {}", custom),
+            _ => String::new(),
         };
 
         let supporting_info = if let Some(ref info) = self.config.supporting_info {
@@ -101,22 +584,40 @@ impl AIResponseGenerator {
             String::new()
         };
 
+        let rating_instructions =
+            if let Some(prompt) = self.config.rating_prompts.get(&review.rating) {
+                format!("\n- For this {}-star review: {}", review.rating, prompt)
+            } else {
+                String::new()
+            };
+
+        let length_instructions = if let Some(min) = self.config.min_response_chars {
+            format!(
+                "\n- Write at least {} characters, using complete sentences",
+                min
+            )
+        } else {
+            String::new()
+        };
+
         format!(
             "You are a professional app developer responding to App Store reviews. Your responses should be:
 - Professional, friendly, and appreciative
 - Acknowledge the user's specific feedback
 - Keep responses under 350 characters (App Store limit)
-- Thank users for their time and feedback{}{}{}{}
+- Thank users for their time and feedback{}{}{}{}{}{}
 
 Always be genuine and avoid overly promotional language.",
             keywords_text,
             support_text,
             custom_instructions,
-            supporting_info
+            supporting_info,
+            rating_instructions,
+            length_instructions
         )
     }
 
-    fn build_user_prompt(&self, review: &Review) -> String {
+    fn build_user_prompt(&self, review: &Review, reply_language: Option<&str>) -> String {
         let rating_context = match review.rating {
             5 => "This is a 5-star positive review",
             4 => "This is a 4-star mostly positive review",
@@ -129,17 +630,199 @@ Always be genuine and avoid overly promotional language.",
         let title_text = review.title.as_deref().unwrap_or("(No title)");
         let body_text = review.body.as_deref().unwrap_or("(No review text)");
 
+        let original_text = match &review.original_text {
+            Some(original) => format!(
+                "\nOriginal review text ({}): \"{}\"",
+                review.language.as_deref().unwrap_or("original language"),
+                original
+            ),
+            None => String::new(),
+        };
+
+        let language_instruction = match reply_language {
+            Some(language) => format!(" Write the response in this language: {}.", language),
+            None => String::new(),
+        };
+
         format!(
             "{}.
 
 Review title: \"{}\"
-Review text: \"{}\"
+Review text: \"{}\"{}
 
-Please generate a professional response to this review.",
+Please generate a professional response to this review.{}",
             rating_context,
             title_text,
-            body_text
+            body_text,
+            original_text,
+            language_instruction
         )
     }
+}
+
+// When `complete` asks again instead of returning what the API sent
+struct Retries {
+    max_tokens: u32,
+    retry_max_tokens: u32,
+    retried_cut_off: bool,
+    retried_empty: bool,
+}
 
-}
\ No newline at end of file
+impl Retries {
+    fn new(max_tokens: u32, retry_max_tokens: u32) -> Self {
+        Self {
+            max_tokens,
+            retry_max_tokens,
+            retried_cut_off: false,
+            retried_empty: false,
+        }
+    }
+
+    // The response text when it's usable, or None to ask again with `max_tokens`
+    fn check(
+        &mut self,
+        content: String,
+        finish_reason: Option<FinishReason>,
+    ) -> Result<Option<String>> {
+        // The model occasionally answers with nothing, e.g. after a refusal,
+        // so ask once more before giving up on it
+        if content.trim().is_empty() {
+            if self.retried_empty {
+                return Err(anyhow!("The AI returned an empty response twice"));
+            }
+            self.retried_empty = true;
+            return Ok(None);
+        }
+
+        // A response cut off at the token limit ends mid-sentence, so retry
+        // once, with more room when the budget allows, rather than hand back
+        // something unsubmittable
+        if finish_reason != Some(FinishReason::Length) {
+            return Ok(Some(content));
+        }
+        if self.retried_cut_off {
+            return Err(anyhow!(
+                "The AI response was cut off at the {} token limit. Try again or write the response manually",
+                self.retry_max_tokens
+            ));
+        }
+        self.retried_cut_off = true;
+        self.max_tokens = self.retry_max_tokens;
+        Ok(None)
+    }
+}
+
+// OpenAI reports unknown, retired and access-restricted models alike as
+// `model_not_found`, but older responses only say so in the message
+fn model_unavailable(error: &OpenAIError) -> bool {
+    let OpenAIError::ApiError(api_error) = error else {
+        return false;
+    };
+    if api_error.code.as_deref() == Some("model_not_found") {
+        return true;
+    }
+    let message = api_error.message.to_lowercase();
+    message.contains("model")
+        && (message.contains("does not exist")
+            || message.contains("do not have access")
+            || message.contains("not allowed to sample"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_content_is_retried_once_then_reported() {
+        let mut retries = Retries::new(300, RETRY_MAX_TOKENS);
+        assert_eq!(
+            retries
+                .check(String::new(), Some(FinishReason::Stop))
+                .unwrap(),
+            None
+        );
+        let error = retries
+            .check("  \n".to_string(), Some(FinishReason::Stop))
+            .unwrap_err();
+        assert_eq!(error.to_string(), "The AI returned an empty response twice");
+    }
+
+    #[test]
+    fn content_after_an_empty_retry_is_returned() {
+        let mut retries = Retries::new(300, RETRY_MAX_TOKENS);
+        assert_eq!(
+            retries
+                .check(String::new(), Some(FinishReason::Stop))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            retries
+                .check("Thanks!".to_string(), Some(FinishReason::Stop))
+                .unwrap(),
+            Some("Thanks!".to_string())
+        );
+    }
+
+    #[test]
+    fn cut_off_content_is_retried_once_with_more_tokens() {
+        let mut retries = Retries::new(300, RETRY_MAX_TOKENS);
+        assert_eq!(
+            retries
+                .check("Thanks for".to_string(), Some(FinishReason::Length))
+                .unwrap(),
+            None
+        );
+        assert_eq!(retries.max_tokens, RETRY_MAX_TOKENS);
+        assert!(retries
+            .check("Thanks for".to_string(), Some(FinishReason::Length))
+            .is_err());
+    }
+
+    #[test]
+    fn rating_range_accepts_a_single_rating_or_a_range() {
+        assert_eq!(parse_rating_range("5"), Some((5, 5)));
+        assert_eq!(parse_rating_range("1-3"), Some((1, 3)));
+        assert_eq!(parse_rating_range(" 2 - 4 "), Some((2, 4)));
+    }
+
+    #[test]
+    fn rating_range_rejects_reversed_out_of_range_and_malformed_keys() {
+        assert_eq!(parse_rating_range("3-1"), None);
+        assert_eq!(parse_rating_range("0"), None);
+        assert_eq!(parse_rating_range("4-6"), None);
+        for key in ["", "five", "1-", "-3", "1-2-3", "1..3"] {
+            assert_eq!(parse_rating_range(key), None, "{}", key);
+        }
+    }
+
+    #[test]
+    fn retry_budget_only_grows_from_the_built_in_default() {
+        let review: Review = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "rating": 1,
+            "reviewer_nickname": "someone",
+            "created_date": "2024-01-01T00:00:00Z",
+            "territory": "USA",
+        }))
+        .unwrap();
+
+        let config = AIConfig::default();
+        assert_eq!(config.max_tokens_for(&review), MAX_TOKENS);
+        assert_eq!(config.retry_max_tokens_for(&review), RETRY_MAX_TOKENS);
+
+        let config = AIConfig {
+            max_tokens: Some(MAX_TOKENS),
+            ..AIConfig::default()
+        };
+        assert_eq!(config.retry_max_tokens_for(&review), MAX_TOKENS);
+
+        let config = AIConfig {
+            platform: Some(Platform::Android),
+            ..AIConfig::default()
+        };
+        let limit_tokens = config.max_tokens_for(&review);
+        assert!(limit_tokens < MAX_TOKENS);
+        assert_eq!(config.retry_max_tokens_for(&review), limit_tokens);
+    }
+}
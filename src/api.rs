@@ -1,37 +1,121 @@
 use anyhow::{anyhow, Result};
 use chrono::{Duration, Utc};
 use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
 
+use crate::audit::{self, AiUsage};
 use crate::config::{Config, Platform};
-use crate::review::{Review, ReviewsResponse};
+use crate::review::{
+    CommentAuthor, ResponseState, Review, ReviewResponse, ReviewsResponse, SingleReviewResponse,
+    ThreadComment, RESPONSE_TYPE,
+};
+use crate::text;
 
 const APP_STORE_CONNECT_API_BASE: &str = "https://api.appstoreconnect.apple.com/v1";
 const GOOGLE_PLAY_API_BASE: &str = "https://www.googleapis.com/androidpublisher/v3";
 
+// Every store request waits for a permit from the client's connection limit
+trait SendLimited {
+    async fn send_limited(self, limiter: &Semaphore) -> reqwest::Result<reqwest::Response>;
+}
+
+impl SendLimited for reqwest::RequestBuilder {
+    async fn send_limited(self, limiter: &Semaphore) -> reqwest::Result<reqwest::Response> {
+        // The limiter is never closed, so acquiring only waits
+        let _permit = limiter.acquire().await.expect("connection limiter closed");
+        self.send().await
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct Claims {
-    iss: String,
+    // Team keys are identified by the issuer, individual keys by `sub: "user"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    iss: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sub: Option<String>,
     exp: i64,
     aud: String,
 }
 
+// Why a store can't be reached with the configured credentials
+#[derive(Debug)]
+pub enum AccessProblem {
+    // Key/service account files couldn't be read or used to sign a token
+    Credentials(String),
+    Network(String),
+    Unauthorized(String),
+    Forbidden(String),
+    NotFound(String),
+    Other(StatusCode, String),
+}
+
+impl AccessProblem {
+    fn from_status(status: StatusCode, body: String) -> Self {
+        match status {
+            StatusCode::UNAUTHORIZED => Self::Unauthorized(body),
+            StatusCode::FORBIDDEN => Self::Forbidden(body),
+            StatusCode::NOT_FOUND => Self::NotFound(body),
+            _ => Self::Other(status, body),
+        }
+    }
+}
+
+// Returned by `submit_response` when App Store Connect answers 409 Conflict
+// because the review already has a response. `update_response` overwrites it.
+#[derive(Debug)]
+pub struct ResponseExists;
+
+impl std::fmt::Display for ResponseExists {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "A response already exists for this review; edit it instead"
+        )
+    }
+}
+
+impl std::error::Error for ResponseExists {}
+
+#[derive(Clone)]
 pub enum ApiClient {
     AppStore(AppStoreConnectClient),
     GooglePlay(GooglePlayClient),
+    Combined(Box<CombinedClient>),
+}
+
+// Both stores at once for `--both`. Reviews are tagged with their platform and
+// per-review calls are routed by remembering which store each id came from.
+#[derive(Clone)]
+pub struct CombinedClient {
+    app_store: AppStoreConnectClient,
+    google_play: GooglePlayClient,
+    platforms: HashMap<String, Platform>,
 }
 
+#[derive(Clone)]
 pub struct AppStoreConnectClient {
     client: Client,
+    // Shared with the other store's client under --both
+    limiter: Arc<Semaphore>,
     config: Config,
     jwt_token: Option<String>,
     token_expires_at: Option<chrono::DateTime<Utc>>,
+    next_page_url: Option<String>,
+    // `meta.paging.total` from the last page fetched
+    total_reviews: Option<usize>,
 }
 
+#[derive(Clone)]
 pub struct GooglePlayClient {
     client: Client,
+    // Shared with the other store's client under --both
+    limiter: Arc<Semaphore>,
     config: Config,
     access_token: Option<String>,
     token_expires_at: Option<chrono::DateTime<Utc>>,
@@ -54,26 +138,250 @@ struct ServiceAccountKey {
 
 impl ApiClient {
     pub fn new(config: Config) -> Self {
+        let limiter = Arc::new(Semaphore::new(config.max_connections));
         match config.platform {
-            Platform::Ios => Self::AppStore(AppStoreConnectClient::new(config)),
-            Platform::Android => Self::GooglePlay(GooglePlayClient::new(config)),
+            Platform::Ios => Self::AppStore(AppStoreConnectClient::new(config, limiter)),
+            Platform::Android => Self::GooglePlay(GooglePlayClient::new(config, limiter)),
         }
     }
 
+    pub fn combined(app_store_config: Config, google_play_config: Config) -> Self {
+        // One limit across both stores, matching the single --max-connections flag
+        let limiter = Arc::new(Semaphore::new(app_store_config.max_connections));
+        Self::Combined(Box::new(CombinedClient {
+            app_store: AppStoreConnectClient::new(app_store_config, limiter.clone()),
+            google_play: GooglePlayClient::new(google_play_config, limiter),
+            platforms: HashMap::new(),
+        }))
+    }
+
     pub async fn get_reviews(&mut self) -> Result<Vec<Review>> {
         match self {
             Self::AppStore(client) => client.get_reviews().await,
             Self::GooglePlay(client) => client.get_reviews().await,
+            Self::Combined(client) => client.get_reviews().await,
         }
     }
 
-    pub async fn submit_response(&mut self, review_id: &str, response_body: &str) -> Result<()> {
+    // The config of every store this client talks to
+    pub fn configs(&self) -> Vec<&Config> {
         match self {
-            Self::AppStore(client) => client.submit_response(review_id, response_body).await,
-            Self::GooglePlay(client) => client.submit_response(review_id, response_body).await,
+            Self::AppStore(client) => vec![&client.config],
+            Self::GooglePlay(client) => vec![&client.config],
+            Self::Combined(client) => vec![&client.app_store.config, &client.google_play.config],
         }
     }
 
+    // `origin_app_id` is the app the review was loaded for, when known. Once the
+    // store accepted the response this is Ok, with a warning if it couldn't be
+    // written to the audit log.
+    pub async fn submit_response(
+        &mut self,
+        review_id: &str,
+        origin_app_id: Option<&str>,
+        response_body: &str,
+        ai_usage: AiUsage,
+    ) -> Result<Option<String>> {
+        self.route_by_origin(review_id, origin_app_id);
+        self.check_origin(review_id, origin_app_id)?;
+        let config = match self {
+            Self::AppStore(client) => {
+                client.submit_response(review_id, response_body).await?;
+                &client.config
+            }
+            Self::GooglePlay(client) => {
+                client.submit_response(review_id, response_body).await?;
+                &client.config
+            }
+            Self::Combined(client) => client.submit_response(review_id, response_body).await?,
+        };
+
+        Ok(Self::record_submission(
+            config,
+            review_id,
+            response_body,
+            ai_usage,
+        ))
+    }
+
+    // Like `submit_response`, but updates the response that's already there,
+    // or creates one when there is none
+    pub async fn update_response(
+        &mut self,
+        review_id: &str,
+        origin_app_id: Option<&str>,
+        response_body: &str,
+        ai_usage: AiUsage,
+    ) -> Result<Option<String>> {
+        self.route_by_origin(review_id, origin_app_id);
+        self.check_origin(review_id, origin_app_id)?;
+        let config = match self {
+            Self::AppStore(client) => {
+                client.update_response(review_id, response_body).await?;
+                &client.config
+            }
+            // A new Google Play reply overwrites the previous one
+            Self::GooglePlay(client) => {
+                client.submit_response(review_id, response_body).await?;
+                &client.config
+            }
+            Self::Combined(client) => client.update_response(review_id, response_body).await?,
+        };
+
+        Ok(Self::record_submission(
+            config,
+            review_id,
+            response_body,
+            ai_usage,
+        ))
+    }
+
+    // Removes the review's developer response. Only App Store Connect supports this.
+    pub async fn delete_response(
+        &mut self,
+        review_id: &str,
+        origin_app_id: Option<&str>,
+    ) -> Result<()> {
+        self.route_by_origin(review_id, origin_app_id);
+        self.check_origin(review_id, origin_app_id)?;
+        match self {
+            Self::AppStore(client) => client.delete_response(review_id).await,
+            Self::GooglePlay(client) => client.delete_response(review_id).await,
+            Self::Combined(client) => match client.platform_of(review_id)? {
+                Platform::Ios => client.app_store.delete_response(review_id).await,
+                Platform::Android => client.google_play.delete_response(review_id).await,
+            },
+        }
+    }
+
+    // Cached reviews keep the platform they were tagged with under `--both`, so
+    // calls for them can be routed before anything has been fetched
+    pub fn remember_platforms(&mut self, reviews: &[Review]) {
+        let Self::Combined(client) = self else {
+            return;
+        };
+        for review in reviews {
+            if let Some(platform) = review.platform {
+                client.platforms.insert(review.id.clone(), platform);
+            }
+        }
+    }
+
+    // Carries on from the page `other`, a copy of this client, got to, e.g.
+    // after it fetched the newest page in the background. Everything else,
+    // such as the platforms remembered since the copy was made, is kept.
+    pub fn copy_paging_from(&mut self, other: &ApiClient) {
+        match (self, other) {
+            (Self::AppStore(client), Self::AppStore(other)) => client.copy_paging_from(other),
+            (Self::GooglePlay(client), Self::GooglePlay(other)) => client.copy_paging_from(other),
+            (Self::Combined(client), Self::Combined(other)) => {
+                client.app_store.copy_paging_from(&other.app_store);
+                client.google_play.copy_paging_from(&other.google_play);
+            }
+            _ => {}
+        }
+    }
+
+    // Under `--both` a review that isn't loaded, such as one whose response was
+    // queued offline, is sent to the store whose app it was loaded for
+    fn route_by_origin(&mut self, review_id: &str, origin_app_id: Option<&str>) {
+        let (Self::Combined(client), Some(origin)) = (self, origin_app_id) else {
+            return;
+        };
+        if client.platforms.contains_key(review_id) {
+            return;
+        }
+        let platform = if origin == client.app_store.config.app_id {
+            Platform::Ios
+        } else if origin == client.google_play.config.app_id {
+            Platform::Android
+        } else {
+            return;
+        };
+        client.platforms.insert(review_id.to_string(), platform);
+    }
+
+    // App Store Connect accepts a response to any review the key can see, so a
+    // review loaded for one app could otherwise be answered while configured
+    // for another, e.g. from a cache or queue left by an earlier run
+    fn check_origin(&self, review_id: &str, origin_app_id: Option<&str>) -> Result<()> {
+        let Some(origin) = origin_app_id else {
+            return Ok(());
+        };
+        let configs = match self {
+            Self::Combined(client) => match client.platform_of(review_id) {
+                Ok(Platform::Ios) => vec![&client.app_store.config],
+                Ok(Platform::Android) => vec![&client.google_play.config],
+                Err(_) => self.configs(),
+            },
+            _ => self.configs(),
+        };
+        if configs.iter().any(|config| config.app_id == origin) {
+            return Ok(());
+        }
+
+        let configured: Vec<&str> = configs
+            .iter()
+            .map(|config| config.app_id.as_str())
+            .collect();
+        Err(anyhow!(
+            "Review {} belongs to app {}, but rustpond is configured for {}. Refusing to submit to the wrong app",
+            review_id,
+            origin,
+            configured.join(" and ")
+        ))
+    }
+
+    // The response is already live, so a failed audit write is only a warning.
+    // Failing the submission would offer to send it again.
+    fn record_submission(
+        config: &Config,
+        review_id: &str,
+        response_body: &str,
+        ai_usage: AiUsage,
+    ) -> Option<String> {
+        let path = config.audit_log_path.as_ref()?;
+        audit::record_submission(path, config, review_id, response_body, ai_usage)
+            .err()
+            .map(|e| {
+                format!(
+                    "Response was submitted, but recording it in the audit log failed: {}",
+                    e
+                )
+            })
+    }
+
+    // The request `submit_response` would send, or `update_response` when the
+    // review already has the response `existing_response_id`, for
+    // `--print-payload`. Access tokens are left out.
+    pub fn preview_submission(
+        &self,
+        review_id: &str,
+        existing_response_id: Option<&str>,
+        response_body: &str,
+    ) -> Result<String> {
+        let (method, url, payload) = match self {
+            Self::AppStore(client) => {
+                client.preview_request(review_id, existing_response_id, response_body)
+            }
+            Self::GooglePlay(client) => client.preview_request(review_id, response_body),
+            Self::Combined(client) => match client.platform_of(review_id)? {
+                Platform::Ios => {
+                    client
+                        .app_store
+                        .preview_request(review_id, existing_response_id, response_body)
+                }
+                Platform::Android => client.google_play.preview_request(review_id, response_body),
+            },
+        };
+        Ok(format!(
+            "{} {}\n\n{}",
+            method,
+            url,
+            serde_json::to_string_pretty(&payload)?
+        ))
+    }
+
     pub async fn get_review_response(
         &mut self,
         review_id: &str,
@@ -81,38 +389,220 @@ impl ApiClient {
         match self {
             Self::AppStore(client) => client.get_review_response(review_id).await,
             Self::GooglePlay(client) => client.get_review_response(review_id).await,
+            Self::Combined(client) => match client.platform_of(review_id)? {
+                Platform::Ios => client.app_store.get_review_response(review_id).await,
+                Platform::Android => client.google_play.get_review_response(review_id).await,
+            },
+        }
+    }
+
+    // Cheap request that exercises the credentials and the app id. The error
+    // says which store failed.
+    pub async fn check_access(&mut self) -> std::result::Result<(), (Platform, AccessProblem)> {
+        match self {
+            Self::AppStore(client) => client.check_access().await.map_err(|p| (Platform::Ios, p)),
+            Self::GooglePlay(client) => client
+                .check_access()
+                .await
+                .map_err(|p| (Platform::Android, p)),
+            Self::Combined(client) => {
+                client
+                    .app_store
+                    .check_access()
+                    .await
+                    .map_err(|p| (Platform::Ios, p))?;
+                client
+                    .google_play
+                    .check_access()
+                    .await
+                    .map_err(|p| (Platform::Android, p))
+            }
+        }
+    }
+
+    // Fetches one review with its response, for reviews outside the loaded pages
+    pub async fn get_review(&mut self, review_id: &str) -> Result<Review> {
+        match self {
+            Self::AppStore(client) => client.get_review(review_id).await,
+            Self::GooglePlay(client) => client.get_review(review_id).await,
+            Self::Combined(client) => client.get_review(review_id).await,
         }
     }
 
     pub async fn load_more_reviews(&mut self) -> Result<Vec<Review>> {
         match self {
-            Self::AppStore(_) => Ok(Vec::new()), // iOS loads all reviews at once
+            Self::AppStore(client) => client.load_next_page().await,
             Self::GooglePlay(client) => client.load_next_page().await,
+            Self::Combined(client) => client.load_next_page().await,
         }
     }
 
     pub fn has_more_reviews(&self) -> bool {
         match self {
-            Self::AppStore(_) => false, // iOS loads all reviews at once
+            Self::AppStore(client) => client.has_more_reviews(),
             Self::GooglePlay(client) => client.has_more_reviews(),
+            Self::Combined(client) => {
+                client.app_store.has_more_reviews() || client.google_play.has_more_reviews()
+            }
         }
     }
 
-    pub async fn refresh_all_reviews(&mut self) -> Result<Vec<Review>> {
+    // Only App Store Connect reports how many reviews there are in total
+    pub fn total_reviews(&self) -> Option<usize> {
         match self {
-            Self::AppStore(client) => client.get_reviews().await,
+            Self::AppStore(client) => client.total_reviews,
+            Self::GooglePlay(_) | Self::Combined(_) => None,
+        }
+    }
+
+    // The warning is set when a page failed after some were fetched, in which
+    // case the reviews are only the ones before it
+    pub async fn refresh_all_reviews(&mut self) -> Result<(Vec<Review>, Option<String>)> {
+        match self {
+            Self::AppStore(client) => client.refresh_all_reviews().await,
             Self::GooglePlay(client) => client.refresh_all_reviews().await,
+            Self::Combined(client) => client.refresh_all_reviews().await,
+        }
+    }
+
+    // Only the first page, for merging into the loaded reviews. Pages loaded
+    // with `l` stay loaded and the next `l` carries on after them.
+    pub async fn fetch_newest_reviews(&mut self) -> Result<Vec<Review>> {
+        match self {
+            Self::AppStore(client) => client.fetch_newest_page().await,
+            Self::GooglePlay(client) => client.fetch_newest_page().await,
+            Self::Combined(client) => client.fetch_newest_page().await,
+        }
+    }
+}
+
+impl CombinedClient {
+    fn tag(&mut self, mut reviews: Vec<Review>, platform: Platform) -> Vec<Review> {
+        for review in &mut reviews {
+            review.platform = Some(platform);
+            self.platforms.insert(review.id.clone(), platform);
+        }
+        reviews
+    }
+
+    fn platform_of(&self, review_id: &str) -> Result<Platform> {
+        self.platforms.get(review_id).copied().ok_or_else(|| {
+            anyhow!(
+                "Unknown review {}. Refresh the reviews and try again",
+                review_id
+            )
+        })
+    }
+
+    async fn get_reviews(&mut self) -> Result<Vec<Review>> {
+        let app_store = self.app_store.get_reviews().await?;
+        let google_play = self.google_play.get_reviews().await?;
+        let mut reviews = self.tag(app_store, Platform::Ios);
+        reviews.extend(self.tag(google_play, Platform::Android));
+        Ok(reviews)
+    }
+
+    async fn refresh_all_reviews(&mut self) -> Result<(Vec<Review>, Option<String>)> {
+        let (app_store, app_store_warning) = self.app_store.refresh_all_reviews().await?;
+        let (google_play, google_play_warning) = self.google_play.refresh_all_reviews().await?;
+        let mut reviews = self.tag(app_store, Platform::Ios);
+        reviews.extend(self.tag(google_play, Platform::Android));
+        let warnings: Vec<String> = [
+            app_store_warning.map(|w| format!("App Store: {}", w)),
+            google_play_warning.map(|w| format!("Google Play: {}", w)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        Ok((reviews, (!warnings.is_empty()).then(|| warnings.join("; "))))
+    }
+
+    async fn fetch_newest_page(&mut self) -> Result<Vec<Review>> {
+        let app_store = self.app_store.fetch_newest_page().await?;
+        let google_play = self.google_play.fetch_newest_page().await?;
+        let mut reviews = self.tag(app_store, Platform::Ios);
+        reviews.extend(self.tag(google_play, Platform::Android));
+        Ok(reviews)
+    }
+
+    async fn load_next_page(&mut self) -> Result<Vec<Review>> {
+        let mut reviews = Vec::new();
+        if self.app_store.has_more_reviews() {
+            let page = self.app_store.load_next_page().await?;
+            reviews.extend(self.tag(page, Platform::Ios));
+        }
+        if self.google_play.has_more_reviews() {
+            let page = self.google_play.load_next_page().await?;
+            reviews.extend(self.tag(page, Platform::Android));
+        }
+        Ok(reviews)
+    }
+
+    // Ids that weren't loaded yet, e.g. from `--review-id`, are tried in both stores
+    async fn get_review(&mut self, review_id: &str) -> Result<Review> {
+        let (platform, review) = match self.platforms.get(review_id).copied() {
+            Some(Platform::Ios) => (Platform::Ios, self.app_store.get_review(review_id).await?),
+            Some(Platform::Android) => (
+                Platform::Android,
+                self.google_play.get_review(review_id).await?,
+            ),
+            None => match self.app_store.get_review(review_id).await {
+                Ok(review) => (Platform::Ios, review),
+                Err(_) => (
+                    Platform::Android,
+                    self.google_play.get_review(review_id).await?,
+                ),
+            },
+        };
+        Ok(self.tag(vec![review], platform).remove(0))
+    }
+
+    async fn update_response(&mut self, review_id: &str, response_body: &str) -> Result<&Config> {
+        match self.platform_of(review_id)? {
+            Platform::Ios => {
+                self.app_store
+                    .update_response(review_id, response_body)
+                    .await?;
+                Ok(&self.app_store.config)
+            }
+            Platform::Android => {
+                self.google_play
+                    .submit_response(review_id, response_body)
+                    .await?;
+                Ok(&self.google_play.config)
+            }
+        }
+    }
+
+    // Returns the config of the store the response went to, for the audit log
+    async fn submit_response(&mut self, review_id: &str, response_body: &str) -> Result<&Config> {
+        match self.platform_of(review_id)? {
+            Platform::Ios => {
+                self.app_store
+                    .submit_response(review_id, response_body)
+                    .await?;
+                Ok(&self.app_store.config)
+            }
+            Platform::Android => {
+                self.google_play
+                    .submit_response(review_id, response_body)
+                    .await?;
+                Ok(&self.google_play.config)
+            }
         }
     }
 }
 
 impl AppStoreConnectClient {
-    pub fn new(config: Config) -> Self {
+    pub fn new(config: Config, limiter: Arc<Semaphore>) -> Self {
         Self {
             client: Client::new(),
+            limiter,
             config,
             jwt_token: None,
             token_expires_at: None,
+            next_page_url: None,
+            total_reviews: None,
         }
     }
 
@@ -127,11 +617,16 @@ impl AppStoreConnectClient {
             .key_id
             .as_ref()
             .ok_or_else(|| anyhow!("Key ID not configured for iOS"))?;
-        let issuer_id = self
-            .config
-            .issuer_id
-            .as_ref()
-            .ok_or_else(|| anyhow!("Issuer ID not configured for iOS"))?;
+        let issuer_id = if self.config.individual_key {
+            None
+        } else {
+            Some(
+                self.config
+                    .issuer_id
+                    .clone()
+                    .ok_or_else(|| anyhow!("Issuer ID not configured for iOS"))?,
+            )
+        };
 
         // read private key file
         let private_key_content = fs::read_to_string(private_key_path)
@@ -145,7 +640,8 @@ impl AppStoreConnectClient {
         let exp = now + Duration::minutes(20); // Apple recommends max 20 minutes
 
         let claims = Claims {
-            iss: issuer_id.clone(),
+            sub: self.config.individual_key.then(|| "user".to_string()),
+            iss: issuer_id,
             exp: exp.timestamp(),
             aud: "appstoreconnect-v1".to_string(),
         };
@@ -182,15 +678,182 @@ impl AppStoreConnectClient {
         Ok(())
     }
 
-    pub async fn get_reviews(&mut self) -> Result<Vec<Review>> {
+    async fn check_access(&mut self) -> std::result::Result<(), AccessProblem> {
+        self.ensure_valid_token()
+            .await
+            .map_err(|e| AccessProblem::Credentials(e.to_string()))?;
+
+        let token = self.jwt_token.as_ref().unwrap();
+        let url = format!("{}/apps/{}", APP_STORE_CONNECT_API_BASE, self.config.app_id);
+
+        let response = self
+            .client
+            .get(&url)
+            .bearer_auth(token)
+            .query(&[("fields[apps]", "name")])
+            .send_limited(&self.limiter)
+            .await
+            .map_err(|e| AccessProblem::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(AccessProblem::from_status(status, error_text));
+        }
+
+        Ok(())
+    }
+
+    async fn get_review(&mut self, review_id: &str) -> Result<Review> {
         self.ensure_valid_token().await?;
 
         let token = self.jwt_token.as_ref().unwrap();
         let url = format!(
-            "{}/apps/{}/customerReviews",
-            APP_STORE_CONNECT_API_BASE, self.config.app_id
+            "{}/customerReviews/{}",
+            APP_STORE_CONNECT_API_BASE, review_id
         );
 
+        let response = self
+            .client
+            .get(&url)
+            .bearer_auth(token)
+            .query(&[
+                ("include", "response,app"),
+                (
+                    "fields[customerReviewResponses]",
+                    "responseBody,lastModifiedDate,state",
+                ),
+                ("fields[apps]", "bundleId"),
+            ])
+            .send_limited(&self.limiter)
+            .await
+            .map_err(|e| anyhow!("Failed to fetch review: {}", e))?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(anyhow!("Review {} was not found", review_id));
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(anyhow!(
+                "Failed to fetch review with status {}: {}",
+                status,
+                error_text
+            ));
+        }
+
+        let response_text = response
+            .text()
+            .await
+            .map_err(|e| anyhow!("Failed to read review: {}", e))?;
+        let review_response: SingleReviewResponse = serde_json::from_str(&response_text)
+            .map_err(|e| anyhow!("Failed to parse review: {}", e))?;
+
+        let mut review = Review::try_from(review_response.data)
+            .map_err(|e| anyhow!("App Store Connect returned an unexpected review: {}", e))?;
+        let included = review_response.included.unwrap_or_default();
+        // The key can see every app's reviews, so one fetched by id may belong
+        // to an app other than the configured one
+        let app_id = included
+            .iter()
+            .find(|resource| resource.type_ == "apps")
+            .map(|resource| resource.id.clone())
+            .ok_or_else(|| {
+                anyhow!(
+                    "App Store Connect didn't say which app review {} belongs to",
+                    review_id
+                )
+            })?;
+        if app_id != self.config.app_id {
+            return Err(anyhow!(
+                "Review {} belongs to app {}, but rustpond is configured for {}",
+                review_id,
+                app_id,
+                self.config.app_id
+            ));
+        }
+        review.app_id = Some(app_id);
+        if self.config.debug {
+            review.raw = serde_json::from_str::<serde_json::Value>(&response_text)
+                .ok()
+                .and_then(|value| value.get("data").cloned());
+        }
+        review.response = included
+            .into_iter()
+            .find_map(|resource| resource.into_review_response());
+
+        Ok(review)
+    }
+
+    fn copy_paging_from(&mut self, other: &Self) {
+        self.next_page_url = other.next_page_url.clone();
+        self.total_reviews = other.total_reviews;
+    }
+
+    pub async fn get_reviews(&mut self) -> Result<Vec<Review>> {
+        self.next_page_url = None;
+        self.fetch_reviews_page(None).await
+    }
+
+    // A page that fails stays next, so it can be tried again
+    pub async fn load_next_page(&mut self) -> Result<Vec<Review>> {
+        match self.next_page_url.take() {
+            Some(url) => {
+                let page = self.fetch_reviews_page(Some(url.clone())).await;
+                if page.is_err() {
+                    self.next_page_url = Some(url);
+                }
+                page
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    pub fn has_more_reviews(&self) -> bool {
+        self.next_page_url.is_some()
+    }
+
+    pub async fn fetch_newest_page(&mut self) -> Result<Vec<Review>> {
+        let next_page_url = self.next_page_url.take();
+        let reviews = self.fetch_reviews_page(None).await;
+        self.next_page_url = next_page_url;
+        reviews
+    }
+
+    // With --fetch-all this follows every `links.next`, otherwise only the newest
+    // page is loaded. Like Google Play, a failed page keeps the ones before it.
+    pub async fn refresh_all_reviews(&mut self) -> Result<(Vec<Review>, Option<String>)> {
+        let mut all_reviews = self.get_reviews().await?;
+        let mut warning = None;
+
+        if self.config.fetch_all {
+            while self.has_more_reviews() {
+                match self.load_next_page().await {
+                    Ok(page_reviews) => all_reviews.extend(page_reviews),
+                    Err(e) => {
+                        warning = Some(stopped_early(all_reviews.len(), &e));
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok((all_reviews, warning))
+    }
+
+    // `next_url` comes from the previous page's `links.next` and already carries the query
+    async fn fetch_reviews_page(&mut self, next_url: Option<String>) -> Result<Vec<Review>> {
+        self.ensure_valid_token().await?;
+
+        let token = self.jwt_token.as_ref().unwrap();
+        let url = next_url.clone().unwrap_or_else(|| {
+            format!(
+                "{}/apps/{}/customerReviews",
+                APP_STORE_CONNECT_API_BASE, self.config.app_id
+            )
+        });
+
         use std::io::Write;
         let mut log_file = std::fs::OpenOptions::new()
             .create(true)
@@ -201,21 +864,28 @@ impl AppStoreConnectClient {
         writeln!(
             log_file,
             "DEBUG: Using token (first 20 chars): {}...",
-            &token[..20.min(token.len())]
+            text::truncate_chars(token, 20)
         )
         .ok();
 
-        let response = self
-            .client
-            .get(&url)
-            .bearer_auth(token)
-            .query(&[("limit", "200"), ("sort", "-createdDate")])
-            .send()
-            .await
-            .map_err(|e| {
-                writeln!(log_file, "DEBUG: Request failed with error: {}", e).ok();
-                anyhow!("Failed to fetch reviews: {}", e)
-            })?;
+        let mut request = self.client.get(&url).bearer_auth(token);
+        if next_url.is_none() {
+            request = request.query(&[
+                ("limit", "200"),
+                ("sort", self.config.fetch_sort.query_value()),
+                // Fetch responses inline instead of one request per review
+                ("include", "response"),
+                (
+                    "fields[customerReviewResponses]",
+                    "responseBody,lastModifiedDate,state",
+                ),
+            ]);
+        }
+
+        let response = request.send_limited(&self.limiter).await.map_err(|e| {
+            writeln!(log_file, "DEBUG: Request failed with error: {}", e).ok();
+            anyhow!("Failed to fetch reviews: {}", e)
+        })?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -234,31 +904,99 @@ impl AppStoreConnectClient {
             .map_err(|e| anyhow!("Failed to read response text: {}", e))?;
 
         // Try to parse the JSON response
+        // The body holds every review's text, so it goes to debug.log rather
+        // than into the error, which can end up in a debug bundle
         let reviews_response: ReviewsResponse =
             serde_json::from_str(&response_text).map_err(|e| {
-                anyhow!(
-                    "Failed to parse reviews response: {}. Response was: {}",
-                    e,
+                writeln!(
+                    log_file,
+                    "DEBUG: Unparseable reviews response: {}",
                     response_text
                 )
+                .ok();
+                anyhow!(
+                    "Failed to parse reviews response: {}. The response was written to debug.log",
+                    e
+                )
             })?;
 
-        let reviews = reviews_response
-            .data
+        // Parsed a second time only when needed, to keep the raw JSON per review
+        let mut raw_reviews = if self.config.debug {
+            serde_json::from_str::<serde_json::Value>(&response_text)
+                .ok()
+                .and_then(|value| value.get("data").and_then(|data| data.as_array()).cloned())
+                .unwrap_or_default()
+                .into_iter()
+        } else {
+            Vec::new().into_iter()
+        };
+
+        self.next_page_url = reviews_response.links.as_ref().and_then(|l| l.next.clone());
+        if let Some(paging) = reviews_response
+            .meta
+            .as_ref()
+            .and_then(|m| m.paging.as_ref())
+        {
+            self.total_reviews = Some(paging.total.max(0) as usize);
+        }
+
+        let mut responses: HashMap<String, ReviewResponse> = reviews_response
+            .included
+            .unwrap_or_default()
             .into_iter()
-            .map(|data| data.into())
+            .filter_map(|resource| resource.into_review_response())
+            .map(|response| (response.id.clone(), response))
             .collect();
 
+        // Entries of an unexpected type are logged and skipped. If none of a page's
+        // entries are reviews the API has likely changed, so that's an error.
+        let entries = reviews_response.data.len();
+        let mut reviews = Vec::with_capacity(entries);
+        let mut unexpected = Vec::new();
+        for data in reviews_response.data {
+            let raw = raw_reviews.next();
+            let response_id = match data.response_id() {
+                Ok(response_id) => response_id.map(str::to_string),
+                Err(e) => {
+                    writeln!(log_file, "WARNING: Ignoring response link: {}", e).ok();
+                    None
+                }
+            };
+
+            match Review::try_from(data) {
+                Ok(mut review) => {
+                    review.app_id = Some(self.config.app_id.clone());
+                    review.response = response_id.and_then(|id| responses.remove(&id));
+                    review.raw = raw;
+                    reviews.push(review);
+                }
+                Err(e) => {
+                    writeln!(log_file, "WARNING: Skipping review entry: {}", e).ok();
+                    unexpected.push(e.to_string());
+                }
+            }
+        }
+
+        if reviews.is_empty() && !unexpected.is_empty() {
+            return Err(anyhow!(
+                "App Store Connect returned {} entries but none were customer reviews, so the API may have changed: {}",
+                entries,
+                unexpected[0]
+            ));
+        }
+
         Ok(reviews)
     }
 
-    pub async fn submit_response(&mut self, review_id: &str, response_body: &str) -> Result<()> {
-        self.ensure_valid_token().await?;
-
-        let token = self.jwt_token.as_ref().unwrap();
-        let url = format!("{}/customerReviewResponses", APP_STORE_CONNECT_API_BASE);
+    fn submission_url(&self) -> String {
+        format!("{}/customerReviewResponses", APP_STORE_CONNECT_API_BASE)
+    }
 
-        let request_body = serde_json::json!({
+    // `responseBody` is the only writable attribute of a customerReviewResponse.
+    // There's no locale or territory to set: the response belongs to the review,
+    // which already carries its territory.
+    fn submission_payload(review_id: &str, response_body: &str) -> serde_json::Value {
+        serde_json::json!({
             "data": {
                 "type": "customerReviewResponses",
                 "attributes": {
@@ -273,7 +1011,15 @@ impl AppStoreConnectClient {
                     }
                 }
             }
-        });
+        })
+    }
+
+    pub async fn submit_response(&mut self, review_id: &str, response_body: &str) -> Result<()> {
+        self.ensure_valid_token().await?;
+
+        let token = self.jwt_token.as_ref().unwrap();
+        let url = self.submission_url();
+        let request_body = Self::submission_payload(review_id, response_body);
 
         let response = self
             .client
@@ -281,10 +1027,13 @@ impl AppStoreConnectClient {
             .bearer_auth(token)
             .header("Content-Type", "application/json")
             .json(&request_body)
-            .send()
+            .send_limited(&self.limiter)
             .await
             .map_err(|e| anyhow!("Failed to submit response: {}", e))?;
 
+        if response.status() == StatusCode::CONFLICT {
+            return Err(ResponseExists.into());
+        }
         if !response.status().is_success() {
             let status = response.status();
             let error_text = response.text().await.unwrap_or_default();
@@ -298,6 +1047,158 @@ impl AppStoreConnectClient {
         Ok(())
     }
 
+    fn update_url(&self, response_id: &str) -> String {
+        format!(
+            "{}/customerReviewResponses/{}",
+            APP_STORE_CONNECT_API_BASE, response_id
+        )
+    }
+
+    fn update_payload(response_id: &str, response_body: &str) -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "type": RESPONSE_TYPE,
+                "id": response_id,
+                "attributes": {
+                    "responseBody": response_body
+                }
+            }
+        })
+    }
+
+    // Method, URL and payload for `ApiClient::preview_submission`. Only loaded
+    // reviews carry the response id; one answered this session is looked up
+    // when it's sent.
+    fn preview_request(
+        &self,
+        review_id: &str,
+        existing_response_id: Option<&str>,
+        response_body: &str,
+    ) -> (&'static str, String, serde_json::Value) {
+        match existing_response_id {
+            Some(response_id) => {
+                let response_id = if response_id.is_empty() {
+                    "<current response id>"
+                } else {
+                    response_id
+                };
+                (
+                    "PATCH",
+                    self.update_url(response_id),
+                    Self::update_payload(response_id, response_body),
+                )
+            }
+            None => (
+                "POST",
+                self.submission_url(),
+                Self::submission_payload(review_id, response_body),
+            ),
+        }
+    }
+
+    // PATCHes the current response found by `get_review_response`, or creates
+    // one when there is none. If App Store Connect refuses to update responses,
+    // the draft is POSTed instead, which creates or replaces the response. The
+    // old one is never deleted first, so a failed POST can't lose it.
+    pub async fn update_response(&mut self, review_id: &str, response_body: &str) -> Result<()> {
+        if let Some(existing) = self.get_review_response(review_id).await? {
+            if self.patch_response(&existing.id, response_body).await? {
+                return Ok(());
+            }
+        }
+        self.submit_response(review_id, response_body).await
+    }
+
+    // False when the API doesn't allow updating responses, which it reports as
+    // 405, or as 403 saying the resource "does not allow 'UPDATE'"
+    async fn patch_response(&mut self, response_id: &str, response_body: &str) -> Result<bool> {
+        self.ensure_valid_token().await?;
+
+        let token = self.jwt_token.as_ref().unwrap();
+        let url = self.update_url(response_id);
+        let request_body = Self::update_payload(response_id, response_body);
+
+        let response = self
+            .client
+            .patch(&url)
+            .bearer_auth(token)
+            .header("Content-Type", "application/json")
+            .json(&request_body)
+            .send_limited(&self.limiter)
+            .await
+            .map_err(|e| anyhow!("Failed to update the existing response: {}", e))?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(true);
+        }
+        let error_text = response.text().await.unwrap_or_default();
+        if status == StatusCode::METHOD_NOT_ALLOWED
+            || (status == StatusCode::FORBIDDEN && error_text.contains("does not allow"))
+        {
+            use std::io::Write;
+            if let Ok(mut log_file) = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open("debug.log")
+            {
+                writeln!(
+                    log_file,
+                    "DEBUG: Updating response {} isn't supported ({}), posting the draft instead",
+                    response_id, status
+                )
+                .ok();
+            }
+            return Ok(false);
+        }
+        Err(anyhow!(
+            "Failed to update the existing response with status {}: {}",
+            status,
+            error_text
+        ))
+    }
+
+    // Looks up the review's response first, since responses have their own ids
+    pub async fn delete_response(&mut self, review_id: &str) -> Result<()> {
+        match self.get_review_response(review_id).await? {
+            Some(existing) => self.delete_response_by_id(&existing.id).await,
+            None => Err(anyhow!(
+                "Review {} has no developer response to delete",
+                review_id
+            )),
+        }
+    }
+
+    async fn delete_response_by_id(&mut self, response_id: &str) -> Result<()> {
+        self.ensure_valid_token().await?;
+
+        let token = self.jwt_token.as_ref().unwrap();
+        let url = format!(
+            "{}/customerReviewResponses/{}",
+            APP_STORE_CONNECT_API_BASE, response_id
+        );
+
+        let response = self
+            .client
+            .delete(&url)
+            .bearer_auth(token)
+            .send_limited(&self.limiter)
+            .await
+            .map_err(|e| anyhow!("Failed to delete the existing response: {}", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(anyhow!(
+                "Failed to delete the existing response with status {}: {}",
+                status,
+                error_text
+            ));
+        }
+
+        Ok(())
+    }
+
     pub async fn get_review_response(
         &mut self,
         review_id: &str,
@@ -327,7 +1228,7 @@ impl AppStoreConnectClient {
             .client
             .get(&url)
             .bearer_auth(token)
-            .send()
+            .send_limited(&self.limiter)
             .await
             .map_err(|e| anyhow!("Failed to fetch response: {}", e))?;
 
@@ -405,7 +1306,7 @@ impl AppStoreConnectClient {
             .client
             .get(&url)
             .bearer_auth(token)
-            .send()
+            .send_limited(&self.limiter)
             .await
             .map_err(|e| anyhow!("Failed to fetch response details: {}", e))?;
 
@@ -467,10 +1368,7 @@ impl AppStoreConnectClient {
                     .and_then(|s| s.as_str())
                     .unwrap_or("PENDING");
 
-                let state = match state_str {
-                    "PUBLISHED" => crate::review::ResponseState::Published,
-                    _ => crate::review::ResponseState::Pending,
-                };
+                let state = crate::review::ResponseState::from_api(state_str);
 
                 writeln!(
                     log_file,
@@ -494,9 +1392,10 @@ impl AppStoreConnectClient {
 }
 
 impl GooglePlayClient {
-    pub fn new(config: Config) -> Self {
+    pub fn new(config: Config, limiter: Arc<Semaphore>) -> Self {
         Self {
             client: Client::new(),
+            limiter,
             config,
             access_token: None,
             token_expires_at: None,
@@ -569,7 +1468,7 @@ impl GooglePlayClient {
             .post(&service_account.token_uri)
             .header("Content-Type", "application/json")
             .json(&token_request)
-            .send()
+            .send_limited(&self.limiter)
             .await
             .map_err(|e| anyhow!("Failed to request access token: {}", e))?;
 
@@ -608,27 +1507,24 @@ impl GooglePlayClient {
 
         self.ensure_valid_token().await?;
 
-        let token = self.access_token.as_ref().unwrap();
-        let url = format!(
-            "{}/applications/{}/reviews",
-            GOOGLE_PLAY_API_BASE, self.config.app_id
-        );
-
-        let mut query_params = vec![("access_token", token.as_str()), ("maxResults", "100")];
-
-        // add pagination token if we have one
-        if let Some(ref page_token) = &self.next_page_token {
-            query_params.push(("token", page_token.as_str()));
+        let mut response = self.fetch_reviews_page().await?;
+
+        if response.status() == StatusCode::UNAUTHORIZED {
+            // The access token can expire between pages of a long refresh, so force
+            // a new one and retry this page once before giving up
+            self.invalidate_token();
+            self.ensure_valid_token().await?;
+            response = self.fetch_reviews_page().await?;
+
+            if response.status() == StatusCode::UNAUTHORIZED {
+                let error_text = response.text().await.unwrap_or_default();
+                return Err(anyhow!(
+                    "Google Play rejected a freshly refreshed access token (401). Check that the service account still has access to this app: {}",
+                    error_text
+                ));
+            }
         }
 
-        let response = self
-            .client
-            .get(&url)
-            .query(&query_params)
-            .send()
-            .await
-            .map_err(|e| anyhow!("Failed to fetch reviews: {}", e))?;
-
         if !response.status().is_success() {
             let status = response.status();
             let error_text = response.text().await.unwrap_or_default();
@@ -667,27 +1563,164 @@ impl GooglePlayClient {
 
         self.has_more_pages = self.next_page_token.is_some();
 
+        // The Play API has no sort parameter, so each page is reordered here
+        self.config.fetch_sort.sort(&mut page_reviews);
+
         Ok(page_reviews)
     }
 
+    async fn check_access(&mut self) -> std::result::Result<(), AccessProblem> {
+        // This also covers the token exchange with Google's OAuth endpoint
+        self.ensure_valid_token()
+            .await
+            .map_err(|e| AccessProblem::Credentials(e.to_string()))?;
+
+        let token = self.access_token.as_ref().unwrap();
+        let url = format!(
+            "{}/applications/{}/reviews",
+            GOOGLE_PLAY_API_BASE, self.config.app_id
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&[("access_token", token.as_str()), ("maxResults", "1")])
+            .send_limited(&self.limiter)
+            .await
+            .map_err(|e| AccessProblem::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(AccessProblem::from_status(status, error_text));
+        }
+
+        Ok(())
+    }
+
+    async fn get_review(&mut self, review_id: &str) -> Result<Review> {
+        self.ensure_valid_token().await?;
+
+        let token = self.access_token.as_ref().unwrap();
+        let url = format!(
+            "{}/applications/{}/reviews/{}",
+            GOOGLE_PLAY_API_BASE, self.config.app_id, review_id
+        );
+
+        let mut query_params = vec![("access_token", token.as_str())];
+        if let Some(language) = &self.config.translation_language {
+            query_params.push(("translationLanguage", language.as_str()));
+        }
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&query_params)
+            .send_limited(&self.limiter)
+            .await
+            .map_err(|e| anyhow!("Failed to fetch review: {}", e))?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(anyhow!("Review {} was not found", review_id));
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(anyhow!(
+                "Failed to fetch review with status {}: {}",
+                status,
+                error_text
+            ));
+        }
+
+        let review_data: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| anyhow!("Failed to parse review: {}", e))?;
+
+        self.parse_google_play_review(&review_data)
+            .ok_or_else(|| anyhow!("Review {} is missing required fields", review_id))
+    }
+
+    async fn fetch_reviews_page(&self) -> Result<reqwest::Response> {
+        let token = self.access_token.as_ref().unwrap();
+        let url = format!(
+            "{}/applications/{}/reviews",
+            GOOGLE_PLAY_API_BASE, self.config.app_id
+        );
+
+        let mut query_params = vec![("access_token", token.as_str()), ("maxResults", "100")];
+
+        // add pagination token if we have one
+        if let Some(ref page_token) = &self.next_page_token {
+            query_params.push(("token", page_token.as_str()));
+        }
+
+        if let Some(language) = &self.config.translation_language {
+            query_params.push(("translationLanguage", language.as_str()));
+        }
+
+        self.client
+            .get(&url)
+            .query(&query_params)
+            .send_limited(&self.limiter)
+            .await
+            .map_err(|e| anyhow!("Failed to fetch reviews: {}", e))
+    }
+
+    fn invalidate_token(&mut self) {
+        self.access_token = None;
+        self.token_expires_at = None;
+    }
+
     pub fn has_more_reviews(&self) -> bool {
         self.has_more_pages
     }
 
-    pub async fn refresh_all_reviews(&mut self) -> Result<Vec<Review>> {
-        // Reset pagination state
+    // The next page loaded is the first one again
+    pub fn restart_pagination(&mut self) {
         self.next_page_token = None;
         self.has_more_pages = true;
+    }
+
+    fn copy_paging_from(&mut self, other: &Self) {
+        self.next_page_token = other.next_page_token.clone();
+        self.has_more_pages = other.has_more_pages;
+    }
+
+    pub async fn fetch_newest_page(&mut self) -> Result<Vec<Review>> {
+        let next_page_token = self.next_page_token.take();
+        let has_more_pages = std::mem::replace(&mut self.has_more_pages, true);
+        let reviews = self.load_next_page().await;
+        self.next_page_token = next_page_token;
+        self.has_more_pages = has_more_pages;
+        reviews
+    }
+
+    // A page that still fails after `load_next_page`'s retry ends the refresh,
+    // but the pages before it are kept and returned with a warning. The failed
+    // page stays next, so 'l' can pick up from there.
+    pub async fn refresh_all_reviews(&mut self) -> Result<(Vec<Review>, Option<String>)> {
+        self.restart_pagination();
 
         let mut all_reviews = Vec::new();
+        let mut warning = None;
 
         // Load all pages
         while self.has_more_pages {
-            let page_reviews = self.load_next_page().await?;
-            all_reviews.extend(page_reviews);
+            match self.load_next_page().await {
+                Ok(page_reviews) => all_reviews.extend(page_reviews),
+                Err(e) if all_reviews.is_empty() => return Err(e),
+                Err(e) => {
+                    warning = Some(stopped_early(all_reviews.len(), &e));
+                    break;
+                }
+            }
         }
+        self.config.fetch_sort.sort(&mut all_reviews);
 
-        Ok(all_reviews)
+        Ok((all_reviews, warning))
     }
 
     fn parse_google_play_review(&self, review_data: &serde_json::Value) -> Option<Review> {
@@ -706,6 +1739,13 @@ impl GooglePlayClient {
         let rating = user_comment.get("starRating")?.as_i64()? as i32;
         let body = user_comment.get("text")?.as_str().map(|s| s.to_string());
 
+        // `originalText` is only present when `text` holds Google's translation
+        let original_text = user_comment
+            .get("originalText")
+            .and_then(|t| t.as_str())
+            .map(|s| s.to_string())
+            .filter(|original| Some(original) != body.as_ref());
+
         // Parse timestamp from seconds field
         let created_timestamp = user_comment
             .get("lastModified")?
@@ -751,6 +1791,7 @@ impl GooglePlayClient {
             .and_then(|lang| lang.as_str())
             .unwrap_or("")
             .to_string();
+        let language = Some(reviewer_language.clone()).filter(|lang| !lang.is_empty());
 
         // combine device, OS, and language info for territory field
         let territory = {
@@ -771,6 +1812,9 @@ impl GooglePlayClient {
             }
         };
 
+        // The list endpoint already includes any developer reply
+        let response = Self::parse_developer_comment(&review_id, review_data);
+
         Some(Review {
             id: review_id,
             rating,
@@ -780,22 +1824,77 @@ impl GooglePlayClient {
             created_date,
             territory,
             version,
-            response: None,
+            response,
+            original_text,
+            language,
+            thumbs_up: user_comment.get("thumbsUpCount").and_then(|v| v.as_i64()),
+            thumbs_down: user_comment.get("thumbsDownCount").and_then(|v| v.as_i64()),
+            thread: Self::parse_comment_thread(comments),
+            platform: None,
+            app_id: Some(self.config.app_id.clone()),
+            raw: self.config.debug.then(|| review_data.clone()),
         })
     }
 
-    pub async fn submit_response(&mut self, review_id: &str, response_body: &str) -> Result<()> {
-        self.ensure_valid_token().await?;
+    // Each entry in `comments` holds either a `userComment` or a `developerComment`
+    fn parse_comment_thread(comments: &[serde_json::Value]) -> Vec<ThreadComment> {
+        comments
+            .iter()
+            .filter_map(|comment| {
+                let (author, content) = if let Some(user) = comment.get("userComment") {
+                    (CommentAuthor::User, user)
+                } else {
+                    (CommentAuthor::Developer, comment.get("developerComment")?)
+                };
 
-        let token = self.access_token.as_ref().unwrap();
-        let url = format!(
+                let last_modified = content
+                    .get("lastModified")
+                    .and_then(|lm| lm.get("seconds"))
+                    .and_then(|s| s.as_str())
+                    .and_then(|s| s.parse::<i64>().ok())
+                    .and_then(|seconds| chrono::DateTime::from_timestamp(seconds, 0));
+
+                Some(ThreadComment {
+                    author,
+                    text: content.get("text")?.as_str()?.to_string(),
+                    last_modified,
+                })
+            })
+            .collect()
+    }
+
+    fn submission_url(&self, review_id: &str) -> String {
+        format!(
             "{}/applications/{}/reviews/{}:reply",
             GOOGLE_PLAY_API_BASE, self.config.app_id, review_id
-        );
+        )
+    }
 
-        let request_body = serde_json::json!({
+    fn submission_payload(response_body: &str) -> serde_json::Value {
+        serde_json::json!({
             "replyText": response_body
-        });
+        })
+    }
+
+    // A new reply overwrites the previous one, so replacing sends the same request
+    fn preview_request(
+        &self,
+        review_id: &str,
+        response_body: &str,
+    ) -> (&'static str, String, serde_json::Value) {
+        (
+            "POST",
+            self.submission_url(review_id),
+            Self::submission_payload(response_body),
+        )
+    }
+
+    pub async fn submit_response(&mut self, review_id: &str, response_body: &str) -> Result<()> {
+        self.ensure_valid_token().await?;
+
+        let token = self.access_token.as_ref().unwrap();
+        let url = self.submission_url(review_id);
+        let request_body = Self::submission_payload(response_body);
 
         // Debug logging
         use std::io::Write;
@@ -811,7 +1910,7 @@ impl GooglePlayClient {
             .query(&[("access_token", token)])
             .header("Content-Type", "application/json")
             .json(&request_body)
-            .send()
+            .send_limited(&self.limiter)
             .await
             .map_err(|e| {
                 writeln!(log_file, "DEBUG: Android submit request failed: {}", e).ok();
@@ -852,6 +1951,14 @@ impl GooglePlayClient {
         Ok(())
     }
 
+    // The Google Play Developer API can only create or replace replies, and
+    // rejects an empty reply, so there's nothing to call here
+    pub async fn delete_response(&mut self, _review_id: &str) -> Result<()> {
+        Err(anyhow!(
+            "Google Play doesn't allow deleting replies through its API. Edit the reply instead, or delete it in the Play Console"
+        ))
+    }
+
     pub async fn get_review_response(
         &mut self,
         review_id: &str,
@@ -868,7 +1975,7 @@ impl GooglePlayClient {
             .client
             .get(&url)
             .query(&[("access_token", token)])
-            .send()
+            .send_limited(&self.limiter)
             .await
             .map_err(|e| anyhow!("Failed to fetch review: {}", e))?;
 
@@ -884,38 +1991,49 @@ impl GooglePlayClient {
         let review_data: serde_json::Value = serde_json::from_str(&response_text)
             .map_err(|e| anyhow!("Failed to parse review response: {}", e))?;
 
-        // Check if there's a developer reply in the comments
-        if let Some(comments) = review_data.get("comments").and_then(|c| c.as_array()) {
-            for comment in comments {
-                if let Some(developer_comment) = comment.get("developerComment") {
-                    let response_body = developer_comment
-                        .get("text")
-                        .and_then(|t| t.as_str())
-                        .unwrap_or("")
-                        .to_string();
-
-                    let last_modified_str = developer_comment
-                        .get("lastModified")
-                        .and_then(|lm| lm.get("seconds"))
-                        .and_then(|s| s.as_str())
-                        .unwrap_or("0");
-
-                    let last_modified_timestamp = last_modified_str.parse::<i64>().unwrap_or(0);
-                    let last_modified_date =
-                        chrono::DateTime::from_timestamp(last_modified_timestamp, 0)
-                            .unwrap_or_else(|| chrono::Utc::now())
-                            .with_timezone(&chrono::Utc);
-
-                    return Ok(Some(crate::review::ReviewResponse {
-                        id: format!("{}-response", review_id),
-                        response_body,
-                        last_modified_date,
-                        state: crate::review::ResponseState::Published, // Google Play responses are immediately published
-                    }));
-                }
-            }
-        }
+        Ok(Self::parse_developer_comment(review_id, &review_data))
+    }
 
-        Ok(None)
+    // Check if there's a developer reply in the comments
+    fn parse_developer_comment(
+        review_id: &str,
+        review_data: &serde_json::Value,
+    ) -> Option<ReviewResponse> {
+        let comments = review_data.get("comments")?.as_array()?;
+        let developer_comment = comments
+            .iter()
+            .find_map(|comment| comment.get("developerComment"))?;
+
+        let response_body = developer_comment
+            .get("text")
+            .and_then(|t| t.as_str())
+            .unwrap_or("")
+            .to_string();
+
+        let last_modified_str = developer_comment
+            .get("lastModified")
+            .and_then(|lm| lm.get("seconds"))
+            .and_then(|s| s.as_str())
+            .unwrap_or("0");
+
+        let last_modified_timestamp = last_modified_str.parse::<i64>().unwrap_or(0);
+        let last_modified_date = chrono::DateTime::from_timestamp(last_modified_timestamp, 0)
+            .unwrap_or_else(chrono::Utc::now)
+            .with_timezone(&chrono::Utc);
+
+        Some(ReviewResponse {
+            id: format!("{}-response", review_id),
+            response_body,
+            last_modified_date,
+            state: ResponseState::Published, // Google Play responses are immediately published
+        })
     }
 }
+
+// Why a refresh returned fewer reviews than there are
+pub fn stopped_early(fetched: usize, error: &anyhow::Error) -> String {
+    format!(
+        "Refresh stopped after {} reviews, so older ones are missing: {}",
+        fetched, error
+    )
+}
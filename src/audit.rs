@@ -0,0 +1,66 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+use crate::config::{Config, Platform};
+
+// How a submitted response was written. Only recorded with `--tag-ai-responses`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct AiUsage {
+    pub ai_assisted: bool,
+    // The user changed the AI draft before submitting it
+    pub edited_after_ai: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct AuditEntry<'a> {
+    timestamp: DateTime<Utc>,
+    platform: &'a Platform,
+    app_id: &'a str,
+    review_id: &'a str,
+    submitted_by: String,
+    response_body: &'a str,
+    #[serde(flatten)]
+    ai_usage: Option<AiUsage>,
+}
+
+// Appends one JSON line per successfully submitted response. The file is only
+// ever appended to and is flushed after every entry so a crash can't lose it.
+pub fn record_submission(
+    path: &Path,
+    config: &Config,
+    review_id: &str,
+    response_body: &str,
+    ai_usage: AiUsage,
+) -> Result<()> {
+    let entry = AuditEntry {
+        timestamp: Utc::now(),
+        platform: &config.platform,
+        app_id: &config.app_id,
+        review_id,
+        submitted_by: std::env::var("USER")
+            .or_else(|_| std::env::var("USERNAME"))
+            .unwrap_or_else(|_| "unknown".to_string()),
+        response_body,
+        ai_usage: config.tag_ai_responses.then_some(ai_usage),
+    };
+
+    let line = serde_json::to_string(&entry)
+        .map_err(|e| anyhow!("Failed to serialize audit entry: {}", e))?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| anyhow!("Failed to open audit log {}: {}", path.display(), e))?;
+
+    writeln!(file, "{}", line)
+        .and_then(|_| file.flush())
+        .and_then(|_| file.sync_data())
+        .map_err(|e| anyhow!("Failed to write audit log {}: {}", path.display(), e))?;
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::{Config, Platform};
+use crate::review::Review;
+
+// The reviews from the last successful load of one app, used by `--offline`
+// and to open the list straight away on the next online start
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewCache {
+    pub fetched_at: DateTime<Utc>,
+    pub reviews: Vec<Review>,
+}
+
+impl ReviewCache {
+    // A missing file just means the app hasn't been cached yet
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read review cache {}: {}", path.display(), e))?;
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|e| anyhow!("Failed to parse review cache {}: {}", path.display(), e))
+    }
+
+    // Written to a temporary file first so an interrupted save can't corrupt it
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| anyhow!("Failed to create {}: {}", parent.display(), e))?;
+        }
+
+        let content = serde_json::to_string(self)
+            .map_err(|e| anyhow!("Failed to serialize review cache: {}", e))?;
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, content)
+            .and_then(|_| fs::rename(&tmp_path, path))
+            .map_err(|e| anyhow!("Failed to write review cache {}: {}", path.display(), e))
+    }
+}
+
+// ~/.cache/rustpond/<platform>-<app_id>.json, or under $XDG_CACHE_HOME. None
+// when neither is set.
+pub fn path(platform: Platform, app_id: &str) -> Option<PathBuf> {
+    let cache_dir = env::var("XDG_CACHE_HOME")
+        .ok()
+        .map(PathBuf::from)
+        .or_else(|| {
+            env::var("HOME")
+                .ok()
+                .map(|home| PathBuf::from(home).join(".cache"))
+        })?;
+    Some(cache_dir.join("rustpond").join(file_name(platform, app_id)))
+}
+
+// Anything that can't be part of a file name, which only a malformed app id
+// would contain, is replaced
+fn file_name(platform: Platform, app_id: &str) -> String {
+    let app_id: String = app_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let platform = match platform {
+        Platform::Ios => "ios",
+        Platform::Android => "android",
+    };
+    format!("{}-{}.json", platform, app_id)
+}
+
+// The caches of every app in use merged into one, or None unless each of them
+// has one. `--both` keeps a file per store, and the older timestamp is kept.
+pub fn load_all(configs: &[&Config]) -> Result<Option<ReviewCache>> {
+    let mut merged: Option<ReviewCache> = None;
+    for config in configs {
+        let Some(path) = path(config.platform, &config.app_id) else {
+            return Ok(None);
+        };
+        let Some(cache) = ReviewCache::load(&path)? else {
+            return Ok(None);
+        };
+        merged = Some(match merged {
+            Some(mut merged) => {
+                merged.fetched_at = merged.fetched_at.min(cache.fetched_at);
+                merged.reviews.extend(cache.reviews);
+                merged
+            }
+            None => cache,
+        });
+    }
+    Ok(merged)
+}
+
+// Splits the reviews by the store they came from. Reviews are only tagged with
+// a platform under `--both`, so untagged ones belong to the only app in use.
+pub fn save_all(configs: &[&Config], reviews: &[Review]) -> Result<()> {
+    let fetched_at = Utc::now();
+    for config in configs {
+        let Some(path) = path(config.platform, &config.app_id) else {
+            continue;
+        };
+        let cache = ReviewCache {
+            fetched_at,
+            reviews: reviews
+                .iter()
+                .filter(|review| review.platform.unwrap_or(config.platform) == config.platform)
+                .cloned()
+                .collect(),
+        };
+        cache.save(&path)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_name_is_platform_and_app_id() {
+        assert_eq!(file_name(Platform::Ios, "123456789"), "ios-123456789.json");
+        assert_eq!(
+            file_name(Platform::Android, "com.example.app"),
+            "android-com.example.app.json"
+        );
+        assert_eq!(file_name(Platform::Ios, "../etc/x"), "ios-.._etc_x.json");
+    }
+
+    #[test]
+    fn saved_cache_loads_again() {
+        let path = env::temp_dir()
+            .join(format!("rustpond-test-{}", uuid::Uuid::new_v4()))
+            .join("ios-1.json");
+        assert!(ReviewCache::load(&path).unwrap().is_none());
+
+        let reviews: Vec<Review> =
+            serde_json::from_str(include_str!("../demo/reviews.json")).unwrap();
+        let cache = ReviewCache {
+            fetched_at: Utc::now(),
+            reviews: reviews.clone(),
+        };
+        cache.save(&path).unwrap();
+        let loaded = ReviewCache::load(&path).unwrap().unwrap();
+        fs::remove_dir_all(path.parent().unwrap()).ok();
+
+        assert_eq!(loaded.fetched_at, cache.fetched_at);
+        let ids = |reviews: &[Review]| reviews.iter().map(|r| r.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&loaded.reviews), ids(&reviews));
+    }
+}
@@ -1,96 +1,1084 @@
 use anyhow::{anyhow, Result};
+use chrono::format::{Item, StrftimeItems};
 use clap::ArgMatches;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
 use std::env;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
 
-#[derive(Debug, Clone)]
+use tui::style::{Color, Modifier, Style};
+
+use crate::review::Review;
+use crate::sensitive::SensitiveTerms;
+use crate::spam::SpamRules;
+use crate::timezone::DisplayTimezone;
+
+const DEFAULT_PREVIEW_CHARS: usize = 500;
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+const DEFAULT_AI_ATTEMPTS: usize = 2;
+const DEFAULT_AI_TIMEOUT_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Platform {
     Ios,
     Android,
 }
 
+impl Platform {
+    // Short label for list badges
+    pub fn badge(&self) -> &'static str {
+        match self {
+            Platform::Ios => "iOS",
+            Platform::Android => "Android",
+        }
+    }
+
+    // Maximum response length enforced by the store, in characters
+    pub fn character_limit(&self) -> Option<usize> {
+        match self {
+            Platform::Android => Some(350),
+            Platform::Ios => None, // No limit for iOS
+        }
+    }
+}
+
+// What Ctrl+Left/Right do in the response editor. Many terminals report Cmd as
+// Ctrl, so users used to Cmd+Arrow line jumps can opt into `line`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CtrlArrowMode {
+    Word,
+    Line,
+}
+
+// Order reviews are fetched in. App Store Connect sorts server side; Google
+// Play only returns newest first, so its pages are reordered after fetching.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FetchSort {
+    Newest,
+    Oldest,
+    LowestRated,
+    HighestRated,
+}
+
+impl FetchSort {
+    fn parse(value: &str) -> Result<Self> {
+        match value {
+            "-createdDate" => Ok(FetchSort::Newest),
+            "createdDate" => Ok(FetchSort::Oldest),
+            "rating" => Ok(FetchSort::LowestRated),
+            "-rating" => Ok(FetchSort::HighestRated),
+            other => Err(anyhow!(
+                "Invalid fetch sort '{}'. Expected '-createdDate', 'createdDate', 'rating' or '-rating'",
+                other
+            )),
+        }
+    }
+
+    // Value of the App Store Connect `sort` query parameter
+    pub fn query_value(&self) -> &'static str {
+        match self {
+            FetchSort::Newest => "-createdDate",
+            FetchSort::Oldest => "createdDate",
+            FetchSort::LowestRated => "rating",
+            FetchSort::HighestRated => "-rating",
+        }
+    }
+
+    // Reviews with the same rating stay newest first
+    pub fn sort(&self, reviews: &mut [Review]) {
+        match self {
+            FetchSort::Newest => reviews.sort_by_key(|r| Reverse(r.created_date)),
+            FetchSort::Oldest => reviews.sort_by_key(|r| r.created_date),
+            FetchSort::LowestRated => reviews.sort_by_key(|r| (r.rating, Reverse(r.created_date))),
+            FetchSort::HighestRated => {
+                reviews.sort_by_key(|r| (Reverse(r.rating), Reverse(r.created_date)))
+            }
+        }
+    }
+}
+
+// Settings read from rustpond.toml. Everything is optional; command line flags
+// override the file, and the file overrides environment variables.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigFile {
+    pub ios: IosSettings,
+    pub android: AndroidSettings,
+    pub ai: AiSettings,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct IosSettings {
+    pub app_id: Option<String>,
+    pub key_id: Option<String>,
+    pub issuer_id: Option<String>,
+    pub private_key: Option<PathBuf>,
+    pub individual_key: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AndroidSettings {
+    pub app_id: Option<String>,
+    pub service_account: Option<PathBuf>,
+}
+
+// Replace the built-in AI defaults. An --ai-config file replaces these in turn.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AiSettings {
+    pub model: Option<String>,
+    pub keywords: Option<Vec<String>>,
+    pub support_email: Option<String>,
+}
+
+impl ConfigFile {
+    // `--config` (or RUSTPOND_CONFIG) must exist. Otherwise ./rustpond.toml and
+    // then the XDG config directory are tried, and having neither is fine.
+    fn find(
+        matches: &ArgMatches,
+        var: &dyn Fn(&str) -> Option<String>,
+    ) -> Result<Option<(PathBuf, Self)>> {
+        if let Some(path) = matches
+            .get_one::<String>("config")
+            .map(PathBuf::from)
+            .or_else(|| var("RUSTPOND_CONFIG").map(PathBuf::from))
+        {
+            let file = Config::from_file(&path)?;
+            return Ok(Some((path, file)));
+        }
+
+        let config_dir = var("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| var("HOME").map(|home| PathBuf::from(home).join(".config")));
+        let candidates = [
+            Some(PathBuf::from("rustpond.toml")),
+            config_dir.map(|dir| dir.join("rustpond").join("config.toml")),
+        ];
+        for path in candidates.into_iter().flatten() {
+            if path.is_file() {
+                let file = Config::from_file(&path)?;
+                return Ok(Some((path, file)));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub platform: Platform,
     pub app_id: String,
     pub key_id: Option<String>,
+    // None when signing with an individual key, which has no issuer
     pub issuer_id: Option<String>,
+    // Sign tokens as an individual API key rather than a team key
+    pub individual_key: bool,
     pub private_key_path: Option<PathBuf>,
     pub service_account_path: Option<PathBuf>,
     pub openai_api_key: Option<String>,
+    pub audit_log_path: Option<PathBuf>,
+    pub signature: Option<String>,
+    // Appended to responses drafted by AI
+    pub ai_disclosure: Option<String>,
+    // Record in the audit log whether each response was drafted by AI
+    pub tag_ai_responses: bool,
+    pub ctrl_arrows: CtrlArrowMode,
+    pub tidy_whitespace: bool,
+    // Collapse runs of blank lines in displayed review text
+    pub collapse_blank_lines: bool,
+    // Start with the help panel under the review list hidden
+    pub hide_help: bool,
+    // Browse reviews and their responses without any way to write or submit one
+    pub read_only: bool,
+    // Review text in the details pane is cut off after this many characters
+    // until expanded. None shows it in full.
+    pub preview_chars: Option<usize>,
+    // Rows moved by PageUp/PageDown in the review list. None uses the number
+    // of rows that fit on screen.
+    pub list_page_size: Option<usize>,
+    pub translation_language: Option<String>,
+    pub require_ai: bool,
+    pub display_timezone: DisplayTimezone,
+    // strftime-style date shown in the list. The details pane adds the time.
+    pub date_format: String,
+    pub ai_config_path: Option<PathBuf>,
+    // The rustpond.toml that was found, if any
+    pub config_file_path: Option<PathBuf>,
+    // AI defaults from its [ai] section
+    pub ai_settings: AiSettings,
+    // Overrides `model` from the AI config file
+    pub ai_model: Option<String>,
+    pub min_response_chars: Option<usize>,
+    // How many AI drafts batch flows request at once
+    pub ai_concurrency: usize,
+    // Overrides the per-reply token budget derived from the character limit
+    pub ai_max_tokens: Option<u32>,
+    // Leave the support email and custom prompt out of AI prompts, for every
+    // review or only those rated below `promotion_min_rating`
+    pub no_promotions: bool,
+    pub promotion_min_rating: Option<i32>,
+    // After this many failed attempts, or once `ai_timeout` has passed, 'a'
+    // falls back to the template response
+    pub ai_attempts: usize,
+    pub ai_timeout: Duration,
+    // How many store API requests may be in flight at once
+    pub max_connections: usize,
+    pub state_path: Option<PathBuf>,
+    pub spam_rules: SpamRules,
+    // Empty unless --sensitive-terms is given
+    pub sensitive_terms: SensitiveTerms,
+    pub review_id: Option<String>,
+    // Bearer token required by --serve, only read from RUSTPOND_SERVE_TOKEN so it
+    // doesn't show up in the process list
+    pub serve_token: Option<String>,
+    pub fetch_all: bool,
+    // 'r' and auto-refresh only fetch the newest page and merge it in
+    pub quick_refresh: bool,
+    pub fetch_sort: FetchSort,
+    // Browse cached reviews and queue responses without touching the network
+    pub offline: bool,
+    // Don't start from the cached reviews while fresh ones load
+    pub no_cache: bool,
+    // Demo mode: reviews are read from this JSON file and nothing is submitted
+    pub reviews_file: Option<PathBuf>,
+    // Keep each review's raw JSON for the inspector popup
+    pub debug: bool,
+    // Show the submit request instead of sending it
+    pub print_payload: bool,
+    pub auto_refresh: Option<Duration>,
+    pub highlight_symbol: String,
+    pub highlight_style: Style,
 }
 
 impl Config {
+    // Parses a rustpond.toml. Relative key paths are taken from the file's
+    // directory, so the file works from anywhere.
+    pub fn from_file(path: &Path) -> Result<ConfigFile> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read config file {}: {}", path.display(), e))?;
+        let mut file: ConfigFile = toml::from_str(&content)
+            .map_err(|e| anyhow!("Failed to parse config file {}: {}", path.display(), e))?;
+
+        let dir = path.parent().unwrap_or(Path::new("."));
+        for key_path in [&mut file.ios.private_key, &mut file.android.service_account]
+            .into_iter()
+            .flatten()
+        {
+            if key_path.is_relative() {
+                *key_path = dir.join(&*key_path);
+            }
+        }
+
+        Ok(file)
+    }
+
     pub fn from_args_and_env(matches: &ArgMatches) -> Result<Self> {
+        Self::from_args(matches, &|name| env::var(name).ok())
+    }
+
+    // Like `from_args_and_env`, with environment variables looked up by `var`
+    pub fn from_args(matches: &ArgMatches, var: &dyn Fn(&str) -> Option<String>) -> Result<Self> {
         let platform = if matches.get_flag("android") {
             Platform::Android
         } else {
             Platform::Ios
         };
+        Self::for_platform_with(matches, platform, var)
+    }
+
+    // Used directly by `--both`, which needs a config for each store
+    pub fn for_platform(matches: &ArgMatches, platform: Platform) -> Result<Self> {
+        Self::for_platform_with(matches, platform, &|name| env::var(name).ok())
+    }
+
+    fn for_platform_with(
+        matches: &ArgMatches,
+        platform: Platform,
+        var: &dyn Fn(&str) -> Option<String>,
+    ) -> Result<Self> {
+        // Demo reviews come from a file, so no store credentials are needed
+        let reviews_file = matches.get_one::<String>("reviews-file").map(PathBuf::from);
+
+        let (config_file_path, file) = match ConfigFile::find(matches, var)? {
+            Some((path, file)) => (Some(path), file),
+            None => (None, ConfigFile::default()),
+        };
 
         let app_id = matches
             .get_one::<String>("app-id")
             .cloned()
             .or_else(|| match platform {
-                Platform::Ios => env::var("APP_STORE_APP_ID").ok(),
-                Platform::Android => env::var("GOOGLE_PLAY_PACKAGE_NAME").ok(),
+                Platform::Ios => file.ios.app_id.clone(),
+                Platform::Android => file.android.app_id.clone(),
             })
+            .or_else(|| match platform {
+                Platform::Ios => var("APP_STORE_APP_ID"),
+                Platform::Android => var("GOOGLE_PLAY_PACKAGE_NAME"),
+            })
+            .or_else(|| reviews_file.as_ref().map(|_| "demo".to_string()))
             .ok_or_else(|| match platform {
-                Platform::Ios => anyhow!("App ID is required. Use --app-id or set APP_STORE_APP_ID environment variable"),
-                Platform::Android => anyhow!("Package name is required. Use --app-id or set GOOGLE_PLAY_PACKAGE_NAME environment variable"),
+                Platform::Ios => anyhow!("App ID is required. Use --app-id, set app_id under [ios] in rustpond.toml, or set APP_STORE_APP_ID environment variable"),
+                Platform::Android => anyhow!("Package name is required. Use --app-id, set app_id under [android] in rustpond.toml, or set GOOGLE_PLAY_PACKAGE_NAME environment variable"),
             })?;
+        if reviews_file.is_none() {
+            check_app_id_format(&app_id, platform, matches.get_flag("strict"))?;
+        }
 
-        match platform {
+        let individual_key = matches.get_flag("individual-key")
+            || file.ios.individual_key.unwrap_or_else(|| {
+                var("APP_STORE_CONNECT_INDIVIDUAL_KEY")
+                    .is_some_and(|value| value == "1" || value == "true")
+            });
+
+        let (key_id, issuer_id, private_key_path, service_account_path) = match platform {
+            _ if reviews_file.is_some() => (None, None, None, None),
             Platform::Ios => {
                 let key_id = matches
                     .get_one::<String>("key-id")
                     .cloned()
-                    .or_else(|| env::var("APP_STORE_CONNECT_KEY_ID").ok())
-                    .ok_or_else(|| anyhow!("Key ID is required for iOS. Use --key-id or set APP_STORE_CONNECT_KEY_ID environment variable"))?;
+                    .or_else(|| file.ios.key_id.clone())
+                    .or_else(|| var("APP_STORE_CONNECT_KEY_ID"))
+                    .ok_or_else(|| anyhow!("Key ID is required for iOS. Use --key-id, set key_id under [ios] in rustpond.toml, or set APP_STORE_CONNECT_KEY_ID environment variable"))?;
 
                 let issuer_id = matches
                     .get_one::<String>("issuer-id")
                     .cloned()
-                    .or_else(|| env::var("APP_STORE_CONNECT_ISSUER_ID").ok())
-                    .ok_or_else(|| anyhow!("Issuer ID is required for iOS. Use --issuer-id or set APP_STORE_CONNECT_ISSUER_ID environment variable"))?;
+                    .or_else(|| file.ios.issuer_id.clone())
+                    .or_else(|| var("APP_STORE_CONNECT_ISSUER_ID"));
+                let issuer_id = match issuer_id {
+                    _ if individual_key => None,
+                    Some(issuer_id) => Some(issuer_id),
+                    None => return Err(anyhow!("Issuer ID is required for iOS. Use --issuer-id, set issuer_id under [ios] in rustpond.toml, or set APP_STORE_CONNECT_ISSUER_ID environment variable, or pass --individual-key when signing with an individual API key")),
+                };
 
                 let private_key_path = matches
                     .get_one::<String>("private-key")
                     .map(PathBuf::from)
-                    .or_else(|| env::var("APP_STORE_CONNECT_PRIVATE_KEY_PATH").ok().map(PathBuf::from))
-                    .ok_or_else(|| anyhow!("Private key path is required for iOS. Use --private-key or set APP_STORE_CONNECT_PRIVATE_KEY_PATH environment variable"))?;
-
-                let openai_api_key = env::var("OPENAI_API_KEY").ok();
-
-                Ok(Config {
-                    platform,
-                    app_id,
-                    key_id: Some(key_id),
-                    issuer_id: Some(issuer_id),
-                    private_key_path: Some(private_key_path),
-                    service_account_path: None,
-                    openai_api_key,
-                })
+                    .or_else(|| file.ios.private_key.clone())
+                    .or_else(|| var("APP_STORE_CONNECT_PRIVATE_KEY_PATH").map(PathBuf::from))
+                    .ok_or_else(|| anyhow!("Private key path is required for iOS. Use --private-key, set private_key under [ios] in rustpond.toml, or set APP_STORE_CONNECT_PRIVATE_KEY_PATH environment variable"))?;
+
+                (Some(key_id), issuer_id, Some(private_key_path), None)
             }
             Platform::Android => {
                 let service_account_path = matches
                     .get_one::<String>("service-account")
                     .map(PathBuf::from)
-                    .or_else(|| env::var("GOOGLE_PLAY_SERVICE_ACCOUNT_PATH").ok().map(PathBuf::from))
-                    .ok_or_else(|| anyhow!("Service account path is required for Android. Use --service-account or set GOOGLE_PLAY_SERVICE_ACCOUNT_PATH environment variable"))?;
-
-                let openai_api_key = env::var("OPENAI_API_KEY").ok();
-
-                Ok(Config {
-                    platform,
-                    app_id,
-                    key_id: None,
-                    issuer_id: None,
-                    private_key_path: None,
-                    service_account_path: Some(service_account_path),
-                    openai_api_key,
-                })
+                    .or_else(|| file.android.service_account.clone())
+                    .or_else(|| var("GOOGLE_PLAY_SERVICE_ACCOUNT_PATH").map(PathBuf::from))
+                    .ok_or_else(|| anyhow!("Service account path is required for Android. Use --service-account, set service_account under [android] in rustpond.toml, or set GOOGLE_PLAY_SERVICE_ACCOUNT_PATH environment variable"))?;
+
+                (None, None, None, Some(service_account_path))
+            }
+        };
+
+        let openai_api_key = var("OPENAI_API_KEY");
+
+        let audit_log_path = matches
+            .get_one::<String>("audit-log")
+            .map(PathBuf::from)
+            .or_else(|| var("RUSTPOND_AUDIT_LOG").map(PathBuf::from));
+
+        let signature = matches
+            .get_one::<String>("signature")
+            .cloned()
+            .or_else(|| var("RUSTPOND_SIGNATURE"))
+            .filter(|signature| !signature.trim().is_empty());
+
+        let ai_disclosure = matches
+            .get_one::<String>("ai-disclosure")
+            .cloned()
+            .or_else(|| var("RUSTPOND_AI_DISCLOSURE"))
+            .filter(|disclosure| !disclosure.trim().is_empty());
+
+        let ctrl_arrows = match matches
+            .get_one::<String>("ctrl-arrows")
+            .cloned()
+            .or_else(|| var("RUSTPOND_CTRL_ARROWS"))
+            .as_deref()
+        {
+            None | Some("word") => CtrlArrowMode::Word,
+            Some("line") => CtrlArrowMode::Line,
+            Some(other) => {
+                return Err(anyhow!(
+                    "Invalid Ctrl+Arrow mode '{}'. Expected 'word' or 'line'",
+                    other
+                ))
             }
+        };
+
+        let tidy_whitespace = !matches.get_flag("keep-whitespace");
+
+        let translation_language = matches
+            .get_one::<String>("translate-to")
+            .cloned()
+            .or_else(|| var("GOOGLE_PLAY_TRANSLATION_LANGUAGE"));
+
+        let require_ai = matches.get_flag("require-ai");
+
+        let display_timezone = match matches
+            .get_one::<String>("timezone")
+            .cloned()
+            .or_else(|| var("RUSTPOND_TIMEZONE"))
+        {
+            Some(value) => DisplayTimezone::parse(&value)?,
+            None => DisplayTimezone::Utc,
+        };
+
+        let date_format = matches
+            .get_one::<String>("date-format")
+            .cloned()
+            .or_else(|| var("RUSTPOND_DATE_FORMAT"))
+            .unwrap_or_else(|| DEFAULT_DATE_FORMAT.to_string());
+        validate_date_format(&date_format)?;
+
+        let fetch_sort = match matches
+            .get_one::<String>("fetch-sort")
+            .cloned()
+            .or_else(|| var("RUSTPOND_FETCH_SORT"))
+        {
+            Some(value) => FetchSort::parse(&value)?,
+            None => FetchSort::Newest,
+        };
+
+        let ai_config_path = matches
+            .get_one::<String>("ai-config")
+            .map(PathBuf::from)
+            .or_else(|| var("RUSTPOND_AI_CONFIG").map(PathBuf::from));
+
+        let ai_model = matches
+            .get_one::<String>("ai-model")
+            .cloned()
+            .or_else(|| var("RUSTPOND_AI_MODEL"))
+            .filter(|model| !model.trim().is_empty());
+
+        let min_response_chars = number_setting(
+            matches,
+            var,
+            "min-response-chars",
+            "RUSTPOND_MIN_RESPONSE_CHARS",
+            "minimum response length",
+            "a number of characters",
+            |_: &usize| true,
+        )?;
+
+        let preview_chars = number_setting(
+            matches,
+            var,
+            "preview-chars",
+            "RUSTPOND_PREVIEW_CHARS",
+            "preview length",
+            "a number of characters, or 0 to always show the full text",
+            |_: &usize| true,
+        )?
+        .map_or(Some(DEFAULT_PREVIEW_CHARS), |chars| {
+            (chars > 0).then_some(chars)
+        });
+
+        let list_page_size = number_setting(
+            matches,
+            var,
+            "list-page-size",
+            "RUSTPOND_LIST_PAGE_SIZE",
+            "list page size",
+            "a positive number of rows",
+            |&size: &usize| size > 0,
+        )?;
+
+        let ai_max_tokens = number_setting(
+            matches,
+            var,
+            "ai-max-tokens",
+            "RUSTPOND_AI_MAX_TOKENS",
+            "AI max tokens",
+            "a number of at least 1",
+            |&n: &u32| n >= 1,
+        )?;
+
+        let promotion_min_rating = number_setting(
+            matches,
+            var,
+            "promotion-min-rating",
+            "RUSTPOND_PROMOTION_MIN_RATING",
+            "promotion minimum rating",
+            "a star rating from 1 to 5",
+            |rating: &i32| (1..=5).contains(rating),
+        )?;
+
+        let ai_attempts = number_setting(
+            matches,
+            var,
+            "ai-attempts",
+            "RUSTPOND_AI_ATTEMPTS",
+            "AI attempts",
+            "a number of at least 1",
+            |&n: &usize| n >= 1,
+        )?
+        .unwrap_or(DEFAULT_AI_ATTEMPTS);
+
+        let ai_timeout = number_setting(
+            matches,
+            var,
+            "ai-timeout-secs",
+            "RUSTPOND_AI_TIMEOUT_SECS",
+            "AI timeout",
+            "a number of seconds",
+            |&secs: &u64| secs > 0,
+        )?
+        .map_or(
+            Duration::from_secs(DEFAULT_AI_TIMEOUT_SECS),
+            Duration::from_secs,
+        );
+
+        let ai_concurrency = number_setting(
+            matches,
+            var,
+            "ai-concurrency",
+            "RUSTPOND_AI_CONCURRENCY",
+            "AI concurrency",
+            "a number of at least 1",
+            |&n: &usize| n >= 1,
+        )?
+        .unwrap_or(1);
+
+        let max_connections = number_setting(
+            matches,
+            var,
+            "max-connections",
+            "RUSTPOND_MAX_CONNECTIONS",
+            "max connections",
+            "a number of at least 1",
+            |&n: &usize| n >= 1,
+        )?
+        .unwrap_or(4);
+
+        let auto_refresh = number_setting(
+            matches,
+            var,
+            "auto-refresh-secs",
+            "RUSTPOND_AUTO_REFRESH_SECS",
+            "auto-refresh interval",
+            "a number of seconds",
+            |&secs: &u64| secs > 0,
+        )?
+        .map(Duration::from_secs);
+
+        // Local review notes live under the XDG data directory unless overridden
+        let state_path = matches
+            .get_one::<String>("state-file")
+            .map(PathBuf::from)
+            .or_else(|| var("RUSTPOND_STATE_FILE").map(PathBuf::from))
+            .or_else(|| {
+                var("XDG_DATA_HOME")
+                    .map(PathBuf::from)
+                    .or_else(|| {
+                        var("HOME").map(|home| PathBuf::from(home).join(".local").join("share"))
+                    })
+                    .map(|dir| dir.join("rustpond").join("review_state.json"))
+            });
+
+        let spam_rules = match matches
+            .get_one::<String>("spam-rules")
+            .map(PathBuf::from)
+            .or_else(|| var("RUSTPOND_SPAM_RULES").map(PathBuf::from))
+        {
+            Some(path) => SpamRules::from_file(&path)?,
+            None => SpamRules::default(),
+        };
+
+        let sensitive_terms = match matches
+            .get_one::<String>("sensitive-terms")
+            .map(PathBuf::from)
+            .or_else(|| var("RUSTPOND_SENSITIVE_TERMS").map(PathBuf::from))
+        {
+            Some(path) => SensitiveTerms::from_file(&path)?,
+            None => SensitiveTerms::default(),
+        };
+
+        let highlight_symbol = matches
+            .get_one::<String>("highlight-symbol")
+            .cloned()
+            .or_else(|| var("RUSTPOND_HIGHLIGHT_SYMBOL"))
+            .unwrap_or_else(|| ">> ".to_string());
+
+        let highlight_style = parse_highlight_style(
+            &matches
+                .get_one::<String>("highlight-style")
+                .cloned()
+                .or_else(|| var("RUSTPOND_HIGHLIGHT_STYLE"))
+                .unwrap_or_else(|| "reversed".to_string()),
+        )?;
+
+        Ok(Config {
+            platform,
+            app_id,
+            key_id,
+            issuer_id,
+            individual_key,
+            private_key_path,
+            service_account_path,
+            openai_api_key,
+            audit_log_path,
+            signature,
+            ctrl_arrows,
+            tidy_whitespace,
+            collapse_blank_lines: matches.get_flag("collapse-blank-lines"),
+            read_only: matches.get_flag("read-only")
+                || var("RUSTPOND_READ_ONLY").is_some_and(|value| value == "1" || value == "true"),
+            hide_help: matches.get_flag("hide-help")
+                || var("RUSTPOND_HIDE_HELP").is_some_and(|value| value == "1" || value == "true"),
+            preview_chars,
+            list_page_size,
+            ai_disclosure,
+            tag_ai_responses: matches.get_flag("tag-ai-responses"),
+            translation_language,
+            require_ai,
+            display_timezone,
+            date_format,
+            ai_config_path,
+            config_file_path,
+            ai_settings: file.ai,
+            ai_model,
+            min_response_chars,
+            ai_concurrency,
+            ai_max_tokens,
+            no_promotions: matches.get_flag("no-promotions")
+                || var("RUSTPOND_NO_PROMOTIONS")
+                    .is_some_and(|value| value == "1" || value == "true"),
+            promotion_min_rating,
+            ai_attempts,
+            ai_timeout,
+            max_connections,
+            state_path,
+            spam_rules,
+            sensitive_terms,
+            review_id: matches.get_one::<String>("review-id").cloned(),
+            serve_token: var("RUSTPOND_SERVE_TOKEN").filter(|token| !token.is_empty()),
+            fetch_all: matches.get_flag("fetch-all"),
+            quick_refresh: matches.get_flag("quick-refresh")
+                || var("RUSTPOND_QUICK_REFRESH")
+                    .is_some_and(|value| value == "1" || value == "true"),
+            fetch_sort,
+            offline: matches.get_flag("offline"),
+            no_cache: matches.get_flag("no-cache")
+                || var("RUSTPOND_NO_CACHE").is_some_and(|value| value == "1" || value == "true"),
+            reviews_file,
+            debug: matches.get_flag("debug"),
+            print_payload: matches.get_flag("print-payload"),
+            auto_refresh,
+            highlight_symbol,
+            highlight_style,
+        })
+    }
+}
+
+// A number from `flag`, or else from `env_var`. None when neither is set, and
+// an error naming the setting when the value isn't a number or fails `valid`.
+fn number_setting<T: FromStr>(
+    matches: &ArgMatches,
+    var: &dyn Fn(&str) -> Option<String>,
+    flag: &str,
+    env_var: &str,
+    name: &str,
+    expected: &str,
+    valid: impl Fn(&T) -> bool,
+) -> Result<Option<T>> {
+    let Some(value) = matches
+        .get_one::<String>(flag)
+        .cloned()
+        .or_else(|| var(env_var))
+    else {
+        return Ok(None);
+    };
+
+    match value.trim().parse::<T>() {
+        Ok(number) if valid(&number) => Ok(Some(number)),
+        _ => Err(anyhow!(
+            "Invalid {} '{}'. Expected {}",
+            name,
+            value,
+            expected
+        )),
+    }
+}
+
+// Parses a comma separated list like "bold,fg=yellow,bg=#303030"
+fn parse_highlight_style(spec: &str) -> Result<Style> {
+    let mut style = Style::default();
+
+    for part in spec
+        .split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+    {
+        style = match part.to_lowercase().as_str() {
+            "reversed" => style.add_modifier(Modifier::REVERSED),
+            "bold" => style.add_modifier(Modifier::BOLD),
+            "italic" => style.add_modifier(Modifier::ITALIC),
+            "underlined" => style.add_modifier(Modifier::UNDERLINED),
+            other => match other.split_once('=') {
+                Some(("fg", color)) => style.fg(parse_color(color)?),
+                Some(("bg", color)) => style.bg(parse_color(color)?),
+                _ => {
+                    return Err(anyhow!(
+                        "Invalid highlight style '{}'. Use reversed, bold, italic, underlined, fg=COLOR or bg=COLOR",
+                        part
+                    ))
+                }
+            },
+        };
+    }
+
+    Ok(style)
+}
+
+// An App Store id passed with --android, or a package name without it, would
+// otherwise only show up as a 404 from the store
+fn check_app_id_format(app_id: &str, platform: Platform, strict: bool) -> Result<()> {
+    let problem = match platform {
+        Platform::Ios if !app_id.chars().all(|c| c.is_ascii_digit()) => format!(
+            "App id '{}' doesn't look like a numeric App Store id.{}",
+            app_id,
+            if looks_like_package_name(app_id) {
+                " Package names are for Google Play, so pass --android"
+            } else {
+                ""
+            }
+        ),
+        Platform::Android if !looks_like_package_name(app_id) => format!(
+            "'{}' doesn't look like a Google Play package name such as com.example.app.{}",
+            app_id,
+            if app_id.chars().all(|c| c.is_ascii_digit()) {
+                " Numeric ids are for the App Store, so leave out --android"
+            } else {
+                ""
+            }
+        ),
+        _ => return Ok(()),
+    };
+
+    if strict {
+        return Err(anyhow!(problem));
+    }
+    eprintln!("Warning: {} (--strict makes this an error)", problem);
+    Ok(())
+}
+
+// Two or more dot-separated segments, each starting with a letter
+fn looks_like_package_name(value: &str) -> bool {
+    let segments: Vec<&str> = value.split('.').collect();
+    segments.len() >= 2
+        && segments.iter().all(|segment| {
+            segment.starts_with(|c: char| c.is_ascii_alphabetic())
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        })
+}
+
+// chrono only reports a bad format string when a date is formatted with it,
+// and then panics inside Display, so catch it at startup instead
+fn validate_date_format(format: &str) -> Result<()> {
+    if format.trim().is_empty() {
+        return Err(anyhow!("--date-format can't be empty"));
+    }
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(anyhow!(
+            "Invalid --date-format '{}'. Use strftime specifiers like '%d/%m/%Y'",
+            format
+        ));
+    }
+    Ok(())
+}
+
+fn parse_color(value: &str) -> Result<Color> {
+    let color = match value {
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "gray" => Color::Gray,
+        "darkgray" => Color::DarkGray,
+        "lightred" => Color::LightRed,
+        "lightgreen" => Color::LightGreen,
+        "lightyellow" => Color::LightYellow,
+        "lightblue" => Color::LightBlue,
+        "lightmagenta" => Color::LightMagenta,
+        "lightcyan" => Color::LightCyan,
+        "white" => Color::White,
+        hex if hex.starts_with('#') && hex.len() == 7 && hex.is_ascii() => {
+            let channel = |range| u8::from_str_radix(&hex[range], 16);
+            match (channel(1..3), channel(3..5), channel(5..7)) {
+                (Ok(r), Ok(g), Ok(b)) => Color::Rgb(r, g, b),
+                _ => return Err(anyhow!("Invalid color '{}'", value)),
+            }
+        }
+        _ => return Err(anyhow!("Invalid color '{}'", value)),
+    };
+    Ok(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn matches(args: &[&str]) -> ArgMatches {
+        crate::build_cli().get_matches_from(std::iter::once("rustpond").chain(args.iter().copied()))
+    }
+
+    #[test]
+    fn number_setting_reads_the_flag() {
+        let matches = matches(&["--max-connections", " 8 "]);
+        let value = number_setting(
+            &matches,
+            &|_| None,
+            "max-connections",
+            "RUSTPOND_MAX_CONNECTIONS",
+            "max connections",
+            "a number of at least 1",
+            |&n: &usize| n >= 1,
+        );
+        assert_eq!(value.unwrap(), Some(8));
+    }
+
+    #[test]
+    fn number_setting_is_none_when_not_given() {
+        let value = number_setting(
+            &matches(&[]),
+            &|_| None,
+            "max-connections",
+            "RUSTPOND_MAX_CONNECTIONS",
+            "max connections",
+            "a number of at least 1",
+            |&n: &usize| n >= 1,
+        );
+        assert_eq!(value.unwrap(), None);
+    }
+
+    #[test]
+    fn number_setting_names_the_setting_when_invalid() {
+        for given in ["0", "many"] {
+            let error = number_setting(
+                &matches(&["--max-connections", given]),
+                &|_| None,
+                "max-connections",
+                "RUSTPOND_MAX_CONNECTIONS",
+                "max connections",
+                "a number of at least 1",
+                |&n: &usize| n >= 1,
+            )
+            .unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                format!(
+                    "Invalid max connections '{}'. Expected a number of at least 1",
+                    given
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn well_formed_app_ids_pass_strict_checks() {
+        check_app_id_format("123456789", Platform::Ios, true).unwrap();
+        check_app_id_format("com.example.app_2", Platform::Android, true).unwrap();
+    }
+
+    #[test]
+    fn misplaced_app_ids_only_warn_without_strict() {
+        check_app_id_format("com.example.app", Platform::Ios, false).unwrap();
+        check_app_id_format("123456789", Platform::Android, false).unwrap();
+    }
+
+    #[test]
+    fn misplaced_app_ids_are_errors_with_strict() {
+        let error = check_app_id_format("com.example.app", Platform::Ios, true).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "App id 'com.example.app' doesn't look like a numeric App Store id. Package names are for Google Play, so pass --android"
+        );
+        let error = check_app_id_format("my app", Platform::Ios, true).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "App id 'my app' doesn't look like a numeric App Store id."
+        );
+
+        let error = check_app_id_format("123456789", Platform::Android, true).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "'123456789' doesn't look like a Google Play package name such as com.example.app. Numeric ids are for the App Store, so leave out --android"
+        );
+        for app_id in ["example", "com.1example", "com..app"] {
+            assert!(
+                check_app_id_format(app_id, Platform::Android, true).is_err(),
+                "{}",
+                app_id
+            );
         }
     }
-}
\ No newline at end of file
+
+    // Looks environment variables up in `vars` instead of the real environment
+    fn with_vars(args: &[&str], vars: &[(&str, &str)]) -> Result<Config> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        Config::from_args(&matches(args), &|name| vars.get(name).cloned())
+    }
+
+    // A rustpond.toml in its own temporary directory
+    fn config_file(content: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("rustpond-test-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("rustpond.toml");
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn from_file_reads_every_section() {
+        let path = config_file(
+            r#"
+            [ios]
+            app_id = "123456789"
+            key_id = "KEY"
+            private_key = "keys/AuthKey.p8"
+            individual_key = true
+
+            [android]
+            app_id = "com.example.app"
+            service_account = "/etc/rustpond/service-account.json"
+
+            [ai]
+            model = "gpt-4o-mini"
+            keywords = ["fast", "simple"]
+            support_email = "help@example.com"
+            "#,
+        );
+        let file = Config::from_file(&path).unwrap();
+
+        assert_eq!(file.ios.app_id.as_deref(), Some("123456789"));
+        assert_eq!(file.ios.key_id.as_deref(), Some("KEY"));
+        assert_eq!(file.ios.issuer_id, None);
+        assert_eq!(file.ios.individual_key, Some(true));
+        // Relative to the file, absolute paths kept as they are
+        assert_eq!(
+            file.ios.private_key,
+            Some(path.parent().unwrap().join("keys/AuthKey.p8"))
+        );
+        assert_eq!(
+            file.android.service_account,
+            Some(PathBuf::from("/etc/rustpond/service-account.json"))
+        );
+        assert_eq!(file.android.app_id.as_deref(), Some("com.example.app"));
+        assert_eq!(file.ai.model.as_deref(), Some("gpt-4o-mini"));
+        assert_eq!(
+            file.ai.keywords,
+            Some(vec!["fast".to_string(), "simple".to_string()])
+        );
+        assert_eq!(file.ai.support_email.as_deref(), Some("help@example.com"));
+        fs::remove_dir_all(path.parent().unwrap()).ok();
+    }
+
+    #[test]
+    fn from_file_rejects_unknown_settings() {
+        for content in [
+            "[ios]\napp_idd = \"1\"",
+            "[iso]\napp_id = \"1\"",
+            "model = \"x\"",
+        ] {
+            let path = config_file(content);
+            let error = Config::from_file(&path).unwrap_err();
+            assert!(
+                error.to_string().contains("unknown field"),
+                "{}: {}",
+                content,
+                error
+            );
+            fs::remove_dir_all(path.parent().unwrap()).ok();
+        }
+    }
+
+    #[test]
+    fn number_setting_falls_back_to_the_environment() {
+        let value = number_setting(
+            &matches(&[]),
+            &|name| (name == "RUSTPOND_MAX_CONNECTIONS").then(|| "6".to_string()),
+            "max-connections",
+            "RUSTPOND_MAX_CONNECTIONS",
+            "max connections",
+            "a number of at least 1",
+            |&n: &usize| n >= 1,
+        );
+        assert_eq!(value.unwrap(), Some(6));
+    }
+
+    #[test]
+    fn flags_override_the_file_which_overrides_the_environment() {
+        let path = config_file(
+            r#"
+            [ios]
+            app_id = "111"
+            key_id = "FILE_KEY"
+            private_key = "/keys/file.p8"
+            "#,
+        );
+        let config = with_vars(
+            &["--config", path.to_str().unwrap(), "--app-id", "222"],
+            &[
+                ("APP_STORE_APP_ID", "333"),
+                ("APP_STORE_CONNECT_KEY_ID", "ENV_KEY"),
+                ("APP_STORE_CONNECT_ISSUER_ID", "env-issuer"),
+                ("APP_STORE_CONNECT_PRIVATE_KEY_PATH", "/keys/env.p8"),
+            ],
+        )
+        .unwrap();
+        fs::remove_dir_all(path.parent().unwrap()).ok();
+
+        assert_eq!(config.app_id, "222");
+        assert_eq!(config.key_id.as_deref(), Some("FILE_KEY"));
+        assert_eq!(
+            config.private_key_path,
+            Some(PathBuf::from("/keys/file.p8"))
+        );
+        // Not in the file, so the environment still fills it in
+        assert_eq!(config.issuer_id.as_deref(), Some("env-issuer"));
+    }
+
+    #[test]
+    fn missing_credentials_point_at_rustpond_toml() {
+        let path = config_file("");
+        let config_arg = path.to_str().unwrap();
+        let ios = with_vars(
+            &["--config", config_arg, "--app-id", "123", "--key-id", "KEY"],
+            &[],
+        )
+        .unwrap_err();
+        let android = with_vars(
+            &[
+                "--config",
+                config_arg,
+                "--android",
+                "--app-id",
+                "com.example.app",
+            ],
+            &[],
+        )
+        .unwrap_err();
+        fs::remove_dir_all(path.parent().unwrap()).ok();
+
+        assert!(ios.to_string().contains("rustpond.toml"), "{}", ios);
+        assert!(android.to_string().contains("rustpond.toml"), "{}", android);
+    }
+}
@@ -0,0 +1,215 @@
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::review::Review;
+use crate::text;
+
+// Store errors can quote whatever the API sent back, so only their start is kept
+const MAX_ERROR_CHARS: usize = 300;
+
+// Builds a plain-text report to attach to bug reports. Credentials only show
+// whether they are set, and the selected review only shows its metadata, so
+// the bundle can be shared without editing it first.
+pub fn bundle(configs: &[&Config], last_error: Option<&str>, review: Option<&Review>) -> String {
+    let mut out = String::new();
+    writeln!(out, "rustpond debug bundle").ok();
+    writeln!(out, "Generated: {}", Utc::now().to_rfc3339()).ok();
+    writeln!(out, "Version: {}", env!("CARGO_PKG_VERSION")).ok();
+    writeln!(
+        out,
+        "OS: {} ({})",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    )
+    .ok();
+    writeln!(
+        out,
+        "Last error: {}",
+        last_error.map_or("none".to_string(), shorten_error)
+    )
+    .ok();
+
+    for config in configs {
+        writeln!(out).ok();
+        writeln!(out, "[{} config]", config.platform.badge()).ok();
+        let settings = [
+            ("app_id", config.app_id.clone()),
+            ("key_id", secret(config.key_id.as_deref()).to_string()),
+            ("issuer_id", secret(config.issuer_id.as_deref()).to_string()),
+            ("individual_key", config.individual_key.to_string()),
+            (
+                "private_key",
+                file_status(config.private_key_path.as_deref()).to_string(),
+            ),
+            (
+                "service_account",
+                file_status(config.service_account_path.as_deref()).to_string(),
+            ),
+            (
+                "openai_api_key",
+                secret(config.openai_api_key.as_deref()).to_string(),
+            ),
+            (
+                "serve_token",
+                secret(config.serve_token.as_deref()).to_string(),
+            ),
+            (
+                "config_file",
+                file_status(config.config_file_path.as_deref()).to_string(),
+            ),
+            (
+                "ai_config",
+                file_status(config.ai_config_path.as_deref()).to_string(),
+            ),
+            ("ai_settings", format!("{:?}", config.ai_settings)),
+            (
+                "audit_log",
+                file_status(config.audit_log_path.as_deref()).to_string(),
+            ),
+            (
+                "state_file",
+                file_status(config.state_path.as_deref()).to_string(),
+            ),
+            ("signature", format!("{:?}", config.signature)),
+            ("ai_disclosure", format!("{:?}", config.ai_disclosure)),
+            ("tag_ai_responses", config.tag_ai_responses.to_string()),
+            ("require_ai", config.require_ai.to_string()),
+            (
+                "translation_language",
+                format!("{:?}", config.translation_language),
+            ),
+            ("tidy_whitespace", config.tidy_whitespace.to_string()),
+            ("hide_help", config.hide_help.to_string()),
+            ("read_only", config.read_only.to_string()),
+            ("list_page_size", format!("{:?}", config.list_page_size)),
+            (
+                "collapse_blank_lines",
+                config.collapse_blank_lines.to_string(),
+            ),
+            (
+                "min_response_chars",
+                format!("{:?}", config.min_response_chars),
+            ),
+            ("ctrl_arrows", format!("{:?}", config.ctrl_arrows)),
+            ("display_timezone", format!("{:?}", config.display_timezone)),
+            ("date_format", config.date_format.clone()),
+            ("ai_concurrency", config.ai_concurrency.to_string()),
+            ("ai_max_tokens", format!("{:?}", config.ai_max_tokens)),
+            ("no_promotions", config.no_promotions.to_string()),
+            (
+                "promotion_min_rating",
+                format!("{:?}", config.promotion_min_rating),
+            ),
+            ("ai_attempts", config.ai_attempts.to_string()),
+            ("ai_timeout", format!("{:?}", config.ai_timeout)),
+            ("max_connections", config.max_connections.to_string()),
+            ("fetch_all", config.fetch_all.to_string()),
+            ("quick_refresh", config.quick_refresh.to_string()),
+            ("no_cache", config.no_cache.to_string()),
+            ("fetch_sort", config.fetch_sort.query_value().to_string()),
+            ("print_payload", config.print_payload.to_string()),
+            ("auto_refresh", format!("{:?}", config.auto_refresh)),
+            ("spam_rules", format!("{:?}", config.spam_rules)),
+            (
+                "sensitive_terms",
+                format!("{} terms", config.sensitive_terms.len()),
+            ),
+        ];
+        for (name, value) in settings {
+            writeln!(out, "{}: {}", name, value).ok();
+        }
+    }
+
+    if let Some(review) = review {
+        writeln!(out).ok();
+        writeln!(out, "[Selected review]").ok();
+        writeln!(out, "id: {}", review.id).ok();
+        writeln!(out, "rating: {}", review.rating).ok();
+        writeln!(out, "territory: {}", review.territory).ok();
+        writeln!(out, "version: {:?}", review.version).ok();
+        writeln!(out, "created: {}", review.created_date.to_rfc3339()).ok();
+        let body_chars = review.body.as_deref().map(|body| body.chars().count());
+        writeln!(out, "body_chars: {:?} (text redacted)", body_chars).ok();
+        writeln!(out, "thread_comments: {}", review.thread.len()).ok();
+        writeln!(
+            out,
+            "response: {}",
+            review
+                .response
+                .as_ref()
+                .map(|response| response.state.label())
+                .unwrap_or_else(|| "none".to_string())
+        )
+        .ok();
+    }
+
+    out
+}
+
+// Writes the bundle next to where rustpond was started and returns the path
+pub fn write_bundle(contents: &str) -> Result<PathBuf> {
+    let path = PathBuf::from(format!(
+        "rustpond-debug-{}.txt",
+        Utc::now().format("%Y%m%d-%H%M%S")
+    ));
+    fs::write(&path, contents).map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+fn shorten_error(error: &str) -> String {
+    let kept = text::truncate_chars(error, MAX_ERROR_CHARS);
+    if kept.len() == error.len() {
+        return error.to_string();
+    }
+    format!(
+        "{}… ({} more characters redacted)",
+        kept,
+        error.chars().count() - MAX_ERROR_CHARS
+    )
+}
+
+fn secret(value: Option<&str>) -> &'static str {
+    match value {
+        Some(value) if !value.is_empty() => "set (redacted)",
+        _ => "not set",
+    }
+}
+
+// Paths can contain user names, so only report whether the files exist
+fn file_status(path: Option<&Path>) -> &'static str {
+    match path {
+        Some(path) if path.exists() => "set, file found (path redacted)",
+        Some(_) => "set, file NOT found (path redacted)",
+        None => "not set",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_errors_are_cut_before_they_reach_the_bundle() {
+        let error = format!(
+            "Failed to parse reviews response: {}",
+            "private review text ".repeat(100)
+        );
+        let bundle = bundle(&[], Some(&error), None);
+        let line = bundle
+            .lines()
+            .find(|line| line.starts_with("Last error: "))
+            .unwrap();
+        assert!(line.chars().count() < MAX_ERROR_CHARS + 60);
+        assert!(line.ends_with("more characters redacted)"));
+    }
+
+    #[test]
+    fn short_errors_are_kept_whole() {
+        let bundle = bundle(&[], Some("API request failed with status 401"), None);
+        assert!(bundle.contains("Last error: API request failed with status 401\n"));
+    }
+}
@@ -0,0 +1,431 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, IsTerminal};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+use crate::ai::{self, AIConfig, AIResponseGenerator};
+use crate::api::ApiClient;
+use crate::config::Config;
+use crate::review::{CommentAuthor, Review, ThreadComment};
+
+// Which reviews to include in an export
+pub struct ExportFilter {
+    pub since: Option<NaiveDate>,
+    // Empty means every rating
+    pub ratings: Vec<i32>,
+}
+
+impl ExportFilter {
+    fn matches(&self, review: &Review) -> bool {
+        let recent_enough = match self.since {
+            Some(since) => review.created_date.date_naive() >= since,
+            None => true,
+        };
+        recent_enough && (self.ratings.is_empty() || self.ratings.contains(&review.rating))
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ExportedReview<'a> {
+    review_id: &'a str,
+    rating: i32,
+    reviewer: &'a str,
+    created_date: DateTime<Utc>,
+    territory: &'a str,
+    version: Option<&'a str>,
+    title: Option<&'a str>,
+    body: Option<&'a str>,
+    suggested_response: Option<String>,
+    // "drafted", "failed" or "not requested", so failed rows can be redone
+    draft_status: &'static str,
+    draft_error: Option<String>,
+}
+
+// Writes reviews without a developer response to `path`, as JSON when the
+// extension is `.json` and CSV otherwise. Each row gets an AI draft when AI
+// is enabled. With `drafts_required` (`--export-drafts`) the export is for
+// approving drafts, so it fails without AI rather than writing empty rows.
+pub async fn export_unresponded(
+    client: &mut ApiClient,
+    config: &Config,
+    path: &Path,
+    filter: &ExportFilter,
+    drafts_required: bool,
+) -> Result<()> {
+    let ai_config = AIConfig::for_config(config)?;
+    if drafts_required && ai_config.openai_api_key.is_empty() {
+        return Err(anyhow!(
+            "export --drafts needs AI. Set OPENAI_API_KEY or use export --unresponded"
+        ));
+    }
+
+    // Responses come back with the review list, so no per-review requests are needed
+    let reviews: Vec<Review> = fetch_every_review(client)
+        .await?
+        .into_iter()
+        .filter(|review| review.response.is_none() && filter.matches(review))
+        .collect();
+
+    let suggestions = if ai_config.openai_api_key.is_empty() {
+        eprintln!("AI is disabled, so the export won't include suggested responses");
+        vec![None; reviews.len()]
+    } else {
+        let generator = Arc::new(AIResponseGenerator::new(ai_config)?);
+        if confirm_ai_cost(&generator, &reviews, config)? {
+            let drafts = draft_responses(generator.clone(), &reviews, config).await;
+            let (input_tokens, output_tokens) = generator.tokens_used();
+            println!(
+                "AI usage: {} input + {} output tokens, {}",
+                input_tokens,
+                output_tokens,
+                describe_cost(generator.model(), input_tokens, output_tokens)
+            );
+            drafts.into_iter().map(Some).collect()
+        } else if drafts_required {
+            println!("Cancelled, nothing was exported");
+            return Ok(());
+        } else {
+            eprintln!("Exporting without suggested responses");
+            vec![None; reviews.len()]
+        }
+    };
+
+    let mut rows = Vec::with_capacity(reviews.len());
+    let mut failed = 0;
+    for (review, draft) in reviews.iter().zip(suggestions) {
+        let (suggested_response, draft_status, draft_error) = match draft {
+            Some(Ok(draft)) => (Some(draft), "drafted", None),
+            Some(Err(e)) => {
+                failed += 1;
+                (None, "failed", Some(e))
+            }
+            None => (None, "not requested", None),
+        };
+        rows.push(ExportedReview {
+            review_id: &review.id,
+            rating: review.rating,
+            reviewer: &review.reviewer_nickname,
+            created_date: review.created_date,
+            territory: &review.territory,
+            version: review.version.as_deref(),
+            title: review.title.as_deref(),
+            body: review.body.as_deref(),
+            suggested_response,
+            draft_status,
+            draft_error,
+        });
+    }
+    write_rows(path, &rows, &rows)?;
+
+    println!(
+        "Exported {} unresponded reviews to {}",
+        rows.len(),
+        path.display()
+    );
+    if failed > 0 {
+        eprintln!(
+            "{} drafts failed and are marked \"failed\" in the draft_status column",
+            failed
+        );
+    }
+
+    Ok(())
+}
+
+// Shows the estimated tokens and cost of drafting every row and asks before
+// spending them. Without a terminal to ask on, drafting goes ahead.
+fn confirm_ai_cost(
+    generator: &AIResponseGenerator,
+    reviews: &[Review],
+    config: &Config,
+) -> Result<bool> {
+    if reviews.is_empty() {
+        return Ok(true);
+    }
+
+    let (input_tokens, output_tokens) =
+        generator.estimate_tokens(reviews, config.translation_language.as_deref());
+    eprintln!(
+        "Drafting {} responses with {} takes about {} input + {} output tokens, {}",
+        reviews.len(),
+        generator.model(),
+        input_tokens,
+        output_tokens,
+        describe_cost(generator.model(), input_tokens, output_tokens)
+    );
+
+    if !io::stdin().is_terminal() {
+        return Ok(true);
+    }
+    eprint!("Draft responses? [Y/n] ");
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    Ok(!answer.trim().eq_ignore_ascii_case("n"))
+}
+
+fn describe_cost(model: &str, input_tokens: u64, output_tokens: u64) -> String {
+    match ai::estimated_cost(model, input_tokens, output_tokens) {
+        Some(cost) if cost < 0.01 => "costing less than $0.01".to_string(),
+        Some(cost) => format!("costing about ${:.2}", cost),
+        None => format!("cost unknown (no built-in pricing for {})", model),
+    }
+}
+
+// Drafts up to `config.ai_concurrency` responses at once and returns them in
+// review order. The OpenAI client backs off and retries on its own when rate
+// limited (429), so the limit only bounds how hard it gets hit.
+async fn draft_responses(
+    generator: Arc<AIResponseGenerator>,
+    reviews: &[Review],
+    config: &Config,
+) -> Vec<std::result::Result<String, String>> {
+    let semaphore = Arc::new(Semaphore::new(config.ai_concurrency));
+    let handles: Vec<_> = reviews
+        .iter()
+        .cloned()
+        .map(|review| {
+            let generator = generator.clone();
+            let semaphore = semaphore.clone();
+            let language = config.translation_language.clone();
+            tokio::spawn(async move {
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .map_err(|e| anyhow!("{}", e))?;
+                generator
+                    .generate_response(&review, language.as_deref())
+                    .await
+            })
+        })
+        .collect();
+
+    let mut drafts = Vec::with_capacity(reviews.len());
+    for (idx, (handle, review)) in handles.into_iter().zip(reviews).enumerate() {
+        eprint!("\rDrafting responses {}/{}...", idx + 1, reviews.len());
+        let draft = match handle.await.map_err(anyhow::Error::from).and_then(|r| r) {
+            Ok(draft) => Ok(draft),
+            Err(e) => {
+                eprintln!("\nFailed to draft a response for {}: {}", review.id, e);
+                Err(e.to_string())
+            }
+        };
+        drafts.push(draft);
+    }
+    if !drafts.is_empty() {
+        eprintln!();
+    }
+    if let Some(notice) = generator.model_fallback() {
+        eprintln!("{}", notice);
+    }
+
+    drafts
+}
+
+#[derive(Debug, Serialize)]
+struct ExportedThread<'a> {
+    review_id: &'a str,
+    rating: i32,
+    reviewer: &'a str,
+    thread: Vec<ThreadComment>,
+}
+
+// CSV can't nest, so threads are flattened to one row per comment
+#[derive(Debug, Serialize)]
+struct ExportedComment<'a> {
+    review_id: &'a str,
+    position: usize,
+    author: CommentAuthor,
+    text: &'a str,
+    last_modified: Option<DateTime<Utc>>,
+}
+
+// Every review for the exports. A page that stopped `refresh_all_reviews` is
+// still next, so the loop gives it one more try before the export fails.
+async fn fetch_every_review(client: &mut ApiClient) -> Result<Vec<Review>> {
+    let (mut reviews, warning) = client.refresh_all_reviews().await?;
+    if let Some(warning) = warning {
+        eprintln!("Warning: {}. Retrying from there", warning);
+    }
+    while client.has_more_reviews() {
+        reviews.extend(client.load_more_reviews().await?);
+    }
+    Ok(reviews)
+}
+
+// Writes every review with its full comment thread. Google Play returns the
+// thread with each review; App Store reviews only ever have the review itself
+// and at most one developer response.
+pub async fn export_threads(client: &mut ApiClient, path: &Path) -> Result<()> {
+    let reviews = fetch_every_review(client).await?;
+
+    let threads: Vec<ExportedThread> = reviews
+        .iter()
+        .map(|review| ExportedThread {
+            review_id: &review.id,
+            rating: review.rating,
+            reviewer: &review.reviewer_nickname,
+            thread: comment_thread(review),
+        })
+        .collect();
+
+    let comments: Vec<ExportedComment> = threads
+        .iter()
+        .flat_map(|thread| {
+            thread
+                .thread
+                .iter()
+                .enumerate()
+                .map(|(idx, comment)| ExportedComment {
+                    review_id: thread.review_id,
+                    position: idx + 1,
+                    author: comment.author,
+                    text: &comment.text,
+                    last_modified: comment.last_modified,
+                })
+        })
+        .collect();
+
+    write_rows(path, &threads, &comments)?;
+
+    println!(
+        "Exported {} review threads to {}",
+        threads.len(),
+        path.display()
+    );
+
+    Ok(())
+}
+
+fn comment_thread(review: &Review) -> Vec<ThreadComment> {
+    if !review.thread.is_empty() {
+        return review.thread.clone();
+    }
+
+    let text = [review.title.as_deref(), review.body.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let mut thread = vec![ThreadComment {
+        author: CommentAuthor::User,
+        text,
+        last_modified: Some(review.created_date),
+    }];
+    if let Some(response) = &review.response {
+        thread.push(ThreadComment {
+            author: CommentAuthor::Developer,
+            text: response.response_body.clone(),
+            last_modified: Some(response.last_modified_date),
+        });
+    }
+    thread
+}
+
+#[derive(Debug, Serialize)]
+struct VersionSummary {
+    version: String,
+    reviews: usize,
+    average_rating: f64,
+    latest_review: DateTime<Utc>,
+}
+
+// Buckets every review by app version with its count and average rating, so a
+// release that hurt sentiment stands out. Printed as a table, or written as CSV
+// when `path` is given. Versions are ordered by their most recent review.
+pub async fn version_report(client: &mut ApiClient, path: Option<&Path>) -> Result<()> {
+    let reviews = fetch_every_review(client).await?;
+
+    let mut buckets: HashMap<&str, Vec<&Review>> = HashMap::new();
+    for review in &reviews {
+        buckets
+            .entry(review.version.as_deref().unwrap_or("unknown"))
+            .or_default()
+            .push(review);
+    }
+
+    let mut summaries: Vec<VersionSummary> = buckets
+        .into_iter()
+        .map(|(version, reviews)| VersionSummary {
+            version: version.to_string(),
+            reviews: reviews.len(),
+            average_rating: reviews.iter().map(|r| r.rating as f64).sum::<f64>()
+                / reviews.len() as f64,
+            latest_review: reviews
+                .iter()
+                .map(|r| r.created_date)
+                .max()
+                .unwrap_or_default(),
+        })
+        .collect();
+    summaries.sort_by_key(|summary| std::cmp::Reverse(summary.latest_review));
+
+    match path {
+        Some(path) => {
+            write_rows(path, &summaries, &summaries)?;
+            println!(
+                "Wrote {} versions from {} reviews to {}",
+                summaries.len(),
+                reviews.len(),
+                path.display()
+            );
+        }
+        None => {
+            let width = summaries
+                .iter()
+                .map(|s| s.version.chars().count())
+                .chain(["Version".len()])
+                .max()
+                .unwrap_or(0);
+            println!(
+                "{:<width$}  {:>7}  {:>10}  Latest review",
+                "Version", "Reviews", "Avg rating"
+            );
+            for summary in &summaries {
+                println!(
+                    "{:<width$}  {:>7}  {:>10.2}  {}",
+                    summary.version,
+                    summary.reviews,
+                    summary.average_rating,
+                    summary.latest_review.format("%Y-%m-%d")
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Writes `json_rows` when the extension is `.json` and `csv_rows` otherwise
+fn write_rows<J: Serialize, C: Serialize>(
+    path: &Path,
+    json_rows: &[J],
+    csv_rows: &[C],
+) -> Result<()> {
+    let is_json = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    let file =
+        File::create(path).map_err(|e| anyhow!("Failed to create {}: {}", path.display(), e))?;
+
+    if is_json {
+        serde_json::to_writer_pretty(file, json_rows)
+            .map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+    } else {
+        let mut writer = csv::Writer::from_writer(file);
+        for row in csv_rows {
+            writer
+                .serialize(row)
+                .map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+        }
+        writer
+            .flush()
+            .map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use anyhow::{anyhow, Result};
+use std::path::Path;
+
+use crate::api::ApiClient;
+use crate::audit::AiUsage;
+use crate::config::Config;
+use crate::text;
+
+// Submits responses from a CSV of `review_id,response_text` rows without
+// starting the TUI. A leading `review_id` header row is skipped. Reviews that
+// already have a developer response are skipped unless `overwrite` is set.
+pub async fn import_responses(
+    client: &mut ApiClient,
+    config: &Config,
+    path: &Path,
+    overwrite: bool,
+) -> Result<()> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_path(path)
+        .map_err(|e| anyhow!("Failed to open {}: {}", path.display(), e))?;
+
+    let mut submitted = 0;
+    let mut skipped = 0;
+    let mut failed = 0;
+
+    for (idx, record) in reader.records().enumerate() {
+        let row = idx + 1;
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                println!("row {}: failed - could not parse CSV: {}", row, e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let review_id = record.get(0).unwrap_or("").trim();
+        let response_text = record.get(1).unwrap_or("");
+
+        if row == 1 && review_id.eq_ignore_ascii_case("review_id") {
+            continue;
+        }
+
+        if review_id.is_empty() || response_text.trim().is_empty() {
+            println!("row {}: failed - expected review_id,response_text", row);
+            failed += 1;
+            continue;
+        }
+
+        if let Some(min) = config.min_response_chars {
+            let length = text::char_count(response_text.trim());
+            if length < min {
+                println!(
+                    "row {} ({}): failed - response is {} characters, minimum is {}",
+                    row, review_id, length, min
+                );
+                failed += 1;
+                continue;
+            }
+        }
+
+        let response_body = text::prepare_response(response_text, config, false);
+        if let Some(limit) = config.platform.character_limit() {
+            let length = text::char_count(&response_body);
+            if length > limit {
+                println!(
+                    "row {} ({}): failed - response is {} characters, limit is {}",
+                    row, review_id, length, limit
+                );
+                failed += 1;
+                continue;
+            }
+        }
+
+        // The review also says which app it belongs to, which the submission
+        // checks against the configured one
+        let review = match client.get_review(review_id).await {
+            Ok(review) => review,
+            Err(e) => {
+                println!(
+                    "row {} ({}): failed - could not look up the review: {}",
+                    row, review_id, e
+                );
+                failed += 1;
+                continue;
+            }
+        };
+        if !overwrite && review.response.is_some() {
+            println!(
+                "row {} ({}): skipped - already has a response (use --overwrite to replace it)",
+                row, review_id
+            );
+            skipped += 1;
+            continue;
+        }
+
+        match client
+            .submit_response(
+                review_id,
+                review.app_id.as_deref(),
+                &response_body,
+                AiUsage::default(),
+            )
+            .await
+        {
+            Ok(warning) => {
+                match warning {
+                    Some(warning) => {
+                        println!("row {} ({}): submitted - {}", row, review_id, warning)
+                    }
+                    None => println!("row {} ({}): submitted", row, review_id),
+                }
+                submitted += 1;
+            }
+            Err(e) => {
+                println!("row {} ({}): failed - {}", row, review_id, e);
+                failed += 1;
+            }
+        }
+    }
+
+    println!(
+        "\nImport finished: {} submitted, {} skipped, {} failed",
+        submitted, skipped, failed
+    );
+
+    Ok(())
+}
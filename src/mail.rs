@@ -0,0 +1,51 @@
+use anyhow::{anyhow, Result};
+use std::process::{Command, Stdio};
+
+pub fn mailto_url(to: &str, subject: &str, body: &str) -> String {
+    format!(
+        "mailto:{}?subject={}&body={}",
+        to,
+        percent_encode(subject),
+        percent_encode(body)
+    )
+}
+
+// Hands the URL to the platform's default handler, e.g. the mail client for mailto:
+pub fn open_url(url: &str) -> Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        // Not `cmd /C start`: cmd would treat the `&` between the mailto
+        // fields as a command separator and cut the body off
+        let mut command = Command::new("rundll32");
+        command.arg("url.dll,FileProtocolHandler");
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+
+    // The TUI owns the terminal, so keep the opener's output off it
+    command
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| anyhow!("Failed to launch the URL handler: {}", e))?;
+
+    Ok(())
+}
+
+// Mail clients expect RFC 3986 encoding, with spaces as %20 rather than '+'
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
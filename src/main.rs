@@ -1,7 +1,9 @@
-use anyhow::Result;
-use chrono::Utc;
-use clap::{Arg, Command};
+use anyhow::{anyhow, Result};
+use chrono::{NaiveDate, Utc};
+use clap::{Arg, ArgGroup, ArgMatches, Command};
 use dotenv::dotenv;
+use std::net::SocketAddr;
+use std::path::Path;
 
 use crate::ai::{AIConfig, AIResponseGenerator};
 use crate::api::ApiClient;
@@ -9,8 +11,21 @@ use crate::review::Review;
 
 mod ai;
 mod api;
+mod audit;
+mod cache;
 mod config;
+mod debug;
+mod export;
+mod import;
+mod mail;
+mod preflight;
 mod review;
+mod sensitive;
+mod serve;
+mod spam;
+mod state;
+mod text;
+mod timezone;
 mod ui;
 
 use ui::ReviewUI;
@@ -20,35 +35,13 @@ async fn main() -> Result<()> {
     // Load .env file if it exists (ignore errors if file doesn't exist)
     dotenv().ok();
 
-    // Test Google Play API access with --test-android flag
-    if std::env::args().any(|arg| arg == "--test-android") {
-        let matches = Command::new("rustpond")
-            .arg(
-                Arg::new("android")
-                    .long("android")
-                    .action(clap::ArgAction::SetTrue),
-            )
-            .arg(Arg::new("app-id").long("app-id").value_name("APP_ID"))
-            .arg(
-                Arg::new("service-account")
-                    .long("service-account")
-                    .value_name("SERVICE_ACCOUNT_PATH"),
-            )
-            .get_matches();
-
-        let config = config::Config::from_args_and_env(&matches)?;
-        let mut client = ApiClient::new(config);
-
-        match client.refresh_all_reviews().await {
-            Ok(reviews) => println!("Successfully accessed reviews: {} found", reviews.len()),
-            Err(e) => println!("Error accessing reviews: {}", e),
-        }
-
-        return Ok(());
-    }
+    let cli_matches = build_cli().get_matches();
+    let (command, matches) = match cli_matches.subcommand() {
+        Some((name, sub_matches)) => (name, sub_matches),
+        None => (legacy_command(&cli_matches), &cli_matches),
+    };
 
-    // Test AI functionality with --test-ai flag
-    if std::env::args().any(|arg| arg == "--test-ai") {
+    if command == "test-ai" {
         let config = AIConfig::default();
         let generator = AIResponseGenerator::new(config)?;
 
@@ -62,17 +55,170 @@ async fn main() -> Result<()> {
             territory: "US".to_string(),
             version: Some("1.0".to_string()),
             response: None,
+            original_text: None,
+            language: None,
+            thumbs_up: None,
+            thumbs_down: None,
+            thread: Vec::new(),
+            platform: None,
+            app_id: None,
+            raw: None,
         };
 
         println!("Testing AI response generation...");
-        match generator.generate_response(&test_review).await {
+        match generator.generate_response(&test_review, None).await {
             Ok(response) => println!("AI Response: {}", response),
             Err(e) => println!("AI Error: {}", e),
         }
         return Ok(());
     }
 
-    let matches = Command::new("rustpond")
+    // With --both the UI runs on the App Store config, and each store's client
+    // keeps its own credentials and app id
+    let (config, mut client) = if matches.get_flag("both") {
+        let app_store = config::Config::for_platform(matches, config::Platform::Ios)?;
+        let google_play = config::Config::for_platform(matches, config::Platform::Android)?;
+        (
+            app_store.clone(),
+            ApiClient::combined(app_store, google_play),
+        )
+    } else {
+        let config = config::Config::from_args_and_env(matches)?;
+        (config.clone(), ApiClient::new(config))
+    };
+
+    if command != "tui" && (config.offline || config.reviews_file.is_some()) {
+        return Err(anyhow!(
+            "'{}' needs the store, so it can't be combined with --offline or --reviews-file",
+            command
+        ));
+    }
+    if matches!(command, "serve" | "respond") && (matches.get_flag("both") || config.read_only) {
+        return Err(anyhow!(
+            "'{}' can't be combined with --both or --read-only",
+            command
+        ));
+    }
+
+    match command {
+        "health" => return preflight::health(&mut client).await,
+        "serve" => {
+            let addr = matches
+                .get_one::<SocketAddr>("serve")
+                .copied()
+                .ok_or_else(|| anyhow!("An address to serve on is required"))?;
+            return serve::run(client, config, addr).await;
+        }
+        "respond" => {
+            let path = matches
+                .get_one::<String>("import-responses")
+                .ok_or_else(|| anyhow!("A CSV of responses is required"))?;
+            let overwrite = matches.get_flag("overwrite");
+            return import::import_responses(&mut client, &config, Path::new(path), overwrite)
+                .await;
+        }
+        "export" => return export(&mut client, &config, matches).await,
+        _ => {}
+    }
+
+    // With --offline or --reviews-file nothing is fetched, so there's nothing to check
+    let offline =
+        config.offline || config.reviews_file.is_some() || preflight::run(&mut client).await?;
+
+    let mut ui = ReviewUI::new(config, client, offline).await?;
+    ui.run().await?;
+
+    Ok(())
+}
+
+// The subcommand the flat mode flags stand for
+fn legacy_command(matches: &ArgMatches) -> &'static str {
+    if matches.get_flag("test-ai") {
+        "test-ai"
+    } else if matches.get_flag("test-android") {
+        "health"
+    } else if matches.contains_id("serve") {
+        "serve"
+    } else if matches.contains_id("import-responses") {
+        "respond"
+    } else if [
+        "export-unresponded",
+        "export-drafts",
+        "export-threads",
+        "version-report",
+    ]
+    .iter()
+    .any(|id| matches.contains_id(id))
+    {
+        "export"
+    } else {
+        "tui"
+    }
+}
+
+async fn export(
+    client: &mut ApiClient,
+    config: &config::Config,
+    matches: &ArgMatches,
+) -> Result<()> {
+    let unresponded_export = matches
+        .get_one::<String>("export-unresponded")
+        .map(|path| (path, false))
+        .or_else(|| {
+            matches
+                .get_one::<String>("export-drafts")
+                .map(|path| (path, true))
+        });
+    if let Some((path, drafts_required)) = unresponded_export {
+        let filter = export::ExportFilter {
+            since: matches.get_one::<NaiveDate>("since").copied(),
+            ratings: matches
+                .get_many::<i64>("rating")
+                .map(|ratings| ratings.map(|&r| r as i32).collect())
+                .unwrap_or_default(),
+        };
+        return export::export_unresponded(
+            client,
+            config,
+            Path::new(path),
+            &filter,
+            drafts_required,
+        )
+        .await;
+    }
+
+    if let Some(path) = matches.get_one::<String>("export-threads") {
+        return export::export_threads(client, Path::new(path)).await;
+    }
+
+    let path = matches
+        .get_one::<String>("version-report")
+        .filter(|path| path.as_str() != "-")
+        .map(Path::new);
+    export::version_report(client, path).await
+}
+
+// Flags that pick a mode instead of configuring one. Each mode also has a
+// subcommand; the flags keep working when no subcommand is given.
+const MODE_ARGS: &[&str] = &[
+    "import-responses",
+    "overwrite",
+    "export-unresponded",
+    "export-drafts",
+    "since",
+    "rating",
+    "export-threads",
+    "serve",
+    "version-report",
+    "test-ai",
+    "test-android",
+];
+
+// The modes besides the TUI need the store, so they can't run on cached or demo reviews
+const OFFLINE_ARGS: &[&str] = &["offline", "reviews-file"];
+
+fn build_cli() -> Command {
+    Command::new("rustpond")
         .version("0.1.0")
         .about("CLI tool for responding to app store reviews (iOS and Android)")
         .arg(
@@ -89,6 +235,13 @@ async fn main() -> Result<()> {
                 .action(clap::ArgAction::SetTrue)
                 .conflicts_with("ios"),
         )
+        .arg(
+            Arg::new("both")
+                .long("both")
+                .help("Load reviews from the App Store and Google Play into one list (app ids come from the config file or APP_STORE_APP_ID and GOOGLE_PLAY_PACKAGE_NAME)")
+                .action(clap::ArgAction::SetTrue)
+                .conflicts_with_all(["ios", "android", "app-id"]),
+        )
         .arg(
             Arg::new("app-id")
                 .long("app-id")
@@ -110,6 +263,12 @@ async fn main() -> Result<()> {
                 .help("App Store Connect API Issuer ID (iOS only)")
                 .required(false),
         )
+        .arg(
+            Arg::new("individual-key")
+                .long("individual-key")
+                .help("Sign requests with an individual App Store Connect API key, which needs no issuer ID (iOS only)")
+                .action(clap::ArgAction::SetTrue),
+        )
         .arg(
             Arg::new("private-key")
                 .long("private-key")
@@ -124,12 +283,467 @@ async fn main() -> Result<()> {
                 .help("Path to Google Play Console service account JSON file (Android only)")
                 .required(false),
         )
-        .get_matches();
-
-    let config = config::Config::from_args_and_env(&matches)?;
-
-    let mut ui = ReviewUI::new(config).await?;
-    ui.run().await?;
-
-    Ok(())
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .value_name("PATH")
+                .help("TOML config file (default: ./rustpond.toml, then ~/.config/rustpond/config.toml)")
+                .required(false),
+        )
+        .arg(
+            Arg::new("audit-log")
+                .long("audit-log")
+                .value_name("PATH")
+                .help("Append every submitted response to this JSON lines audit log")
+                .required(false),
+        )
+        .arg(
+            Arg::new("signature")
+                .long("signature")
+                .value_name("TEXT")
+                .help("Signature appended to every response on submit (e.g. \"- The Candle Team\")")
+                .required(false),
+        )
+        .arg(
+            Arg::new("ctrl-arrows")
+                .long("ctrl-arrows")
+                .value_name("MODE")
+                .help("What Ctrl+Left/Right do in the editor: 'word' (default) or 'line'")
+                .value_parser(["word", "line"])
+                .required(false),
+        )
+        .arg(
+            Arg::new("keep-whitespace")
+                .long("keep-whitespace")
+                .help("Submit responses exactly as typed instead of trimming trailing whitespace and extra blank lines")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("translate-to")
+                .long("translate-to")
+                .value_name("LANGUAGE")
+                .help("Ask Google Play to translate reviews into this language, e.g. 'en' (Android only)")
+                .required(false),
+        )
+        .arg(
+            Arg::new("require-ai")
+                .long("require-ai")
+                .help("Exit with an error if AI responses can't be enabled (e.g. OPENAI_API_KEY is missing)")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("timezone")
+                .long("timezone")
+                .value_name("TZ")
+                .help("Timezone for displayed dates: 'utc' (default), 'local', 'territory' or an IANA name like 'Europe/Berlin'")
+                .required(false),
+        )
+        .arg(
+            Arg::new("import-responses")
+                .long("import-responses")
+                .value_name("CSV")
+                .help("Submit responses from a CSV of review_id,response_text rows without starting the TUI (same as `respond`)")
+                .required(false)
+                .conflicts_with_all(OFFLINE_ARGS)
+                .conflicts_with_all(["both", "read-only"]),
+        )
+        .arg(
+            Arg::new("overwrite")
+                .long("overwrite")
+                .help("With --import-responses, also submit to reviews that already have a response")
+                .action(clap::ArgAction::SetTrue)
+                .requires("import-responses"),
+        )
+        .arg(
+            Arg::new("ai-config")
+                .long("ai-config")
+                .value_name("PATH")
+                .help("JSON file with AI settings (model, keywords, support_email, prompts); reload with Ctrl+R")
+                .required(false),
+        )
+        .arg(
+            Arg::new("min-response-chars")
+                .long("min-response-chars")
+                .value_name("N")
+                .help("Block submitting responses shorter than N characters (also sets RUSTPOND_MIN_RESPONSE_CHARS)")
+                .required(false),
+        )
+        .arg(
+            Arg::new("state-file")
+                .long("state-file")
+                .value_name("PATH")
+                .help("Where to keep local review notes such as skip reasons (default: ~/.local/share/rustpond/review_state.json)")
+                .required(false),
+        )
+        .arg(
+            Arg::new("spam-rules")
+                .long("spam-rules")
+                .value_name("PATH")
+                .help("JSON file with thresholds for flagging likely spam reviews")
+                .required(false),
+        )
+        .arg(
+            Arg::new("sensitive-terms")
+                .long("sensitive-terms")
+                .value_name("PATH")
+                .help("Word list (one term per line) for flagging reviews with profanity or threats")
+                .required(false),
+        )
+        .arg(
+            Arg::new("review-id")
+                .long("review-id")
+                .value_name("ID")
+                .help("Open directly to the details of this review")
+                .required(false),
+        )
+        .arg(
+            Arg::new("fetch-all")
+                .long("fetch-all")
+                .help("Load every review at startup instead of only the newest page")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("export-unresponded")
+                .long("export-unresponded")
+                .value_name("PATH")
+                .help("Write reviews without a developer response to a CSV (or .json) file and exit (same as `export --unresponded`)")
+                .required(false)
+                .conflicts_with_all(OFFLINE_ARGS),
+        )
+        .arg(
+            Arg::new("export-drafts")
+                .long("export-drafts")
+                .value_name("PATH")
+                .help("Like --export-unresponded, but AI drafts are required: write each unresponded review with a suggested response for approval, without submitting anything")
+                .required(false)
+                .conflicts_with("export-unresponded")
+                .conflicts_with_all(OFFLINE_ARGS),
+        )
+        .group(ArgGroup::new("unresponded-export").args(["export-unresponded", "export-drafts"]))
+        .arg(
+            Arg::new("since")
+                .long("since")
+                .value_name("YYYY-MM-DD")
+                .help("Only export reviews created on or after this date")
+                .value_parser(|value: &str| NaiveDate::parse_from_str(value, "%Y-%m-%d"))
+                .requires("unresponded-export"),
+        )
+        .arg(
+            Arg::new("rating")
+                .long("rating")
+                .value_name("STARS")
+                .help("Only export reviews with these star ratings, e.g. --rating 1,2")
+                .value_delimiter(',')
+                .value_parser(clap::value_parser!(i64).range(1..=5))
+                .requires("unresponded-export"),
+        )
+        .arg(
+            Arg::new("highlight-symbol")
+                .long("highlight-symbol")
+                .value_name("TEXT")
+                .help("Marker shown before the selected row (default \">> \")")
+                .required(false),
+        )
+        .arg(
+            Arg::new("highlight-style")
+                .long("highlight-style")
+                .value_name("STYLE")
+                .help("Style of the selected row, e.g. \"bold,fg=black,bg=cyan\" (default \"reversed\")")
+                .required(false),
+        )
+        .arg(
+            Arg::new("ai-disclosure")
+                .long("ai-disclosure")
+                .value_name("TEXT")
+                .help("Line appended to responses drafted by AI, e.g. \"(Drafted with AI assistance)\"")
+                .required(false),
+        )
+        .arg(
+            Arg::new("tag-ai-responses")
+                .long("tag-ai-responses")
+                .help("Record in the audit log whether each response was drafted by AI and then edited")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("export-threads")
+                .long("export-threads")
+                .value_name("PATH")
+                .help("Write every review with its full comment thread to a JSON (or CSV) file and exit (same as `export --threads`)")
+                .required(false)
+                .conflicts_with_all(OFFLINE_ARGS),
+        )
+        .arg(
+            Arg::new("serve")
+                .long("serve")
+                .value_name("ADDR")
+                .help("Serve GET /reviews and POST /reviews/{id}/respond as JSON on ADDR (e.g. 127.0.0.1:8080) instead of starting the TUI (same as `serve`)")
+                .value_parser(clap::value_parser!(SocketAddr))
+                .conflicts_with_all(OFFLINE_ARGS)
+                .conflicts_with_all(["both", "read-only"]),
+        )
+        .arg(
+            Arg::new("ai-concurrency")
+                .long("ai-concurrency")
+                .value_name("N")
+                .help("How many AI responses to draft at once when exporting (default 1)")
+                .required(false),
+        )
+        .arg(
+            Arg::new("collapse-blank-lines")
+                .long("collapse-blank-lines")
+                .help("Show runs of blank lines in review text as a single blank line")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("auto-refresh-secs")
+                .long("auto-refresh-secs")
+                .value_name("N")
+                .help("Reload the review list every N seconds while browsing it; 'p' pauses")
+                .required(false),
+        )
+        .arg(
+            Arg::new("version-report")
+                .long("version-report")
+                .value_name("CSV")
+                .help("Print review count and average rating per app version and exit, or write them to a CSV file (same as `export --versions`)")
+                .num_args(0..=1)
+                .default_missing_value("-")
+                .conflicts_with_all(OFFLINE_ARGS),
+        )
+        .arg(
+            Arg::new("print-payload")
+                .long("print-payload")
+                .help("Dry run: confirming a response shows the exact request that would be submitted instead of sending it")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("max-connections")
+                .long("max-connections")
+                .value_name("N")
+                .help("How many store API requests may run at once (default 4)")
+                .required(false),
+        )
+        .arg(
+            Arg::new("preview-chars")
+                .long("preview-chars")
+                .value_name("N")
+                .help("Cut review text in the details pane off after N characters until Space is pressed (default 500, 0 to always show it in full)")
+                .required(false),
+        )
+        .arg(
+            Arg::new("offline")
+                .long("offline")
+                .help("Browse the reviews cached by the last online run and queue responses until the next one, without network access")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("no-cache")
+                .long("no-cache")
+                .help("Wait for fresh reviews at startup instead of showing the cached ones while they load")
+                .action(clap::ArgAction::SetTrue)
+                .conflicts_with("offline"),
+        )
+        .arg(
+            Arg::new("debug")
+                .long("debug")
+                .help("Keep the raw JSON the store returned for each review, shown with 'j'")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("ai-model")
+                .long("ai-model")
+                .value_name("MODEL")
+                .help("OpenAI model used for drafts, overriding the AI config file (also RUSTPOND_AI_MODEL)")
+                .required(false),
+        )
+        .arg(
+            Arg::new("date-format")
+                .long("date-format")
+                .value_name("FORMAT")
+                .help("strftime-style format for displayed dates, e.g. '%d/%m/%Y' (default '%Y-%m-%d')")
+                .required(false),
+        )
+        .arg(
+            Arg::new("reviews-file")
+                .long("reviews-file")
+                .value_name("JSON")
+                .help("Demo mode: show the reviews in a JSON file instead of fetching them. No credentials are needed and responses aren't submitted")
+                .required(false)
+                .conflicts_with_all(["both", "offline"]),
+        )
+        .arg(
+            Arg::new("strict")
+                .long("strict")
+                .help("Fail instead of warning when the app id doesn't look right for the platform")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("ai-max-tokens")
+                .long("ai-max-tokens")
+                .value_name("N")
+                .help("Token budget for each AI reply (default: sized to the platform's character limit, 500 without one)")
+                .required(false),
+        )
+        .arg(
+            Arg::new("ai-attempts")
+                .long("ai-attempts")
+                .value_name("N")
+                .help("How many times 'a' tries the AI before using the template response (default 2)")
+                .required(false),
+        )
+        .arg(
+            Arg::new("ai-timeout-secs")
+                .long("ai-timeout-secs")
+                .value_name("SECONDS")
+                .help("How long 'a' waits for the AI in total before using the template response (default 60)")
+                .required(false),
+        )
+        .arg(
+            Arg::new("hide-help")
+                .long("hide-help")
+                .help("Start with the help panel hidden ('h' shows it again, also RUSTPOND_HIDE_HELP=1)")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("fetch-sort")
+                .long("fetch-sort")
+                .value_name("SORT")
+                .help("Order reviews are fetched in: '-createdDate' (default), 'createdDate', 'rating' or '-rating'")
+                .value_parser(["-createdDate", "createdDate", "rating", "-rating"])
+                .allow_hyphen_values(true)
+                .required(false),
+        )
+        .arg(
+            Arg::new("no-promotions")
+                .long("no-promotions")
+                .help("Leave the support email and custom prompt (e.g. a Reddit plug) out of AI prompts (also RUSTPOND_NO_PROMOTIONS=1)")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("promotion-min-rating")
+                .long("promotion-min-rating")
+                .value_name("STARS")
+                .help("Only add the support email and custom prompt to AI prompts for reviews with at least this many stars, e.g. 3")
+                .required(false),
+        )
+        .arg(
+            Arg::new("list-page-size")
+                .long("list-page-size")
+                .value_name("N")
+                .help("Rows moved by PageUp/PageDown in the review list (default: as many as fit on screen)")
+                .required(false),
+        )
+        .arg(
+            Arg::new("read-only")
+                .long("read-only")
+                .help("Browse reviews and their existing responses without being able to write or submit any (also RUSTPOND_READ_ONLY=1)")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("quick-refresh")
+                .long("quick-refresh")
+                .help("Make 'r' and auto-refresh fetch only the newest page and merge it into the loaded reviews; 'H' still re-fetches every page (also RUSTPOND_QUICK_REFRESH=1)")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("test-ai")
+                .long("test-ai")
+                .hide(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("test-android")
+                .long("test-android")
+                .hide(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        // Everything but the mode flags applies to every subcommand, before or after it
+        .mut_args(|arg| {
+            if MODE_ARGS.contains(&arg.get_id().as_str()) {
+                arg
+            } else {
+                arg.global(true)
+            }
+        })
+        .subcommand(Command::new("tui").about("Browse and respond to reviews interactively (the default)"))
+        .subcommand(
+            Command::new("export")
+                .about("Write reviews to a file and exit")
+                .next_help_heading("Command options")
+                .arg(
+                    Arg::new("export-unresponded")
+                        .long("unresponded")
+                        .value_name("PATH")
+                        .help("Reviews without a developer response, as CSV (or .json)"),
+                )
+                .arg(
+                    Arg::new("export-drafts")
+                        .long("drafts")
+                        .value_name("PATH")
+                        .help("Like --unresponded, but AI drafts are required"),
+                )
+                .arg(
+                    Arg::new("export-threads")
+                        .long("threads")
+                        .value_name("PATH")
+                        .help("Every review with its full comment thread, as JSON (or CSV)"),
+                )
+                .arg(
+                    Arg::new("version-report")
+                        .long("versions")
+                        .value_name("CSV")
+                        .help("Review count and average rating per app version, printed or written to a CSV file")
+                        .num_args(0..=1)
+                        .default_missing_value("-"),
+                )
+                .group(
+                    ArgGroup::new("export")
+                        .args(["export-unresponded", "export-drafts", "export-threads", "version-report"])
+                        .required(true),
+                )
+                .group(ArgGroup::new("unresponded-export").args(["export-unresponded", "export-drafts"]))
+                .arg(
+                    Arg::new("since")
+                        .long("since")
+                        .value_name("YYYY-MM-DD")
+                        .help("Only export reviews created on or after this date")
+                        .value_parser(|value: &str| NaiveDate::parse_from_str(value, "%Y-%m-%d"))
+                        .requires("unresponded-export"),
+                )
+                .arg(
+                    Arg::new("rating")
+                        .long("rating")
+                        .value_name("STARS")
+                        .help("Only export reviews with these star ratings, e.g. --rating 1,2")
+                        .value_delimiter(',')
+                        .value_parser(clap::value_parser!(i64).range(1..=5))
+                        .requires("unresponded-export"),
+                ),
+        )
+        .subcommand(
+            Command::new("respond")
+                .about("Submit responses from a CSV of review_id,response_text rows and exit")
+                .next_help_heading("Command options")
+                .arg(Arg::new("import-responses").value_name("CSV").required(true))
+                .arg(
+                    Arg::new("overwrite")
+                        .long("overwrite")
+                        .help("Also submit to reviews that already have a response")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("serve")
+                .about("Serve the store API as JSON over HTTP instead of starting the TUI")
+                .next_help_heading("Command options")
+                .arg(
+                    Arg::new("serve")
+                        .value_name("ADDR")
+                        .help("Address to listen on, e.g. 127.0.0.1:8080")
+                        .value_parser(clap::value_parser!(SocketAddr))
+                        .required(true),
+                ),
+        )
+        .subcommand(Command::new("health").about("Check the store credentials and app id, then exit"))
+        .subcommand(Command::new("test-ai").about("Draft a response to a sample review").hide(true))
 }
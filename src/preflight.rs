@@ -0,0 +1,157 @@
+use anyhow::{anyhow, Result};
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use crate::api::{AccessProblem, ApiClient};
+use crate::config::{Config, Platform};
+
+// Checks the store credentials before the TUI takes over the terminal, so a
+// bad key shows up as a readable diagnostic instead of an empty screen.
+// Returns true when the check failed and the user chose to continue offline.
+pub async fn run(client: &mut ApiClient) -> Result<bool> {
+    let Some(store) = check(client).await? else {
+        return Ok(false);
+    };
+
+    if !io::stdin().is_terminal() {
+        return Err(anyhow!("{} access check failed", store));
+    }
+
+    print!("\nContinue offline without loading reviews? [y/N] ");
+    io::stdout().flush().ok();
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+
+    if answer.trim().eq_ignore_ascii_case("y") {
+        Ok(true)
+    } else {
+        Err(anyhow!("{} access check failed", store))
+    }
+}
+
+// `rustpond health`: the same check on its own, failing instead of offering
+// to continue offline
+pub async fn health(client: &mut ApiClient) -> Result<()> {
+    match check(client).await? {
+        Some(store) => Err(anyhow!("{} access check failed", store)),
+        None => Ok(()),
+    }
+}
+
+// Prints the outcome with diagnostics, and returns the store that failed
+async fn check(client: &mut ApiClient) -> Result<Option<&'static str>> {
+    let targets: Vec<String> = client
+        .configs()
+        .iter()
+        .map(|config| {
+            format!(
+                "{} access for {}",
+                store_name(&config.platform),
+                config.app_id
+            )
+        })
+        .collect();
+    print!("Checking {}... ", targets.join(" and "));
+    io::stdout().flush().ok();
+
+    let (platform, problem) = match client.check_access().await {
+        Ok(()) => {
+            println!("ok");
+            return Ok(None);
+        }
+        Err(failure) => failure,
+    };
+    let store = store_name(&platform);
+    let config = client
+        .configs()
+        .into_iter()
+        .find(|config| config.platform == platform)
+        .ok_or_else(|| anyhow!("{} access check failed", store))?;
+
+    println!("failed\n");
+    if client.configs().len() > 1 {
+        println!("{}:", store);
+    }
+    let (summary, details) = describe(&problem, config);
+    println!("{}", summary);
+    if !details.is_empty() {
+        println!("  {}", details);
+    }
+    println!("\nSuggestions:");
+    for suggestion in suggestions(&problem, &platform) {
+        println!("  - {}", suggestion);
+    }
+
+    Ok(Some(store))
+}
+
+fn store_name(platform: &Platform) -> &'static str {
+    match platform {
+        Platform::Ios => "App Store Connect",
+        Platform::Android => "Google Play",
+    }
+}
+
+fn describe(problem: &AccessProblem, config: &Config) -> (String, String) {
+    match problem {
+        AccessProblem::Credentials(e) => (
+            "Could not authenticate with the configured credentials.".to_string(),
+            e.clone(),
+        ),
+        AccessProblem::Network(e) => ("Could not reach the store API.".to_string(), e.clone()),
+        AccessProblem::Unauthorized(body) => (
+            "The store rejected the credentials (401).".to_string(),
+            body.clone(),
+        ),
+        AccessProblem::Forbidden(body) => (
+            "The credentials don't have access to this app (403).".to_string(),
+            body.clone(),
+        ),
+        AccessProblem::NotFound(body) => (
+            format!("App '{}' was not found (404).", config.app_id),
+            body.clone(),
+        ),
+        AccessProblem::Other(status, body) => {
+            (format!("The store API returned {}.", status), body.clone())
+        }
+    }
+}
+
+fn suggestions(problem: &AccessProblem, platform: &Platform) -> Vec<&'static str> {
+    match (problem, platform) {
+        (AccessProblem::Network(_), _) => vec![
+            "check your network connection and any proxy settings",
+        ],
+        (AccessProblem::Credentials(_), Platform::Ios) => vec![
+            "check APP_STORE_CONNECT_PRIVATE_KEY_PATH points to the .p8 key downloaded from App Store Connect",
+            "check APP_STORE_CONNECT_KEY_ID and APP_STORE_CONNECT_ISSUER_ID are set",
+        ],
+        (AccessProblem::Credentials(_), Platform::Android) => vec![
+            "check GOOGLE_PLAY_SERVICE_ACCOUNT_PATH points to the service account's JSON key",
+            "check your network connection, since the key is exchanged for a token online",
+        ],
+        (AccessProblem::Unauthorized(_), Platform::Ios) => vec![
+            "check APP_STORE_CONNECT_KEY_ID matches the .p8 key file",
+            "check APP_STORE_CONNECT_ISSUER_ID",
+            "check the key hasn't been revoked and your system clock is correct",
+        ],
+        (AccessProblem::Unauthorized(_), Platform::Android) => vec![
+            "check the service account key hasn't been deleted or disabled",
+        ],
+        (AccessProblem::Forbidden(_), Platform::Ios) => vec![
+            "give the API key the Customer Support, App Manager or Admin role",
+        ],
+        (AccessProblem::Forbidden(_), Platform::Android) => vec![
+            "invite the service account in Play Console > Users and permissions",
+            "grant it the \"Reply to reviews\" permission for this app",
+        ],
+        (AccessProblem::NotFound(_), Platform::Ios) => vec![
+            "check APP_STORE_APP_ID (or --app-id) is the numeric Apple ID of the app",
+        ],
+        (AccessProblem::NotFound(_), Platform::Android) => vec![
+            "check GOOGLE_PLAY_PACKAGE_NAME (or --app-id) is the app's package name",
+        ],
+        (AccessProblem::Other(_, _), _) => vec![
+            "try again in a moment; the store API may be having problems",
+        ],
+    }
+}
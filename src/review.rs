@@ -1,5 +1,14 @@
+use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::config::Platform;
+
+// JSON:API resource types App Store Connect uses for reviews and responses
+pub const REVIEW_TYPE: &str = "customerReviews";
+pub const RESPONSE_TYPE: &str = "customerReviewResponses";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Review {
@@ -12,9 +21,61 @@ pub struct Review {
     pub territory: String,
     pub version: Option<String>,
     pub response: Option<ReviewResponse>,
+    // Set when Google Play returned a machine translation in `body`
+    #[serde(default)]
+    pub original_text: Option<String>,
+    #[serde(default)]
+    pub language: Option<String>,
+    // Helpfulness votes from other users (Google Play only)
+    #[serde(default)]
+    pub thumbs_up: Option<i64>,
+    #[serde(default)]
+    pub thumbs_down: Option<i64>,
+    // Every user and developer comment in order (Google Play only)
+    #[serde(default)]
+    pub thread: Vec<ThreadComment>,
+    // Which store the review came from, only set with `--both`
+    #[serde(default)]
+    pub platform: Option<Platform>,
+    // The app id the review was loaded for, checked again before submitting
+    #[serde(default)]
+    pub app_id: Option<String>,
+    // The store's JSON for this review, only kept with `--debug`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<serde_json::Value>,
+}
+
+// Reviews for `--reviews-file`, in the same JSON shape the offline cache uses
+pub fn load_reviews_file(path: &Path) -> Result<Vec<Review>> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+    serde_json::from_str(&contents)
+        .map_err(|e| anyhow!("Failed to parse reviews in {}: {}", path.display(), e))
+}
+
+impl Review {
+    // Stars for display. `rating` keeps the raw API value, which could be
+    // outside 1-5 if the store sends something unexpected.
+    pub fn stars(&self) -> String {
+        "⭐".repeat(self.rating.clamp(0, 5) as usize)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadComment {
+    pub author: CommentAuthor,
+    pub text: String,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentAuthor {
+    User,
+    Developer,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReviewResponse {
     pub id: String,
     pub response_body: String,
@@ -22,20 +83,82 @@ pub struct ReviewResponse {
     pub state: ResponseState,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum ResponseState {
     Published,
     Pending,
+    // A state this tool doesn't know about, kept as Apple sent it
+    Other(String),
+}
+
+impl ResponseState {
+    pub fn from_api(state: &str) -> Self {
+        match state {
+            "PUBLISHED" => ResponseState::Published,
+            "PENDING_PUBLISH" | "PENDING" => ResponseState::Pending,
+            other => ResponseState::Other(other.to_string()),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            ResponseState::Published => "Published".to_string(),
+            ResponseState::Pending => "Pending publication".to_string(),
+            ResponseState::Other(raw) => format!("{} (unrecognized state)", raw),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ReviewsResponse {
     pub data: Vec<ReviewData>,
+    pub included: Option<Vec<IncludedResource>>,
     pub links: Option<Links>,
     pub meta: Option<Meta>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SingleReviewResponse {
+    pub data: ReviewData,
+    pub included: Option<Vec<IncludedResource>>,
+}
+
+// Resources requested with `include=response,app`. Only the app's id is used,
+// and other types are tolerated and ignored.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncludedResource {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub attributes: Option<IncludedResponseAttributes>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncludedResponseAttributes {
+    #[serde(rename = "responseBody")]
+    pub response_body: Option<String>,
+    #[serde(rename = "lastModifiedDate")]
+    pub last_modified_date: Option<DateTime<Utc>>,
+    pub state: Option<String>,
+}
+
+impl IncludedResource {
+    pub fn into_review_response(self) -> Option<ReviewResponse> {
+        if self.type_ != RESPONSE_TYPE {
+            return None;
+        }
+        let attributes = self.attributes?;
+
+        Some(ReviewResponse {
+            id: self.id,
+            response_body: attributes.response_body.unwrap_or_default(),
+            last_modified_date: attributes.last_modified_date?,
+            state: ResponseState::from_api(attributes.state.as_deref().unwrap_or("PENDING")),
+        })
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ReviewData {
     pub id: String,
@@ -45,6 +168,30 @@ pub struct ReviewData {
     pub relationships: Option<ReviewRelationships>,
 }
 
+impl ReviewData {
+    // The linked response's id. A link to anything other than a response is
+    // reported rather than matched against the included responses.
+    pub fn response_id(&self) -> Result<Option<&str>> {
+        let Some(linked) = self
+            .relationships
+            .as_ref()
+            .and_then(|r| r.response.as_ref())
+            .and_then(|r| r.data.as_ref())
+        else {
+            return Ok(None);
+        };
+        if linked.type_ != RESPONSE_TYPE {
+            return Err(anyhow!(
+                "review {} links its response as unexpected type '{}' (expected '{}')",
+                self.id,
+                linked.type_,
+                RESPONSE_TYPE
+            ));
+        }
+        Ok(Some(&linked.id))
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ReviewAttributes {
     pub rating: i32,
@@ -92,9 +239,22 @@ pub struct Paging {
     pub limit: i32,
 }
 
-impl From<ReviewData> for Review {
-    fn from(data: ReviewData) -> Self {
-        Review {
+// Anything other than a customer review, e.g. after an API change, is rejected
+// instead of being shown as a bogus row
+impl TryFrom<ReviewData> for Review {
+    type Error = anyhow::Error;
+
+    fn try_from(data: ReviewData) -> Result<Self> {
+        if data.type_ != REVIEW_TYPE {
+            return Err(anyhow!(
+                "resource {} has unexpected type '{}' (expected '{}')",
+                data.id,
+                data.type_,
+                REVIEW_TYPE
+            ));
+        }
+
+        Ok(Review {
             id: data.id,
             rating: data.attributes.rating,
             title: data.attributes.title,
@@ -104,6 +264,44 @@ impl From<ReviewData> for Review {
             territory: data.attributes.territory,
             version: None, // This would need to be extracted from relationships if needed
             response: None, // This will be populated on-demand when entering response mode
-        }
+            original_text: None,
+            language: None,
+            thumbs_up: None,
+            thumbs_down: None,
+            thread: Vec::new(),
+            platform: None,
+            app_id: None,
+            raw: None,
+        })
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn review_rated(rating: i32) -> Review {
+        serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "rating": rating,
+            "reviewer_nickname": "someone",
+            "created_date": "2024-01-01T00:00:00Z",
+            "territory": "USA",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn stars_are_clamped_to_zero_through_five() {
+        assert_eq!(review_rated(-1).stars(), "");
+        assert_eq!(review_rated(0).stars(), "");
+        assert_eq!(review_rated(3).stars(), "⭐⭐⭐");
+        assert_eq!(review_rated(7).stars(), "⭐⭐⭐⭐⭐");
+    }
+
+    #[test]
+    fn out_of_range_ratings_are_kept_as_sent() {
+        assert_eq!(review_rated(-1).rating, -1);
+        assert_eq!(review_rated(7).rating, 7);
+    }
+}
@@ -0,0 +1,132 @@
+use anyhow::{anyhow, Result};
+use std::fs;
+use std::path::Path;
+
+use crate::review::Review;
+
+// Words and phrases that should get a support person's attention, such as
+// profanity or threats. Only used to flag reviews; their text is never changed.
+#[derive(Debug, Clone, Default)]
+pub struct SensitiveTerms {
+    // As written in the list, for showing which ones matched
+    terms: Vec<String>,
+}
+
+impl SensitiveTerms {
+    // One term per line. Blank lines and lines starting with '#' are ignored.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read sensitive terms {}: {}", path.display(), e))?;
+        let terms = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
+            .collect();
+        Ok(Self { terms })
+    }
+
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    // Terms found in the review's title or text, in list order. Matching ignores
+    // case and punctuation and works on whole words, so "ass" doesn't match
+    // "class". A trailing '*' matches any word starting with the term.
+    pub fn matches(&self, review: &Review) -> Vec<&str> {
+        if self.terms.is_empty() {
+            return Vec::new();
+        }
+        let text = [
+            review.title.as_deref(),
+            review.body.as_deref(),
+            review.original_text.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ");
+        let padded = format!(" {} ", normalize(&text));
+
+        self.terms
+            .iter()
+            .filter(|term| match term.strip_suffix('*') {
+                Some(stem) => padded.contains(&format!(" {}", normalize(stem))),
+                None => padded.contains(&format!(" {} ", normalize(term))),
+            })
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+// Lowercase words separated by single spaces
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .map(|word| word.trim_matches('\''))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(terms: &[&str]) -> SensitiveTerms {
+        SensitiveTerms {
+            terms: terms.iter().map(|term| term.to_string()).collect(),
+        }
+    }
+
+    fn review(title: &str, body: &str) -> Review {
+        serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "rating": 1,
+            "title": title,
+            "body": body,
+            "reviewer_nickname": "someone",
+            "created_date": "2024-01-01T00:00:00Z",
+            "territory": "USA",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn matches_whole_words_only() {
+        let terms = terms(&["ass", "refund now"]);
+        assert!(terms
+            .matches(&review("Great class", "Passed my exam, a classic"))
+            .is_empty());
+        assert_eq!(terms.matches(&review("", "What an ass.")), ["ass"]);
+        assert_eq!(
+            terms.matches(&review("Give me a", "refund, now!")),
+            ["refund now"]
+        );
+        assert!(terms.matches(&review("", "refund nowhere")).is_empty());
+    }
+
+    #[test]
+    fn trailing_star_matches_words_starting_with_the_stem() {
+        let terms = terms(&["scam*"]);
+        assert_eq!(terms.matches(&review("Scammers", "")), ["scam*"]);
+        assert_eq!(terms.matches(&review("", "total scam")), ["scam*"]);
+        assert!(terms.matches(&review("", "no subscams here")).is_empty());
+    }
+
+    #[test]
+    fn matching_ignores_case() {
+        let terms = terms(&["Lawsuit", "kill*"]);
+        assert_eq!(
+            terms.matches(&review("LAWSUIT incoming", "this KILLS my battery")),
+            ["Lawsuit", "kill*"]
+        );
+    }
+
+    #[test]
+    fn empty_list_matches_nothing() {
+        assert!(terms(&[])
+            .matches(&review("Scam", "What an ass"))
+            .is_empty());
+    }
+}
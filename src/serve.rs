@@ -0,0 +1,214 @@
+use anyhow::{anyhow, Result};
+use http_body_util::{BodyExt, Full, Limited};
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use serde::Deserialize;
+use serde_json::json;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::Mutex;
+
+use crate::api::{ApiClient, ResponseExists};
+use crate::audit::AiUsage;
+use crate::config::Config;
+use crate::text;
+
+// Responses are capped at a few thousand characters, so anything bigger is a mistake
+const MAX_BODY_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Deserialize)]
+struct RespondRequest {
+    response: String,
+}
+
+struct Server {
+    // Store clients refresh tokens and track paging, so requests take turns
+    client: Mutex<ApiClient>,
+    config: Config,
+    token: Option<String>,
+}
+
+// Serves `GET /reviews` and `POST /reviews/{id}/respond` until Ctrl+C. Without
+// `RUSTPOND_SERVE_TOKEN` only loopback addresses are allowed, since anyone who
+// can reach the server can post responses with the configured credentials.
+pub async fn run(client: ApiClient, config: Config, addr: SocketAddr) -> Result<()> {
+    if config.serve_token.is_none() && !addr.ip().is_loopback() {
+        return Err(anyhow!(
+            "Set RUSTPOND_SERVE_TOKEN to serve on a non-loopback address like {}",
+            addr
+        ));
+    }
+
+    let listener = TcpListener::bind(addr)
+        .await
+        .map_err(|e| anyhow!("Failed to listen on {}: {}", addr, e))?;
+    println!("Serving reviews on http://{} (Ctrl+C to stop)", addr);
+
+    let server = Arc::new(Server {
+        client: Mutex::new(client),
+        token: config.serve_token.clone(),
+        config,
+    });
+
+    loop {
+        let (stream, _) = tokio::select! {
+            accepted = listener.accept() => accepted?,
+            _ = tokio::signal::ctrl_c() => break,
+        };
+
+        let server = server.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |request| {
+                let server = server.clone();
+                async move { Ok::<_, Infallible>(server.handle(request).await) }
+            });
+            if let Err(e) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                eprintln!("Connection error: {}", e);
+            }
+        });
+    }
+
+    Ok(())
+}
+
+impl Server {
+    async fn handle(&self, request: Request<Incoming>) -> Response<Full<Bytes>> {
+        if !self.authorized(&request) {
+            return json_response(
+                StatusCode::UNAUTHORIZED,
+                json!({ "error": "missing or invalid bearer token" }),
+            );
+        }
+
+        let path: Vec<&str> = request.uri().path().trim_matches('/').split('/').collect();
+
+        match (request.method(), path.as_slice()) {
+            (&Method::GET, ["reviews"]) => self.list_reviews().await,
+            (&Method::POST, ["reviews", review_id, "respond"]) => {
+                let review_id = review_id.to_string();
+                self.respond(&review_id, request).await
+            }
+            (_, ["reviews"]) | (_, ["reviews", _, "respond"]) => json_response(
+                StatusCode::METHOD_NOT_ALLOWED,
+                json!({ "error": "method not allowed" }),
+            ),
+            _ => json_response(StatusCode::NOT_FOUND, json!({ "error": "not found" })),
+        }
+    }
+
+    fn authorized(&self, request: &Request<Incoming>) -> bool {
+        let Some(token) = &self.token else {
+            return true;
+        };
+        request
+            .headers()
+            .get(hyper::header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .is_some_and(|given| given == token)
+    }
+
+    async fn list_reviews(&self) -> Response<Full<Bytes>> {
+        let mut client = self.client.lock().await;
+        match client.refresh_all_reviews().await {
+            Ok((reviews, warning)) => {
+                if let Some(warning) = warning {
+                    eprintln!("Warning: {}", warning);
+                }
+                json_response(StatusCode::OK, json!(reviews))
+            }
+            Err(e) => json_response(StatusCode::BAD_GATEWAY, json!({ "error": e.to_string() })),
+        }
+    }
+
+    async fn respond(&self, review_id: &str, request: Request<Incoming>) -> Response<Full<Bytes>> {
+        let body = match Limited::new(request.into_body(), MAX_BODY_BYTES)
+            .collect()
+            .await
+        {
+            Ok(body) => body.to_bytes(),
+            Err(e) => {
+                return json_response(
+                    StatusCode::BAD_REQUEST,
+                    json!({ "error": format!("failed to read body: {}", e) }),
+                )
+            }
+        };
+        let response_text = match serde_json::from_slice::<RespondRequest>(&body) {
+            Ok(parsed) if !parsed.response.trim().is_empty() => parsed.response,
+            _ => {
+                return json_response(
+                    StatusCode::BAD_REQUEST,
+                    json!({ "error": "expected a JSON body like {\"response\": \"...\"}" }),
+                )
+            }
+        };
+
+        // Same checks as the TUI and --import-responses
+        if let Some(min) = self.config.min_response_chars {
+            let length = text::char_count(response_text.trim());
+            if length < min {
+                return json_response(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    json!({ "error": format!("response is {} characters, minimum is {}", length, min) }),
+                );
+            }
+        }
+        let response_body = text::prepare_response(&response_text, &self.config, false);
+        if let Some(limit) = self.config.platform.character_limit() {
+            let length = text::char_count(&response_body);
+            if length > limit {
+                return json_response(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    json!({ "error": format!("response is {} characters, limit is {}", length, limit) }),
+                );
+            }
+        }
+
+        let mut client = self.client.lock().await;
+        // Says which app the review belongs to, which the submission checks
+        // against the configured one
+        let review = match client.get_review(review_id).await {
+            Ok(review) => review,
+            Err(e) => {
+                return json_response(StatusCode::BAD_GATEWAY, json!({ "error": e.to_string() }))
+            }
+        };
+        match client
+            .submit_response(
+                review_id,
+                review.app_id.as_deref(),
+                &response_body,
+                AiUsage::default(),
+            )
+            .await
+        {
+            Ok(warning) => json_response(
+                StatusCode::OK,
+                json!({ "review_id": review_id, "response": response_body, "warning": warning }),
+            ),
+            Err(e) if e.is::<ResponseExists>() => {
+                json_response(StatusCode::CONFLICT, json!({ "error": e.to_string() }))
+            }
+            Err(e) => json_response(StatusCode::BAD_GATEWAY, json!({ "error": e.to_string() })),
+        }
+    }
+}
+
+fn json_response(status: StatusCode, body: serde_json::Value) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::from(body.to_string())));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        hyper::header::CONTENT_TYPE,
+        hyper::header::HeaderValue::from_static("application/json"),
+    );
+    response
+}
@@ -0,0 +1,238 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use crate::review::Review;
+
+// Thresholds for the spam heuristic. Fields missing from a rules file keep
+// the defaults below.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpamRules {
+    // Flag reviews containing URLs
+    pub flag_links: bool,
+    // Flag when distinct words / total words falls below this
+    pub min_unique_word_ratio: f64,
+    // Only apply the word ratio to reviews with at least this many words
+    pub min_words_for_ratio: usize,
+    // Flag when this share of letters is uppercase
+    pub max_uppercase_ratio: f64,
+    pub min_letters_for_uppercase: usize,
+    // Flag the same character repeated this many times in a row
+    pub max_repeated_chars: usize,
+    pub min_chars: usize,
+    pub max_chars: usize,
+    // How many of the signals above must match before a review is flagged
+    pub min_signals: usize,
+    // Refuse to generate AI responses for flagged reviews
+    pub exclude_from_ai: bool,
+}
+
+impl Default for SpamRules {
+    fn default() -> Self {
+        Self {
+            flag_links: true,
+            min_unique_word_ratio: 0.3,
+            min_words_for_ratio: 8,
+            max_uppercase_ratio: 0.8,
+            min_letters_for_uppercase: 12,
+            max_repeated_chars: 8,
+            min_chars: 2,
+            max_chars: 4000,
+            min_signals: 1,
+            exclude_from_ai: false,
+        }
+    }
+}
+
+impl SpamRules {
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read spam rules {}: {}", path.display(), e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| anyhow!("Failed to parse spam rules {}: {}", path.display(), e))
+    }
+
+    // Reasons the review looks like spam, or None if it doesn't
+    pub fn classify(&self, review: &Review) -> Option<Vec<&'static str>> {
+        let text = format!(
+            "{} {}",
+            review.title.as_deref().unwrap_or(""),
+            review.body.as_deref().unwrap_or("")
+        );
+        let text = text.trim();
+        let mut reasons = Vec::new();
+
+        let lower = text.to_lowercase();
+        if self.flag_links
+            && (lower.contains("http://") || lower.contains("https://") || lower.contains("www."))
+        {
+            reasons.push("contains a link");
+        }
+
+        let words: Vec<String> = lower
+            .split_whitespace()
+            .map(|word| {
+                word.trim_matches(|c: char| !c.is_alphanumeric())
+                    .to_string()
+            })
+            .filter(|word| !word.is_empty())
+            .collect();
+        if words.len() >= self.min_words_for_ratio {
+            let unique: HashSet<&String> = words.iter().collect();
+            if (unique.len() as f64 / words.len() as f64) < self.min_unique_word_ratio {
+                reasons.push("repeated text");
+            }
+        }
+
+        let letters: Vec<char> = text.chars().filter(|c| c.is_alphabetic()).collect();
+        if letters.len() >= self.min_letters_for_uppercase {
+            let upper = letters.iter().filter(|c| c.is_uppercase()).count();
+            if (upper as f64 / letters.len() as f64) >= self.max_uppercase_ratio {
+                reasons.push("mostly capitals");
+            }
+        }
+
+        if longest_char_run(text) >= self.max_repeated_chars {
+            reasons.push("repeated characters");
+        }
+
+        let length = text.chars().count();
+        if length < self.min_chars {
+            reasons.push("too short");
+        } else if length > self.max_chars {
+            reasons.push("unusually long");
+        }
+
+        if !reasons.is_empty() && reasons.len() >= self.min_signals {
+            Some(reasons)
+        } else {
+            None
+        }
+    }
+}
+
+fn longest_char_run(text: &str) -> usize {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous = None;
+
+    for c in text.chars() {
+        if Some(c) == previous && !c.is_whitespace() {
+            run += 1;
+        } else {
+            run = 1;
+        }
+        previous = Some(c);
+        longest = longest.max(run);
+    }
+
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn review(body: &str) -> Review {
+        serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "rating": 1,
+            "body": body,
+            "reviewer_nickname": "someone",
+            "created_date": "2024-01-01T00:00:00Z",
+            "territory": "USA",
+        }))
+        .unwrap()
+    }
+
+    fn reasons(body: &str) -> Vec<&'static str> {
+        SpamRules::default()
+            .classify(&review(body))
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn ordinary_review_is_not_flagged() {
+        assert_eq!(
+            SpamRules::default().classify(&review(
+                "The app crashes when I open the settings screen on my phone."
+            )),
+            None
+        );
+    }
+
+    #[test]
+    fn links_are_flagged_unless_disabled() {
+        assert_eq!(
+            reasons("Free coins at https://example.com"),
+            ["contains a link"]
+        );
+        assert_eq!(reasons("Visit WWW.example.com now"), ["contains a link"]);
+        assert!(reasons("I use it on the web too").is_empty());
+
+        let rules = SpamRules {
+            flag_links: false,
+            ..SpamRules::default()
+        };
+        assert_eq!(
+            rules.classify(&review("Free coins at https://example.com")),
+            None
+        );
+    }
+
+    #[test]
+    fn repeated_words_are_flagged_only_in_long_enough_text() {
+        assert_eq!(
+            reasons("buy buy buy buy buy buy buy buy now"),
+            ["repeated text"]
+        );
+        // Below `min_words_for_ratio`
+        assert!(reasons("good good good").is_empty());
+        assert!(reasons("one two three four five six seven eight nine").is_empty());
+    }
+
+    #[test]
+    fn mostly_capitals_are_flagged_only_with_enough_letters() {
+        assert_eq!(
+            reasons("THIS APP IS TERRIBLE AND SLOW"),
+            ["mostly capitals"]
+        );
+        assert!(reasons("WORST APP").is_empty());
+        assert!(reasons("This App Is Terrible And Slow").is_empty());
+    }
+
+    #[test]
+    fn long_runs_of_one_character_are_flagged() {
+        assert_eq!(reasons("Great app!!!!!!!!"), ["repeated characters"]);
+        assert!(reasons("Great app!!!!!!!").is_empty());
+        // Whitespace runs don't count
+        assert!(reasons(&format!("Great{}app", " ".repeat(12))).is_empty());
+    }
+
+    #[test]
+    fn too_short_and_unusually_long_reviews_are_flagged() {
+        assert_eq!(reasons("k"), ["too short"]);
+        assert!(reasons("ok").is_empty());
+        let long = "The app is fine but could be better in places. ".repeat(90);
+        assert_eq!(reasons(&long), ["repeated text", "unusually long"]);
+    }
+
+    #[test]
+    fn min_signals_requires_several_reasons() {
+        let rules = SpamRules {
+            min_signals: 2,
+            ..SpamRules::default()
+        };
+        assert_eq!(
+            rules.classify(&review("Free coins at https://example.com")),
+            None
+        );
+        assert_eq!(
+            rules.classify(&review("FREE COINS AT HTTPS://EXAMPLE.COM")),
+            Some(vec!["contains a link", "mostly capitals"])
+        );
+    }
+}
@@ -0,0 +1,456 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::audit::AiUsage;
+use crate::review::Review;
+
+// Response rate snapshots older than this are dropped
+const RESPONSE_RATE_HISTORY_DAYS: i64 = 90;
+
+// Reasons offered when skipping a review to handle it manually later
+pub const SKIP_REASONS: &[&str] = &[
+    "needs engineering input",
+    "spam",
+    "duplicate",
+    "handle later",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedReview {
+    pub reason: String,
+    pub skipped_at: DateTime<Utc>,
+}
+
+// A free-form memo on a review, never sent to the store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewNote {
+    pub text: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+// A reminder to check back on a review, e.g. after asking the reviewer to
+// email support. The reviewer and snippet are kept so the reminder still makes
+// sense when the review isn't loaded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FollowUp {
+    pub due: NaiveDate,
+    pub note: String,
+    pub reviewer: String,
+    pub snippet: String,
+    pub created_at: DateTime<Utc>,
+}
+
+// A rating that differs from the cached copy of the same review
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RatingChange {
+    pub from: i32,
+    pub to: i32,
+    pub detected_at: DateTime<Utc>,
+}
+
+// The version of a review before the reviewer last changed its title or text.
+// Google Play translations can change between runs, so the original text is
+// compared when there is one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewEdit {
+    pub rating: i32,
+    pub title: Option<String>,
+    pub text: Option<String>,
+    pub detected_at: DateTime<Utc>,
+}
+
+// How many of the loaded reviews had a response, kept once per day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResponseRateSnapshot {
+    pub app_ids: Vec<String>,
+    pub taken_at: DateTime<Utc>,
+    pub responded: usize,
+    pub total: usize,
+}
+
+impl ResponseRateSnapshot {
+    pub fn percent(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.responded as f64 * 100.0 / self.total as f64
+    }
+}
+
+// A response confirmed while offline, submitted on the next online start
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedResponse {
+    pub app_id: String,
+    pub review_id: String,
+    // The exact text to submit, signature included
+    pub response_body: String,
+    #[serde(default)]
+    pub ai_usage: AiUsage,
+    pub queued_at: DateTime<Utc>,
+    // The review already had a response, which this one replaces
+    #[serde(default)]
+    pub replace: bool,
+}
+
+// Per-review notes that only exist on this machine, keyed by review id
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LocalState {
+    #[serde(default)]
+    pub skipped: HashMap<String, SkippedReview>,
+    #[serde(default)]
+    pub queued_responses: Vec<QueuedResponse>,
+    // Kept after the cache moves on, so changes stay visible
+    #[serde(default)]
+    pub rating_changes: HashMap<String, RatingChange>,
+    #[serde(default)]
+    pub review_edits: HashMap<String, ReviewEdit>,
+    #[serde(default)]
+    pub response_rates: Vec<ResponseRateSnapshot>,
+    #[serde(default)]
+    pub notes: HashMap<String, ReviewNote>,
+    #[serde(default)]
+    pub follow_ups: HashMap<String, FollowUp>,
+}
+
+impl LocalState {
+    // A missing file just means nothing has been recorded yet
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read state file {}: {}", path.display(), e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| anyhow!("Failed to parse state file {}: {}", path.display(), e))
+    }
+
+    // Written to a temporary file first so an interrupted save can't corrupt it
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| anyhow!("Failed to create {}: {}", parent.display(), e))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| anyhow!("Failed to serialize state: {}", e))?;
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, content)
+            .and_then(|_| fs::rename(&tmp_path, path))
+            .map_err(|e| anyhow!("Failed to write state file {}: {}", path.display(), e))
+    }
+
+    // Records ratings and text that changed since `previous`, the reviews
+    // cached by the last load
+    pub fn record_changes(&mut self, previous: &[Review], reviews: &[Review]) {
+        let previous: HashMap<&str, &Review> = previous
+            .iter()
+            .map(|review| (review.id.as_str(), review))
+            .collect();
+        for review in reviews {
+            let Some(&old) = previous.get(review.id.as_str()) else {
+                continue;
+            };
+            if old.title != review.title || review_text(old) != review_text(review) {
+                self.review_edits.insert(
+                    review.id.clone(),
+                    ReviewEdit {
+                        rating: old.rating,
+                        title: old.title.clone(),
+                        text: review_text(old).map(str::to_string),
+                        detected_at: Utc::now(),
+                    },
+                );
+            }
+            let old_rating = old.rating;
+            if old_rating == review.rating {
+                continue;
+            }
+            // Compare against the rating before the first change
+            let from = self
+                .rating_changes
+                .get(&review.id)
+                .map_or(old_rating, |change| change.from);
+            if from == review.rating {
+                self.rating_changes.remove(&review.id);
+            } else {
+                self.rating_changes.insert(
+                    review.id.clone(),
+                    RatingChange {
+                        from,
+                        to: review.rating,
+                        detected_at: Utc::now(),
+                    },
+                );
+            }
+        }
+    }
+
+    // A later snapshot on the same day replaces the earlier one
+    pub fn record_response_rate(&mut self, app_ids: Vec<String>, responded: usize, total: usize) {
+        let now = Utc::now();
+        self.response_rates.retain(|snapshot| {
+            !(snapshot.app_ids == app_ids && snapshot.taken_at.date_naive() == now.date_naive())
+                && now - snapshot.taken_at < Duration::days(RESPONSE_RATE_HISTORY_DAYS)
+        });
+        self.response_rates.push(ResponseRateSnapshot {
+            app_ids,
+            taken_at: now,
+            responded,
+            total,
+        });
+    }
+
+    // Oldest first
+    pub fn response_rate_history(&self, app_ids: &[String]) -> Vec<&ResponseRateSnapshot> {
+        self.response_rates
+            .iter()
+            .filter(|snapshot| snapshot.app_ids == app_ids)
+            .collect()
+    }
+
+    pub fn is_queued(&self, review_id: &str) -> bool {
+        self.queued_responses
+            .iter()
+            .any(|queued| queued.review_id == review_id)
+    }
+
+    // A newer draft for the same review replaces the queued one
+    pub fn queue_response(&mut self, queued: QueuedResponse) {
+        self.queued_responses
+            .retain(|existing| existing.review_id != queued.review_id);
+        self.queued_responses.push(queued);
+    }
+
+    pub fn skip_reason(&self, review_id: &str) -> Option<&str> {
+        self.skipped
+            .get(review_id)
+            .map(|skipped| skipped.reason.as_str())
+    }
+
+    pub fn review_edit(&self, review_id: &str) -> Option<&ReviewEdit> {
+        self.review_edits.get(review_id)
+    }
+
+    pub fn note(&self, review_id: &str) -> Option<&ReviewNote> {
+        self.notes.get(review_id)
+    }
+
+    // An empty note removes it
+    pub fn set_note(&mut self, review_id: &str, text: &str) {
+        let text = text.trim();
+        if text.is_empty() {
+            self.notes.remove(review_id);
+        } else {
+            self.notes.insert(
+                review_id.to_string(),
+                ReviewNote {
+                    text: text.to_string(),
+                    updated_at: Utc::now(),
+                },
+            );
+        }
+    }
+
+    pub fn follow_up(&self, review_id: &str) -> Option<&FollowUp> {
+        self.follow_ups.get(review_id)
+    }
+
+    pub fn set_follow_up(&mut self, review_id: &str, follow_up: Option<FollowUp>) {
+        match follow_up {
+            Some(follow_up) => {
+                self.follow_ups.insert(review_id.to_string(), follow_up);
+            }
+            None => {
+                self.follow_ups.remove(review_id);
+            }
+        }
+    }
+
+    // Earliest due first, with the review id
+    pub fn follow_ups_by_due(&self) -> Vec<(&str, &FollowUp)> {
+        let mut follow_ups: Vec<_> = self
+            .follow_ups
+            .iter()
+            .map(|(review_id, follow_up)| (review_id.as_str(), follow_up))
+            .collect();
+        follow_ups.sort_by_key(|(review_id, follow_up)| (follow_up.due, *review_id));
+        follow_ups
+    }
+
+    pub fn due_follow_up_count(&self, today: NaiveDate) -> usize {
+        self.follow_ups
+            .values()
+            .filter(|follow_up| follow_up.due <= today)
+            .count()
+    }
+
+    pub fn set_skip_reason(&mut self, review_id: &str, reason: Option<&str>) {
+        match reason {
+            Some(reason) => {
+                self.skipped.insert(
+                    review_id.to_string(),
+                    SkippedReview {
+                        reason: reason.to_string(),
+                        skipped_at: Utc::now(),
+                    },
+                );
+            }
+            None => {
+                self.skipped.remove(review_id);
+            }
+        }
+    }
+}
+
+// Parses follow-up input: a due date as `YYYY-MM-DD` or `+N` days from today,
+// then an optional note. Empty input means no follow-up.
+pub fn parse_follow_up(input: &str, today: NaiveDate) -> Result<Option<(NaiveDate, String)>> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    let (when, note) = input.split_once(' ').unwrap_or((input, ""));
+    let due = match when.strip_prefix('+') {
+        Some(days) => {
+            let days: u64 = days
+                .trim_end_matches('d')
+                .parse()
+                .map_err(|_| anyhow!("Invalid number of days '{}'", when))?;
+            today
+                .checked_add_days(chrono::Days::new(days))
+                .ok_or_else(|| anyhow!("{} days from today is too far out", days))?
+        }
+        None => NaiveDate::parse_from_str(when, "%Y-%m-%d").map_err(|_| {
+            anyhow!(
+                "Invalid due date '{}'. Use YYYY-MM-DD or +N for N days from today",
+                when
+            )
+        })?,
+    };
+
+    Ok(Some((due, note.trim().to_string())))
+}
+
+// What the reviewer wrote, before any store translation
+fn review_text(review: &Review) -> Option<&str> {
+    review.original_text.as_deref().or(review.body.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn review(id: &str, rating: i32, body: &str) -> Review {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "rating": rating,
+            "title": "Title",
+            "body": body,
+            "reviewer_nickname": "someone",
+            "created_date": "2024-01-01T00:00:00Z",
+            "territory": "USA",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn edited_body_is_recorded_with_the_old_text() {
+        let mut state = LocalState::default();
+        state.record_changes(&[review("1", 2, "Crashes")], &[review("1", 2, "Fixed now")]);
+
+        let edit = &state.review_edits["1"];
+        assert_eq!(edit.text.as_deref(), Some("Crashes"));
+        assert_eq!(edit.title.as_deref(), Some("Title"));
+        assert!(state.rating_changes.is_empty());
+    }
+
+    #[test]
+    fn changed_rating_is_recorded_from_the_first_rating() {
+        let mut state = LocalState::default();
+        state.record_changes(&[review("1", 2, "Ok")], &[review("1", 4, "Ok")]);
+        let change = state.rating_changes["1"];
+        assert_eq!((change.from, change.to), (2, 4));
+        assert!(state.review_edits.is_empty());
+
+        state.record_changes(&[review("1", 4, "Ok")], &[review("1", 5, "Ok")]);
+        let change = state.rating_changes["1"];
+        assert_eq!((change.from, change.to), (2, 5));
+
+        // Back where it started
+        state.record_changes(&[review("1", 5, "Ok")], &[review("1", 2, "Ok")]);
+        assert!(state.rating_changes.is_empty());
+    }
+
+    #[test]
+    fn new_review_records_nothing() {
+        let mut state = LocalState::default();
+        state.record_changes(&[review("1", 3, "Fine")], &[review("2", 1, "New")]);
+        assert!(state.review_edits.is_empty());
+        assert!(state.rating_changes.is_empty());
+    }
+
+    #[test]
+    fn unchanged_review_records_nothing() {
+        let mut state = LocalState::default();
+        state.record_changes(&[review("1", 3, "Fine")], &[review("1", 3, "Fine")]);
+        assert!(state.review_edits.is_empty());
+        assert!(state.rating_changes.is_empty());
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn follow_up_in_days_from_today() {
+        let today = date("2024-02-27");
+        assert_eq!(
+            parse_follow_up("+3 check the fix shipped", today).unwrap(),
+            Some((date("2024-03-01"), "check the fix shipped".to_string()))
+        );
+        assert_eq!(
+            parse_follow_up("+7d", today).unwrap(),
+            Some((date("2024-03-05"), String::new()))
+        );
+    }
+
+    #[test]
+    fn follow_up_on_a_date() {
+        assert_eq!(
+            parse_follow_up("  2024-06-01   email sent ", date("2024-02-27")).unwrap(),
+            Some((date("2024-06-01"), "email sent".to_string()))
+        );
+    }
+
+    #[test]
+    fn empty_follow_up_clears_it() {
+        assert_eq!(parse_follow_up("", date("2024-02-27")).unwrap(), None);
+        assert_eq!(parse_follow_up("   ", date("2024-02-27")).unwrap(), None);
+    }
+
+    #[test]
+    fn invalid_follow_up_is_an_error() {
+        let today = date("2024-02-27");
+        for input in ["tomorrow", "+x", "+-1", "2024-13-01", "01/06/2024 note"] {
+            assert!(parse_follow_up(input, today).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn responses_queued_before_replace_was_recorded_are_new_responses() {
+        let queued: QueuedResponse = serde_json::from_value(serde_json::json!({
+            "app_id": "123",
+            "review_id": "1",
+            "response_body": "Thanks!",
+            "queued_at": "2024-01-01T00:00:00Z",
+        }))
+        .unwrap();
+        assert!(!queued.replace);
+    }
+}
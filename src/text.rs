@@ -0,0 +1,297 @@
+use crate::config::Config;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+// Placed between the typed response and the configured signature
+pub const SIGNATURE_SEPARATOR: &str = "\n\n";
+
+// Store limits count Unicode characters, not bytes, so an emoji counts as one
+// (multi-codepoint sequences like flags or skin tones count per codepoint).
+// Counted in NFC like the submitted text, so a decomposed "é" (e + combining
+// accent) counts as one character.
+pub fn char_count(text: &str) -> usize {
+    text.nfc().count()
+}
+
+// The longest start of `text` that `char_count` puts at `max` or fewer. Cut
+// between grapheme clusters, so a decomposed accent stays with its letter.
+pub fn truncate_to_limit(text: &str, max: usize) -> &str {
+    let mut remaining = max;
+    for (start, grapheme) in text.grapheme_indices(true) {
+        let count = char_count(grapheme);
+        if count > remaining {
+            return &text[..start];
+        }
+        remaining -= count;
+    }
+    text
+}
+
+// At most the first `max` characters. Cut on a char boundary, unlike slicing by
+// byte index, so it can't panic in the middle of an emoji or accented letter.
+pub fn truncate_chars(text: &str, max: usize) -> &str {
+    match text.char_indices().nth(max) {
+        Some((cut, _)) => &text[..cut],
+        None => text,
+    }
+}
+
+// Builds the exact text that is sent to the store from what the user wrote.
+// It's normalized to NFC so the stored response is canonical.
+pub fn prepare_response(text: &str, config: &Config, ai_assisted: bool) -> String {
+    let text = normalize_line_breaks(text);
+    let mut body = if config.tidy_whitespace {
+        tidy_whitespace(&text)
+    } else {
+        text
+    };
+
+    for suffix in appended_lines(config, ai_assisted) {
+        body.push_str(SIGNATURE_SEPARATOR);
+        body.push_str(suffix);
+    }
+    body.nfc().collect()
+}
+
+// Signature and AI disclosure, in the order they follow the response
+pub fn appended_lines(config: &Config, ai_assisted: bool) -> Vec<&str> {
+    let disclosure = config.ai_disclosure.as_deref().filter(|_| ai_assisted);
+    config
+        .signature
+        .as_deref()
+        .into_iter()
+        .chain(disclosure)
+        .collect()
+}
+
+// Both stores keep `\n` line breaks in responses, but pasted text can carry
+// `\r\n`, lone `\r` or Unicode line/paragraph separators, which the stores
+// either show as stray characters or drop. Everything becomes `\n`.
+pub fn normalize_line_breaks(text: &str) -> String {
+    text.replace("\r\n", "\n")
+        .replace(['\r', '\u{2028}', '\u{2029}'], "\n")
+}
+
+// Labels the model sometimes puts in front of its reply, matched case-insensitively
+const AI_REPLY_LABELS: &[&str] = &[
+    "suggested response:",
+    "developer response:",
+    "response:",
+    "reply:",
+];
+
+// Quote pairs the model sometimes wraps its whole reply in
+const AI_REPLY_QUOTES: &[(char, char)] =
+    &[('"', '"'), ('“', '”'), ('„', '“'), ('«', '»'), ('\'', '\'')];
+
+// Strips a leading label like "Response:" and quotes around the whole reply.
+// Quotes are only removed when they don't also appear inside, so a reply that
+// starts and ends with separate quotations is left alone.
+pub fn strip_ai_wrapping(text: &str) -> String {
+    let mut text = text.trim();
+    loop {
+        let before = text;
+        if let Some(label) = AI_REPLY_LABELS.iter().find(|label| {
+            text.get(..label.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(label))
+        }) {
+            text = text[label.len()..].trim_start();
+        }
+        for &(open, close) in AI_REPLY_QUOTES {
+            let Some(inner) = text
+                .strip_prefix(open)
+                .and_then(|rest| rest.strip_suffix(close))
+            else {
+                continue;
+            };
+            if !inner.contains([open, close]) {
+                text = inner.trim();
+                break;
+            }
+        }
+        if text == before {
+            return text.to_string();
+        }
+    }
+}
+
+// Removes trailing whitespace from every line, drops leading/trailing blank
+// lines, and collapses runs of three or more blank lines into a single one.
+pub fn tidy_whitespace(text: &str) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    let mut blank_run = 0;
+
+    for line in text.lines().map(str::trim_end) {
+        if line.is_empty() {
+            blank_run += 1;
+            continue;
+        }
+
+        if !lines.is_empty() {
+            let kept_blanks = if blank_run >= 3 { 1 } else { blank_run };
+            lines.extend(std::iter::repeat_n("", kept_blanks));
+        }
+        blank_run = 0;
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+// Display-only: turns every run of blank lines into a single blank line and
+// drops leading/trailing ones, so padded reviews take less vertical space
+pub fn collapse_blank_lines(text: &str) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    let mut after_blank = false;
+
+    for line in text.trim().lines() {
+        if line.trim().is_empty() {
+            after_blank = true;
+            continue;
+        }
+        if after_blank {
+            lines.push("");
+            after_blank = false;
+        }
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tidy_whitespace_trims_trailing_spaces_and_tabs() {
+        assert_eq!(
+            tidy_whitespace("Thanks for the review!  \t\nWe'll fix it.\t \n"),
+            "Thanks for the review!\nWe'll fix it."
+        );
+    }
+
+    #[test]
+    fn tidy_whitespace_keeps_leading_indentation() {
+        assert_eq!(
+            tidy_whitespace("Steps:\n  1. Update\t\n"),
+            "Steps:\n  1. Update"
+        );
+    }
+
+    #[test]
+    fn tidy_whitespace_collapses_runs_of_blank_lines() {
+        assert_eq!(tidy_whitespace("Hi\n\n\n\n\nBye"), "Hi\n\nBye");
+        assert_eq!(tidy_whitespace("Hi\n \n\t\n  \nBye"), "Hi\n\nBye");
+    }
+
+    #[test]
+    fn tidy_whitespace_keeps_one_or_two_blank_lines() {
+        assert_eq!(tidy_whitespace("Hi\n\nBye"), "Hi\n\nBye");
+        assert_eq!(tidy_whitespace("Hi\n\n\nBye"), "Hi\n\n\nBye");
+    }
+
+    #[test]
+    fn tidy_whitespace_drops_leading_and_trailing_blank_lines() {
+        assert_eq!(tidy_whitespace("\n\n  \nHi\n\n\n"), "Hi");
+    }
+
+    #[test]
+    fn char_count_counts_an_emoji_as_one_character() {
+        assert_eq!(char_count("Thanks 👍"), 8);
+        assert_eq!(char_count(&"😀".repeat(350)), 350);
+    }
+
+    #[test]
+    fn char_count_counts_multi_codepoint_emoji_per_codepoint() {
+        // Skin tone modifier, regional indicator pair and a ZWJ family
+        assert_eq!(char_count("👍🏽"), 2);
+        assert_eq!(char_count("🇩🇪"), 2);
+        assert_eq!(char_count("👨\u{200d}👩\u{200d}👧"), 5);
+    }
+
+    #[test]
+    fn char_count_is_the_same_for_nfc_and_nfd_text() {
+        let nfc = "Caf\u{e9} cr\u{e8}me";
+        let nfd = "Cafe\u{301} cre\u{300}me";
+        assert_eq!(nfd.chars().count(), 12);
+        assert_eq!(char_count(nfc), 10);
+        assert_eq!(char_count(nfd), 10);
+    }
+
+    #[test]
+    fn truncate_to_limit_counts_decomposed_accents_once() {
+        let nfd = "e\u{301}".repeat(300);
+        assert_eq!(truncate_to_limit(&nfd, 350), nfd);
+        assert_eq!(truncate_to_limit(&nfd, 2), "e\u{301}e\u{301}");
+        assert_eq!(truncate_to_limit("ab\u{e9}", 2), "ab");
+    }
+
+    #[test]
+    fn truncate_chars_never_cuts_inside_a_character() {
+        // Byte 50 falls inside the emoji, which panicked with `&text[..50]`
+        let text = format!("{}😀 and more", "a".repeat(49));
+        assert!(!text.is_char_boundary(50));
+        assert_eq!(truncate_chars(&text, 50), format!("{}😀", "a".repeat(49)));
+        assert_eq!(truncate_chars(&text, 49), "a".repeat(49));
+    }
+
+    #[test]
+    fn truncate_chars_keeps_short_text() {
+        assert_eq!(truncate_chars("Grüße", 20), "Grüße");
+        assert_eq!(truncate_chars("Grüße", 3), "Grü");
+        assert_eq!(truncate_chars("", 5), "");
+    }
+
+    #[test]
+    fn normalize_line_breaks_keeps_paragraphs() {
+        let pasted =
+            "Thanks for writing in.\r\n\r\nWe fixed it.\rPlease update.\n\n\nBest,\u{2028}The team";
+        assert_eq!(
+            normalize_line_breaks(pasted),
+            "Thanks for writing in.\n\nWe fixed it.\nPlease update.\n\n\nBest,\nThe team"
+        );
+    }
+
+    #[test]
+    fn normalize_line_breaks_then_tidy_collapses_long_gaps() {
+        let pasted = "First paragraph.\r\n\r\n\r\n\r\n\r\nSecond paragraph.\r\rThird.";
+        assert_eq!(
+            tidy_whitespace(&normalize_line_breaks(pasted)),
+            "First paragraph.\n\nSecond paragraph.\n\nThird."
+        );
+    }
+
+    #[test]
+    fn strip_ai_wrapping_removes_surrounding_quotes() {
+        assert_eq!(
+            strip_ai_wrapping("\"Thanks for the review!\""),
+            "Thanks for the review!"
+        );
+        assert_eq!(strip_ai_wrapping("  “Thanks, Sam!”\n"), "Thanks, Sam!");
+        assert_eq!(strip_ai_wrapping("«Merci beaucoup !»"), "Merci beaucoup !");
+    }
+
+    #[test]
+    fn strip_ai_wrapping_removes_leading_labels() {
+        assert_eq!(strip_ai_wrapping("Response: Thanks!"), "Thanks!");
+        assert_eq!(strip_ai_wrapping("REPLY:\nThanks!"), "Thanks!");
+        assert_eq!(
+            strip_ai_wrapping("Suggested response: \"We're on it.\""),
+            "We're on it."
+        );
+    }
+
+    #[test]
+    fn strip_ai_wrapping_leaves_ordinary_replies_alone() {
+        let replies = [
+            "Thanks for the review!",
+            "\"Great app\" is exactly what we hoped to hear. Thanks, \"Sam\"",
+            "Our response: we fixed it in 2.1.",
+            "It's a 'must have' for us too",
+        ];
+        for reply in replies {
+            assert_eq!(strip_ai_wrapping(reply), reply);
+        }
+    }
+}
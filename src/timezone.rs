@@ -0,0 +1,101 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local, Utc};
+use chrono_tz::Tz;
+
+// Timezone used when rendering dates. Stored dates always stay in UTC.
+#[derive(Debug, Clone)]
+pub enum DisplayTimezone {
+    Utc,
+    Local,
+    Named(Tz),
+    // Infer from the review's territory, falling back to UTC
+    Territory,
+}
+
+impl DisplayTimezone {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "utc" => Ok(Self::Utc),
+            "local" => Ok(Self::Local),
+            "territory" => Ok(Self::Territory),
+            _ => value.parse::<Tz>().map(Self::Named).map_err(|_| {
+                anyhow!(
+                    "Unknown timezone '{}'. Use 'utc', 'local', 'territory' or an IANA name like 'Europe/Berlin'",
+                    value
+                )
+            }),
+        }
+    }
+
+    pub fn format(&self, date: &DateTime<Utc>, territory: &str, fmt: &str) -> String {
+        match self {
+            Self::Utc => date.format(fmt).to_string(),
+            Self::Local => date.with_timezone(&Local).format(fmt).to_string(),
+            Self::Named(tz) => date.with_timezone(tz).format(fmt).to_string(),
+            Self::Territory => match territory_timezone(territory) {
+                Some(tz) => date.with_timezone(&tz).format(fmt).to_string(),
+                None => date.format(fmt).to_string(),
+            },
+        }
+    }
+}
+
+// Best-effort mapping from App Store territory codes (ISO 3166-1 alpha-3) to a
+// representative timezone. Countries spanning several zones use the most
+// populous one, so times there are approximate. Google Play doesn't report a
+// country, so Android reviews fall back to UTC.
+fn territory_timezone(territory: &str) -> Option<Tz> {
+    let tz = match territory {
+        "USA" => Tz::America__New_York,
+        "CAN" => Tz::America__Toronto,
+        "MEX" => Tz::America__Mexico_City,
+        "BRA" => Tz::America__Sao_Paulo,
+        "ARG" => Tz::America__Argentina__Buenos_Aires,
+        "CHL" => Tz::America__Santiago,
+        "COL" => Tz::America__Bogota,
+        "PER" => Tz::America__Lima,
+        "GBR" => Tz::Europe__London,
+        "IRL" => Tz::Europe__Dublin,
+        "FRA" => Tz::Europe__Paris,
+        "DEU" => Tz::Europe__Berlin,
+        "ESP" => Tz::Europe__Madrid,
+        "ITA" => Tz::Europe__Rome,
+        "NLD" => Tz::Europe__Amsterdam,
+        "BEL" => Tz::Europe__Brussels,
+        "CHE" => Tz::Europe__Zurich,
+        "AUT" => Tz::Europe__Vienna,
+        "SWE" => Tz::Europe__Stockholm,
+        "NOR" => Tz::Europe__Oslo,
+        "DNK" => Tz::Europe__Copenhagen,
+        "FIN" => Tz::Europe__Helsinki,
+        "POL" => Tz::Europe__Warsaw,
+        "PRT" => Tz::Europe__Lisbon,
+        "GRC" => Tz::Europe__Athens,
+        "TUR" => Tz::Europe__Istanbul,
+        "RUS" => Tz::Europe__Moscow,
+        "UKR" => Tz::Europe__Kyiv,
+        "ISR" => Tz::Asia__Jerusalem,
+        "ARE" => Tz::Asia__Dubai,
+        "SAU" => Tz::Asia__Riyadh,
+        "EGY" => Tz::Africa__Cairo,
+        "ZAF" => Tz::Africa__Johannesburg,
+        "NGA" => Tz::Africa__Lagos,
+        "IND" => Tz::Asia__Kolkata,
+        "PAK" => Tz::Asia__Karachi,
+        "CHN" => Tz::Asia__Shanghai,
+        "HKG" => Tz::Asia__Hong_Kong,
+        "TWN" => Tz::Asia__Taipei,
+        "JPN" => Tz::Asia__Tokyo,
+        "KOR" => Tz::Asia__Seoul,
+        "SGP" => Tz::Asia__Singapore,
+        "MYS" => Tz::Asia__Kuala_Lumpur,
+        "THA" => Tz::Asia__Bangkok,
+        "VNM" => Tz::Asia__Ho_Chi_Minh,
+        "IDN" => Tz::Asia__Jakarta,
+        "PHL" => Tz::Asia__Manila,
+        "AUS" => Tz::Australia__Sydney,
+        "NZL" => Tz::Pacific__Auckland,
+        _ => return None,
+    };
+    Some(tz)
+}
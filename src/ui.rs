@@ -1,15 +1,27 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local, Utc};
 use crossterm::{
     event::{
-        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        Event, KeyCode, KeyEvent, KeyModifiers,
     },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use std::{
-    io,
+    borrow::Cow,
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    fs,
+    io::{self, IsTerminal},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     time::{Duration, Instant},
 };
+use tokio::task::JoinHandle;
 use tui::{
     backend::{Backend, CrosstermBackend},
     layout::{Constraint, Direction, Layout, Rect},
@@ -18,11 +30,19 @@ use tui::{
     widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
     Frame, Terminal,
 };
+use unicode_segmentation::{GraphemeCursor, UnicodeSegmentation};
+use uuid::Uuid;
 
 use crate::ai::{AIConfig, AIResponseGenerator};
-use crate::api::ApiClient;
-use crate::config::Config;
-use crate::review::Review;
+use crate::api::{self, ApiClient, ResponseExists};
+use crate::audit::AiUsage;
+use crate::cache;
+use crate::config::{Config, CtrlArrowMode, Platform};
+use crate::debug;
+use crate::mail;
+use crate::review::{self, ResponseState, Review, ReviewResponse};
+use crate::state::{self, FollowUp, LocalState, QueuedResponse, SKIP_REASONS};
+use crate::text;
 
 #[derive(Debug, PartialEq)]
 enum AppState {
@@ -30,6 +50,107 @@ enum AppState {
     WritingResponse,
     ConfirmingResponse,
     GeneratingAI,
+    CommandPalette,
+    ConfirmingReplacement,
+    TaggingSkip,
+    SubmitFailed,
+    // The store reported an existing response while submitting
+    ConfirmingOverwrite,
+    // The selected review's raw JSON, with --debug
+    ViewingRawJson,
+    ViewingStats,
+    // Responses sent during this session
+    ViewingActivity,
+    // Writing the selected review's local note
+    EditingNote,
+    // Picking one of the pre-approved responses for the selected review
+    ChoosingCannedResponse,
+    // Setting a reminder to check back on the selected review
+    EditingFollowUp,
+    ViewingFollowUps,
+    // The selected review as it was before the reviewer edited it
+    ViewingPreviousVersion,
+    // Asking before the selected review's developer response is deleted
+    ConfirmingDeletion,
+    // Typing a search query. The list is filtered as it's typed.
+    Searching,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReviewsLayout {
+    ListOnly,
+    Split,
+    DetailsOnly,
+}
+
+impl ReviewsLayout {
+    // Narrow terminals can't fit both panes legibly
+    fn for_width(width: u16) -> Self {
+        if width < NARROW_TERMINAL_WIDTH {
+            Self::ListOnly
+        } else {
+            Self::Split
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Self::Split => Self::ListOnly,
+            Self::ListOnly => Self::DetailsOnly,
+            Self::DetailsOnly => Self::Split,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum EditorCommand {
+    WordLeft,
+    WordRight,
+    LineStart,
+    LineEnd,
+    DeleteWordBackward,
+    DeleteWordForward,
+    ClearAll,
+    InsertReviewerName,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ToneAdjustment {
+    Shorter,
+    Warmer,
+    MoreApologetic,
+}
+
+impl ToneAdjustment {
+    fn from_key(key: &KeyEvent) -> Option<Self> {
+        if !key.modifiers.contains(KeyModifiers::ALT) {
+            return None;
+        }
+        match key.code {
+            KeyCode::Char('1') => Some(Self::Shorter),
+            KeyCode::Char('2') => Some(Self::Warmer),
+            KeyCode::Char('3') => Some(Self::MoreApologetic),
+            _ => None,
+        }
+    }
+
+    fn instruction(self) -> &'static str {
+        match self {
+            Self::Shorter => "Make this response noticeably shorter while keeping its key points.",
+            Self::Warmer => "Make this response warmer and more personal.",
+            Self::MoreApologetic => {
+                "Make this response more apologetic about the user's experience."
+            }
+        }
+    }
+
+    fn loading_message(self) -> &'static str {
+        match self {
+            Self::Shorter => "Making it shorter...",
+            Self::Warmer => "Making it warmer...",
+            Self::MoreApologetic => "Making it more apologetic...",
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -38,6 +159,310 @@ enum InputMode {
     AI,
 }
 
+// Below this width the reviews view defaults to the list alone, and from
+// WIDE_TERMINAL_WIDTH up the details pane gets most of the space
+const NARROW_TERMINAL_WIDTH: u16 = 100;
+const WIDE_TERMINAL_WIDTH: u16 = 180;
+
+// --fetch-all asks before loading more reviews than this
+const LARGE_FETCH_WARNING: usize = 2000;
+
+// Characters of each response shown in the session activity and follow-up lists
+const SNIPPET_CHARS: usize = 80;
+const SEARCH_MATCH_STYLE: Style = Style {
+    fg: Some(Color::Black),
+    bg: Some(Color::Yellow),
+    add_modifier: Modifier::empty(),
+    sub_modifier: Modifier::empty(),
+};
+
+// Attempts at asking the AI to fit an overlong reply within the store limit
+const MAX_SHORTEN_ATTEMPTS: usize = 2;
+
+// A response sent during this session. Unlike the audit log it's never written
+// to disk, and it keeps the review id so the list can jump back to it.
+struct SessionResponse {
+    review_id: String,
+    reviewer: String,
+    responded_at: DateTime<Utc>,
+    snippet: String,
+    replaced: bool,
+}
+
+struct KeyBinding {
+    label: &'static str,
+    code: KeyCode,
+    modifiers: KeyModifiers,
+    description: &'static str,
+    // Whether the action can be launched from the command palette
+    palette: bool,
+}
+
+// Review list keys that write or delete a response, unavailable with --read-only
+const RESPONDING_KEYS: &[KeyCode] = &[
+    KeyCode::Enter,
+    KeyCode::Char('a'),
+    KeyCode::Char('k'),
+    KeyCode::Char('D'),
+];
+
+// Keybindings for the review list. The help panel and the command palette are
+// both generated from this table, so new actions only need to be added here.
+const REVIEW_KEYBINDINGS: &[KeyBinding] = &[
+    KeyBinding {
+        label: "↑/↓",
+        code: KeyCode::Down,
+        modifiers: KeyModifiers::NONE,
+        description: "Navigate reviews",
+        palette: false,
+    },
+    KeyBinding {
+        label: "PgUp/PgDn",
+        code: KeyCode::PageDown,
+        modifiers: KeyModifiers::NONE,
+        description: "Move a page of reviews",
+        palette: false,
+    },
+    KeyBinding {
+        label: "n",
+        code: KeyCode::Char('n'),
+        modifiers: KeyModifiers::NONE,
+        description: "Jump to next unresponded review",
+        palette: true,
+    },
+    KeyBinding {
+        label: "Enter",
+        code: KeyCode::Enter,
+        modifiers: KeyModifiers::NONE,
+        description: "Write manual response",
+        palette: true,
+    },
+    KeyBinding {
+        label: "a",
+        code: KeyCode::Char('a'),
+        modifiers: KeyModifiers::NONE,
+        description: "Generate AI response",
+        palette: true,
+    },
+    KeyBinding {
+        label: "r",
+        code: KeyCode::Char('r'),
+        modifiers: KeyModifiers::NONE,
+        description: "Refresh reviews",
+        palette: true,
+    },
+    KeyBinding {
+        label: "H",
+        code: KeyCode::Char('H'),
+        modifiers: KeyModifiers::SHIFT,
+        description: "Hard refresh: re-fetch every page",
+        palette: true,
+    },
+    KeyBinding {
+        label: "u",
+        code: KeyCode::Char('u'),
+        modifiers: KeyModifiers::NONE,
+        description: "Refresh selected review's response",
+        palette: true,
+    },
+    KeyBinding {
+        label: "p",
+        code: KeyCode::Char('p'),
+        modifiers: KeyModifiers::NONE,
+        description: "Pause or resume auto-refresh",
+        palette: true,
+    },
+    KeyBinding {
+        label: "Ctrl+R",
+        code: KeyCode::Char('r'),
+        modifiers: KeyModifiers::CONTROL,
+        description: "Reload AI settings",
+        palette: true,
+    },
+    KeyBinding {
+        label: "l",
+        code: KeyCode::Char('l'),
+        modifiers: KeyModifiers::NONE,
+        description: "Load more reviews",
+        palette: true,
+    },
+    KeyBinding {
+        label: "s",
+        code: KeyCode::Char('s'),
+        modifiers: KeyModifiers::NONE,
+        description: "Skip with a reason",
+        palette: true,
+    },
+    KeyBinding {
+        label: "f",
+        code: KeyCode::Char('f'),
+        modifiers: KeyModifiers::NONE,
+        description: "Filter by skip reason",
+        palette: true,
+    },
+    KeyBinding {
+        label: "c",
+        code: KeyCode::Char('c'),
+        modifiers: KeyModifiers::NONE,
+        description: "Only reviews whose rating changed",
+        palette: true,
+    },
+    KeyBinding {
+        label: "m",
+        code: KeyCode::Char('m'),
+        modifiers: KeyModifiers::NONE,
+        description: "Responses awaiting moderation (iOS)",
+        palette: true,
+    },
+    KeyBinding {
+        label: "g",
+        code: KeyCode::Char('g'),
+        modifiers: KeyModifiers::NONE,
+        description: "Group by reviewer nickname (heuristic)",
+        palette: true,
+    },
+    KeyBinding {
+        label: "e",
+        code: KeyCode::Char('e'),
+        modifiers: KeyModifiers::NONE,
+        description: "Expand or collapse reviewer group",
+        palette: true,
+    },
+    KeyBinding {
+        label: "[",
+        code: KeyCode::Char('['),
+        modifiers: KeyModifiers::NONE,
+        description: "Previous review by the same reviewer",
+        palette: true,
+    },
+    KeyBinding {
+        label: "]",
+        code: KeyCode::Char(']'),
+        modifiers: KeyModifiers::NONE,
+        description: "Next review by the same reviewer",
+        palette: true,
+    },
+    KeyBinding {
+        label: "Space",
+        code: KeyCode::Char(' '),
+        modifiers: KeyModifiers::NONE,
+        description: "Expand or collapse long review text",
+        palette: true,
+    },
+    KeyBinding {
+        label: "v",
+        code: KeyCode::Char('v'),
+        modifiers: KeyModifiers::NONE,
+        description: "Cycle layout (split / list / details)",
+        palette: true,
+    },
+    KeyBinding {
+        label: "t",
+        code: KeyCode::Char('t'),
+        modifiers: KeyModifiers::NONE,
+        description: "Toggle AI reply language (translated reviews)",
+        palette: true,
+    },
+    KeyBinding {
+        label: "j",
+        code: KeyCode::Char('j'),
+        modifiers: KeyModifiers::NONE,
+        description: "Show the raw store JSON (--debug)",
+        palette: true,
+    },
+    KeyBinding {
+        label: "i",
+        code: KeyCode::Char('i'),
+        modifiers: KeyModifiers::NONE,
+        description: "Show the response rate and its trend",
+        palette: true,
+    },
+    KeyBinding {
+        label: "R",
+        code: KeyCode::Char('R'),
+        modifiers: KeyModifiers::SHIFT,
+        description: "Show the reviews responded to this session",
+        palette: true,
+    },
+    KeyBinding {
+        label: "N",
+        code: KeyCode::Char('N'),
+        modifiers: KeyModifiers::SHIFT,
+        description: "Add or edit a private note on the review",
+        palette: true,
+    },
+    KeyBinding {
+        label: "k",
+        code: KeyCode::Char('k'),
+        modifiers: KeyModifiers::NONE,
+        description: "Reply with a pre-approved response for the rating",
+        palette: true,
+    },
+    KeyBinding {
+        label: "F",
+        code: KeyCode::Char('F'),
+        modifiers: KeyModifiers::SHIFT,
+        description: "Set a follow-up reminder on the review",
+        palette: true,
+    },
+    KeyBinding {
+        label: "U",
+        code: KeyCode::Char('U'),
+        modifiers: KeyModifiers::SHIFT,
+        description: "Show follow-up reminders",
+        palette: true,
+    },
+    KeyBinding {
+        label: "E",
+        code: KeyCode::Char('E'),
+        modifiers: KeyModifiers::SHIFT,
+        description: "Show the review before the reviewer edited it",
+        palette: true,
+    },
+    KeyBinding {
+        label: "/",
+        code: KeyCode::Char('/'),
+        modifiers: KeyModifiers::NONE,
+        description: "Search reviewer names, titles and review text",
+        palette: true,
+    },
+    KeyBinding {
+        label: "D",
+        code: KeyCode::Char('D'),
+        modifiers: KeyModifiers::SHIFT,
+        description: "Delete the selected review's developer response",
+        palette: true,
+    },
+    KeyBinding {
+        label: "h",
+        code: KeyCode::Char('h'),
+        modifiers: KeyModifiers::NONE,
+        description: "Show or hide this help panel",
+        palette: true,
+    },
+    KeyBinding {
+        label: "d",
+        code: KeyCode::Char('d'),
+        modifiers: KeyModifiers::NONE,
+        description: "Save a debug bundle for bug reports",
+        palette: true,
+    },
+    KeyBinding {
+        label: "Ctrl+P",
+        code: KeyCode::Char('p'),
+        modifiers: KeyModifiers::CONTROL,
+        description: "Command palette",
+        palette: false,
+    },
+    KeyBinding {
+        label: "q",
+        code: KeyCode::Char('q'),
+        modifiers: KeyModifiers::NONE,
+        description: "Quit",
+        palette: true,
+    },
+];
+
 pub struct ReviewUI {
     api_client: ApiClient,
     ai_generator: Option<AIResponseGenerator>,
@@ -47,471 +472,2594 @@ pub struct ReviewUI {
     response_text: String,
     cursor_position: usize,
     input_mode: InputMode,
+    // Previous versions of the response, restored with Ctrl+Z
+    undo_stack: Vec<String>,
     ai_generated_response: Option<String>,
     loading: bool,
+    // When the list still shows reviews cached by an earlier run
+    cached_since: Option<DateTime<Utc>>,
+    // The newest page, fetched on a copy of the client while the cached
+    // reviews are on screen
+    background_refresh: Option<JoinHandle<(ApiClient, Result<Vec<Review>>)>>,
+    // Each loaded review's response when the background refresh started, to
+    // tell which ones were submitted, replaced or deleted since
+    responses_before_refresh: HashMap<String, Option<ReviewResponse>>,
     error_message: Option<String>,
+    // Only tracks the selected row. The list is windowed: `list_offset` is the
+    // first visible row on screen and `list_rows` how many fit there, so only
+    // those rows are built each frame however many reviews are loaded.
     list_state: ListState,
+    list_offset: usize,
+    list_rows: usize,
     config: Config,
+    palette_query: String,
+    palette_selected: usize,
+    reply_in_original_language: bool,
+    loading_message: &'static str,
+    // Count and elapsed time while 'r' fetches every Google Play page
+    fetch_progress: Option<String>,
+    // None follows the terminal width until the user picks a layout
+    reviews_layout: Option<ReviewsLayout>,
+    reviews_width: u16,
+    local_state: LocalState,
+    // Only reviews skipped with this reason are listed when set
+    skip_filter: Option<&'static str>,
+    // Only reviews whose response Apple hasn't published yet are listed when set
+    moderation_view: bool,
+    // Only reviews whose rating changed since they were cached are listed when set
+    rating_changes_only: bool,
+    // Reviews sharing a nickname are listed under the newest one when set.
+    // Nicknames aren't unique, so different people can end up in one group.
+    group_by_reviewer: bool,
+    // Nicknames whose older reviews are listed under the newest one
+    expanded_reviewers: HashSet<String>,
+    // When the list was last reloaded, for `--auto-refresh-secs`
+    last_refresh: Instant,
+    auto_refresh_paused: bool,
+    // Kept even when AI is disabled, for the support email and fallback replies
+    ai_config: AIConfig,
+    submit_error: Option<String>,
+    // Request shown instead of submitting with --print-payload, until a key is pressed
+    payload_preview: Option<String>,
+    // Shows the selected review's full text instead of the preview
+    text_expanded: bool,
+    // Only reviews whose nickname, title or text contain this, ignoring case
+    search_query: String,
+    // The current generator's model fallback notice was already shown
+    model_fallback_shown: bool,
+    // Lines scrolled in the raw JSON popup
+    raw_json_scroll: u16,
+    // Kept after the popup is dismissed, for the debug bundle
+    last_error: Option<String>,
+    // Oldest first, shown newest first
+    session_responses: Vec<SessionResponse>,
+    activity_selected: usize,
+    help_visible: bool,
+    // The note being written in the note popup
+    note_input: String,
+    // The due date and note being written in the follow-up popup
+    follow_up_input: String,
+    follow_ups_selected: usize,
+    // Pre-approved responses offered for the selected review
+    canned_responses: Vec<String>,
+    canned_selected: usize,
+    // The draft is a pre-approved response, so it's sent as is or dropped
+    // rather than edited
+    canned_response: bool,
+    // Queued responses whose review was answered on the store in the meantime.
+    // Like a live submission, each waits for confirmation before replacing it,
+    // and the first is the one being confirmed.
+    queued_overwrites: Vec<QueuedResponse>,
 }
 
 impl ReviewUI {
+    // With --both the limit depends on which store the selected review is from
     fn get_character_limit(&self) -> Option<usize> {
-        match self.config.platform {
-            crate::config::Platform::Android => Some(350),
-            crate::config::Platform::Ios => None, // No limit for iOS
+        self.selected_review
+            .and_then(|idx| self.reviews[idx].platform)
+            .unwrap_or(self.config.platform)
+            .character_limit()
+    }
+
+    // Dates are stored in UTC and only converted for display
+    fn format_date(&self, date: &DateTime<Utc>, review: &Review, fmt: &str) -> String {
+        self.config
+            .display_timezone
+            .format(date, &review.territory, fmt)
+    }
+
+    fn list_date(&self, date: &DateTime<Utc>, review: &Review) -> String {
+        self.format_date(date, review, &self.config.date_format)
+    }
+
+    fn detail_date(&self, date: &DateTime<Utc>, review: &Review) -> String {
+        let fmt = format!("{} %H:%M %Z", self.config.date_format);
+        self.format_date(date, review, &fmt)
+    }
+
+    // Characters taken up by the signature and disclosure appended on submit
+    fn reserved_characters(&self) -> usize {
+        text::appended_lines(&self.config, self.ai_assisted())
+            .iter()
+            .map(|line| text::char_count(text::SIGNATURE_SEPARATOR) + text::char_count(line))
+            .sum()
+    }
+
+    // Whether the response being written started from an AI draft. Stays set
+    // through manual edits until the response is submitted or discarded.
+    fn ai_assisted(&self) -> bool {
+        self.ai_generated_response.is_some()
+    }
+
+    fn ai_usage(&self) -> AiUsage {
+        AiUsage {
+            ai_assisted: self.ai_assisted(),
+            edited_after_ai: self
+                .ai_generated_response
+                .as_ref()
+                .is_some_and(|draft| draft.trim() != self.response_text.trim()),
         }
     }
-    
-    fn format_text_with_cursor(&self) -> String {
-        if self.cursor_position <= self.response_text.len() {
-            let mut display_text = self.response_text.clone();
-            // Always show static white square cursor
-            display_text.insert(self.cursor_position, '█'); // White square cursor
-            display_text
+
+    // Limit for the text the user can type, after reserving room for appended lines
+    fn editable_character_limit(&self) -> Option<usize> {
+        self.get_character_limit()
+            .map(|limit| limit.saturating_sub(self.reserved_characters()))
+    }
+
+    fn character_budget_label(&self) -> Option<String> {
+        let limit = self.editable_character_limit()?;
+        let reserved = self.reserved_characters();
+        let count = text::char_count(&self.response_text);
+        let reserved_for = match text::appended_lines(&self.config, self.ai_assisted()).len() {
+            2 => "signature and disclosure",
+            _ if self.config.signature.is_some() => "signature",
+            _ => "disclosure",
+        };
+        let mut label = if reserved > 0 {
+            format!(
+                "{}/{} chars, {} reserved for {}",
+                count, limit, reserved, reserved_for
+            )
         } else {
-            self.response_text.clone()
+            format!("{}/{} chars", count, limit)
+        };
+        if count > limit {
+            label.push_str(" - over the limit, trim before submitting");
         }
+        Some(label)
     }
 
-    fn find_next_word_boundary(&self) -> usize {
-        let chars: Vec<char> = self.response_text.chars().collect();
-        let mut pos = self.cursor_position;
-        
-        // Skip current word (non-whitespace)
-        while pos < chars.len() && !chars[pos].is_whitespace() {
-            pos += 1;
-        }
-        
-        // Skip whitespace to next word
-        while pos < chars.len() && chars[pos].is_whitespace() {
-            pos += 1;
+    // Set when the typed response is below `--min-response-chars`. The signature
+    // doesn't count toward the minimum.
+    fn too_short_message(&self) -> Option<String> {
+        let min = self.config.min_response_chars?;
+        let count = text::char_count(self.response_text.trim());
+        if count < min {
+            Some(format!(
+                "Response is {} characters, but at least {} are required. Press 'n' to keep editing.",
+                count, min
+            ))
+        } else {
+            None
         }
-        
-        pos
     }
 
-    fn find_prev_word_boundary(&self) -> usize {
-        let chars: Vec<char> = self.response_text.chars().collect();
-        if self.cursor_position == 0 {
-            return 0;
-        }
-        
-        let mut pos = self.cursor_position - 1;
-        
-        // Skip whitespace backwards
-        while pos > 0 && chars[pos].is_whitespace() {
-            pos -= 1;
+    // Review text as shown on screen. The stored text is never changed.
+    fn display_text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.config.collapse_blank_lines {
+            Cow::Owned(text::collapse_blank_lines(text))
+        } else {
+            Cow::Borrowed(text)
         }
-        
-        // Skip current word backwards
-        while pos > 0 && !chars[pos - 1].is_whitespace() {
-            pos -= 1;
+    }
+
+    // Review text for the details pane, cut off at `--preview-chars` unless
+    // expanded so the metadata above it stays on screen. Also returns the full
+    // length when the text was cut.
+    fn preview_text<'a>(&self, text: &'a str) -> (Cow<'a, str>, Option<usize>) {
+        let text = self.display_text(text);
+        let length = text::char_count(&text);
+        match self.config.preview_chars {
+            Some(limit) if !self.text_expanded && length > limit => {
+                let cut = text::truncate_chars(&text, limit);
+                (Cow::Owned(format!("{}…", cut.trim_end())), Some(length))
+            }
+            _ => (text, None),
         }
-        
-        pos
     }
 
-    pub async fn new(config: Config) -> Result<Self> {
-        let mut api_client = ApiClient::new(config.clone());
-        let mut reviews = api_client.get_reviews().await?;
+    // The exact text that will be sent to the store
+    fn submission_text(&self) -> String {
+        text::prepare_response(&self.response_text, &self.config, self.ai_assisted())
+    }
 
-        // Initialize AI generator if OpenAI API key is available
-        let ai_generator = if let Some(api_key) = &config.openai_api_key {
-            let ai_config = AIConfig {
-                openai_api_key: api_key.clone(),
-                ..Default::default()
-            };
-            AIResponseGenerator::new(ai_config).ok()
+    // `cursor_position` is a byte offset into `response_text`. Movement keeps it
+    // on a grapheme cluster boundary, so the cursor never lands inside an emoji
+    // ZWJ sequence or between a letter and its combining accent. Typing can still
+    // leave it inside one, e.g. before a combining mark, so it's drawn before
+    // that cluster.
+    fn format_text_with_cursor(&self) -> String {
+        let mut display_text = self.response_text.clone();
+        let position = if self.is_grapheme_boundary(self.cursor_position) {
+            self.cursor_position
         } else {
-            None
+            self.prev_grapheme_boundary()
         };
+        display_text.insert(position, '█'); // White square cursor
+        display_text
+    }
+
+    fn is_grapheme_boundary(&self, position: usize) -> bool {
+        GraphemeCursor::new(position, self.response_text.len(), true)
+            .is_boundary(&self.response_text, 0)
+            .unwrap_or(false)
+    }
 
-        // Sort reviews by date (newest first)
-        reviews.sort_by(|a, b| b.created_date.cmp(&a.created_date));
+    // The whole text is passed as one chunk, so the cursor never asks for more
+    fn prev_grapheme_boundary(&self) -> usize {
+        GraphemeCursor::new(self.cursor_position, self.response_text.len(), true)
+            .prev_boundary(&self.response_text, 0)
+            .ok()
+            .flatten()
+            .unwrap_or(0)
+    }
 
-        let mut list_state = ListState::default();
-        if !reviews.is_empty() {
-            list_state.select(Some(0));
+    fn next_grapheme_boundary(&self) -> usize {
+        GraphemeCursor::new(self.cursor_position, self.response_text.len(), true)
+            .next_boundary(&self.response_text, 0)
+            .ok()
+            .flatten()
+            .unwrap_or(self.response_text.len())
+    }
+
+    fn find_next_word_boundary(&self) -> usize {
+        // Skip current word (non-whitespace), then whitespace to the next word
+        let mut graphemes = self.response_text[self.cursor_position..]
+            .grapheme_indices(true)
+            .skip_while(|(_, g)| !is_whitespace_grapheme(g))
+            .skip_while(|(_, g)| is_whitespace_grapheme(g));
+        graphemes
+            .next()
+            .map(|(idx, _)| self.cursor_position + idx)
+            .unwrap_or(self.response_text.len())
+    }
+
+    fn find_prev_word_boundary(&self) -> usize {
+        // Skip whitespace backwards, then the current word
+        let mut graphemes = self.response_text[..self.cursor_position]
+            .grapheme_indices(true)
+            .rev()
+            .skip_while(|(_, g)| is_whitespace_grapheme(g))
+            .skip_while(|(_, g)| !is_whitespace_grapheme(g));
+        graphemes.next().map(|(idx, g)| idx + g.len()).unwrap_or(0)
+    }
+
+    fn insert_char(&mut self, c: char) {
+        // Check character limit before inserting
+        if let Some(limit) = self.editable_character_limit() {
+            if text::char_count(&self.response_text) >= limit {
+                return;
+            }
         }
+        self.response_text.insert(self.cursor_position, c);
+        self.cursor_position += c.len_utf8();
+    }
 
-        let selected_review = if reviews.is_empty() { None } else { Some(0) };
+    // Characters that can still be typed before the character limit
+    fn remaining_characters(&self) -> usize {
+        self.editable_character_limit()
+            .map(|limit| limit.saturating_sub(text::char_count(&self.response_text)))
+            .unwrap_or(usize::MAX)
+    }
 
-        Ok(Self {
-            api_client,
-            ai_generator,
-            reviews,
-            selected_review,
-            state: AppState::ViewingReviews,
-            response_text: String::new(),
-            cursor_position: 0,
-            input_mode: InputMode::Manual,
-            ai_generated_response: None,
-            loading: false,
-            error_message: None,
-            list_state,
-            config,
-        })
+    // Inserts at the cursor as a single edit that one Ctrl+Z removes again
+    fn insert_text(&mut self, insert: &str) {
+        if insert.is_empty() {
+            return;
+        }
+        self.undo_stack.push(self.response_text.clone());
+        self.response_text.insert_str(self.cursor_position, insert);
+        self.cursor_position += insert.len();
     }
 
-    pub async fn run(&mut self) -> Result<()> {
-        enable_raw_mode()?;
-        let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-        let backend = CrosstermBackend::new(stdout);
-        let mut terminal = Terminal::new(backend)?;
+    // Bracketed paste delivers the whole clipboard as one event. It's cut to
+    // whatever the character limit leaves room for.
+    fn paste(&mut self, pasted: &str) {
+        let pasted = text::normalize_line_breaks(pasted);
+        let pasted: String = pasted
+            .chars()
+            .filter(|c| *c == '\n' || !c.is_control())
+            .collect();
+        let remaining = self.remaining_characters();
+        self.insert_text(text::truncate_to_limit(&pasted, remaining));
+    }
 
-        let result = self.run_app(&mut terminal).await;
+    // Suspends the TUI while $VISUAL or $EDITOR (falling back to vi) edits the
+    // draft in a temporary file. The terminal is restored even if the editor
+    // fails to start.
+    fn edit_externally<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<()> {
+        let path = std::env::temp_dir().join(format!("rustpond-response-{}.txt", Uuid::new_v4()));
+        if let Err(e) = fs::write(&path, &self.response_text) {
+            self.error_message = Some(format!("Failed to write {}: {}", path.display(), e));
+            return Ok(());
+        }
 
         disable_raw_mode()?;
         execute!(
-            terminal.backend_mut(),
+            io::stdout(),
             LeaveAlternateScreen,
-            DisableMouseCapture
+            DisableMouseCapture,
+            DisableBracketedPaste
         )?;
-        terminal.show_cursor()?;
+        let result = run_external_editor(&path);
+        enable_raw_mode()?;
+        execute!(
+            io::stdout(),
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableBracketedPaste
+        )?;
+        terminal.clear()?;
 
-        result
+        let edited = result.and_then(|()| {
+            fs::read_to_string(&path)
+                .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))
+        });
+        fs::remove_file(&path).ok();
+        match edited {
+            Ok(edited) => self.replace_with_edited(&edited),
+            Err(e) => self.error_message = Some(e.to_string()),
+        }
+        Ok(())
     }
 
-    async fn run_app<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<()> {
-        let mut last_tick = Instant::now();
-        let tick_rate = Duration::from_millis(250);
+    // Editors usually end the file with a newline, which isn't part of the reply
+    fn replace_with_edited(&mut self, edited: &str) {
+        let edited = text::normalize_line_breaks(edited);
+        let edited: String = edited
+            .trim_end_matches('\n')
+            .chars()
+            .filter(|c| *c == '\n' || !c.is_control())
+            .collect();
+        if edited == self.response_text {
+            return;
+        }
 
-        loop {
-            terminal.draw(|f| self.ui(f))?;
+        let limit = self.editable_character_limit().unwrap_or(usize::MAX);
+        let length = text::char_count(&edited);
+        self.undo_stack
+            .push(std::mem::take(&mut self.response_text));
+        self.response_text = text::truncate_to_limit(&edited, limit).to_string();
+        self.cursor_position = self.response_text.len();
+        self.error_message = None;
+        if length > limit {
+            self.error_message = Some(format!(
+                "The edited response had {} characters and was cut off at {}. Ctrl+Z restores the previous draft",
+                length, limit
+            ));
+        }
+    }
 
-            let timeout = tick_rate
-                .checked_sub(last_tick.elapsed())
-                .unwrap_or_else(|| Duration::from_secs(0));
+    // A cut-off name reads worse than none, so it's only inserted whole
+    fn insert_reviewer_name(&mut self) {
+        let Some(review_idx) = self.selected_review else {
+            return;
+        };
+        let name = self.reviews[review_idx]
+            .reviewer_nickname
+            .trim()
+            .to_string();
+        if text::char_count(&name) > self.remaining_characters() {
+            self.error_message =
+                Some("The reviewer's name doesn't fit within the character limit".to_string());
+            return;
+        }
+        self.insert_text(&name);
+    }
 
-            if crossterm::event::poll(timeout)? {
-                if let Event::Key(key) = event::read()? {
-                    match self.handle_input(key).await? {
-                        Some(action) => match action {
-                            UIAction::Quit => break,
-                            UIAction::Refresh => {
-                                self.loading = true;
-                                match self.api_client.refresh_all_reviews().await {
-                                    Ok(mut reviews) => {
-                                        // Sort reviews by date (newest first)
-                                        reviews.sort_by(|a, b| b.created_date.cmp(&a.created_date));
-
-                                        self.reviews = reviews;
-                                        self.selected_review = if self.reviews.is_empty() {
-                                            None
-                                        } else {
-                                            Some(0)
-                                        };
-                                        if !self.reviews.is_empty() {
-                                            self.list_state.select(Some(0));
-                                        }
-                                        self.error_message = None;
-                                    }
-                                    Err(e) => {
-                                        self.error_message =
-                                            Some(format!("Failed to refresh reviews: {}", e));
-                                    }
-                                }
-                                self.loading = false;
-                            }
-                            UIAction::LoadMore => {
-                                self.loading = true;
-                                match self.api_client.load_more_reviews().await {
-                                    Ok(mut new_reviews) => {
-                                        new_reviews
-                                            .sort_by(|a, b| b.created_date.cmp(&a.created_date));
-                                        self.reviews.extend(new_reviews);
-                                        self.error_message = None;
-                                    }
-                                    Err(e) => {
-                                        self.error_message =
-                                            Some(format!("Failed to load more reviews: {}", e));
-                                    }
-                                }
-                                self.loading = false;
-                            }
-                        },
-                        None => {}
-                    }
+    fn current_line_start(&self) -> usize {
+        self.response_text[..self.cursor_position]
+            .rfind('\n')
+            .map(|idx| idx + 1)
+            .unwrap_or(0)
+    }
+
+    fn current_line_end(&self) -> usize {
+        self.response_text[self.cursor_position..]
+            .find('\n')
+            .map(|idx| self.cursor_position + idx)
+            .unwrap_or(self.response_text.len())
+    }
+
+    // Maps the many ways terminals report word/line movement onto editor commands.
+    // Option+Arrow often arrives as Alt+b/Alt+f, and Option+Backspace as Alt+w,
+    // Ctrl+W, Alt+DEL or Alt+Backspace depending on the terminal. Cmd is usually
+    // indistinguishable from Ctrl, so Ctrl+Left/Right follow `--ctrl-arrows`.
+    fn editor_command(&self, key: &KeyEvent) -> Option<EditorCommand> {
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let ctrl_line_mode = self.config.ctrl_arrows == CtrlArrowMode::Line;
+
+        match key.code {
+            KeyCode::Left if alt => Some(EditorCommand::WordLeft),
+            KeyCode::Right if alt => Some(EditorCommand::WordRight),
+            KeyCode::Left if ctrl && ctrl_line_mode => Some(EditorCommand::LineStart),
+            KeyCode::Right if ctrl && ctrl_line_mode => Some(EditorCommand::LineEnd),
+            KeyCode::Left if ctrl => Some(EditorCommand::WordLeft),
+            KeyCode::Right if ctrl => Some(EditorCommand::WordRight),
+            KeyCode::Home => Some(EditorCommand::LineStart),
+            KeyCode::End => Some(EditorCommand::LineEnd),
+            KeyCode::Char('b') if alt => Some(EditorCommand::WordLeft),
+            KeyCode::Char('f') if alt => Some(EditorCommand::WordRight),
+            KeyCode::Char('d') if alt => Some(EditorCommand::DeleteWordForward),
+            KeyCode::Char('w') if alt || ctrl => Some(EditorCommand::DeleteWordBackward),
+            KeyCode::Char('\u{0017}') => Some(EditorCommand::DeleteWordBackward),
+            KeyCode::Char('\u{007f}') if alt => Some(EditorCommand::DeleteWordBackward),
+            KeyCode::Backspace if alt => Some(EditorCommand::DeleteWordBackward),
+            KeyCode::Char('u') if ctrl => Some(EditorCommand::ClearAll),
+            KeyCode::Char('\u{0015}') => Some(EditorCommand::ClearAll),
+            KeyCode::Char('n') if ctrl => Some(EditorCommand::InsertReviewerName),
+            KeyCode::Char('\u{000e}') => Some(EditorCommand::InsertReviewerName),
+            _ => None,
+        }
+    }
+
+    fn apply_editor_command(&mut self, command: EditorCommand) {
+        match command {
+            EditorCommand::WordLeft => self.cursor_position = self.find_prev_word_boundary(),
+            EditorCommand::WordRight => self.cursor_position = self.find_next_word_boundary(),
+            EditorCommand::LineStart => self.cursor_position = self.current_line_start(),
+            EditorCommand::LineEnd => self.cursor_position = self.current_line_end(),
+            EditorCommand::DeleteWordBackward => {
+                let word_start = self.find_prev_word_boundary();
+                if word_start < self.cursor_position {
+                    self.response_text.drain(word_start..self.cursor_position);
+                    self.cursor_position = word_start;
                 }
             }
+            EditorCommand::DeleteWordForward => {
+                let word_end = self.find_next_word_boundary();
+                if self.cursor_position < word_end {
+                    self.response_text.drain(self.cursor_position..word_end);
+                }
+            }
+            EditorCommand::ClearAll => {
+                // Keep the old text on the undo stack so Ctrl+Z brings it back
+                if !self.response_text.is_empty() {
+                    self.undo_stack
+                        .push(std::mem::take(&mut self.response_text));
+                    self.cursor_position = 0;
+                }
+            }
+            EditorCommand::InsertReviewerName => self.insert_reviewer_name(),
+        }
+    }
 
-            if last_tick.elapsed() >= tick_rate {
-                last_tick = Instant::now();
+    fn editor_help_text(&self) -> &'static str {
+        match self.config.ctrl_arrows {
+            CtrlArrowMode::Word => "Alt/Ctrl+←/→ - Word left/right   Home/End - Line start/end   Alt+Backspace or Ctrl+W - Delete word   Alt+D - Delete next word   Ctrl+U - Clear all   Ctrl+N - Insert reviewer's name   Ctrl+E - Open in $EDITOR",
+            CtrlArrowMode::Line => "Alt+←/→ - Word left/right   Ctrl+←/→ or Home/End - Line start/end   Alt+Backspace or Ctrl+W - Delete word   Alt+D - Delete next word   Ctrl+U - Clear all   Ctrl+N - Insert reviewer's name   Ctrl+E - Open in $EDITOR",
+        }
+    }
+
+    // A missing API key isn't an error; the reason AI is unavailable is returned instead
+    fn build_ai_generator(
+        ai_config: &AIConfig,
+        offline: bool,
+    ) -> (Option<AIResponseGenerator>, Option<String>) {
+        if offline {
+            return (None, Some("working offline (--offline)".to_string()));
+        }
+        if ai_config.openai_api_key.is_empty() {
+            return (None, Some("OPENAI_API_KEY is not set".to_string()));
+        }
+
+        match AIResponseGenerator::new(ai_config.clone()) {
+            Ok(generator) => (Some(generator), None),
+            Err(e) => (None, Some(e.to_string())),
+        }
+    }
+
+    fn reload_ai_config(&mut self) {
+        match AIConfig::for_config(&self.config) {
+            Ok(ai_config) => {
+                let (generator, disabled_reason) =
+                    Self::build_ai_generator(&ai_config, self.config.offline);
+                self.ai_generator = generator;
+                self.model_fallback_shown = false;
+                self.ai_config = ai_config;
+                let source = match &self.config.ai_config_path {
+                    Some(path) => format!("from {}", path.display()),
+                    None => "(no --ai-config file, using built-in defaults)".to_string(),
+                };
+                self.error_message = Some(match disabled_reason {
+                    Some(reason) => format!(
+                        "Reloaded AI settings {}, but AI is disabled: {}",
+                        source, reason
+                    ),
+                    None => std::iter::once(format!("Reloaded AI settings {}", source))
+                        .chain(self.ai_config.limit_conflicts())
+                        .collect::<Vec<_>>()
+                        .join("\n\n"),
+                });
+            }
+            Err(e) => {
+                // Keep the previous generator so a typo doesn't disable AI mid-session
+                self.error_message = Some(format!("Failed to reload AI settings: {}", e));
+            }
+        }
+    }
+
+    // Runs before the TUI starts, so progress goes straight to the terminal
+    async fn fetch_remaining_reviews(
+        api_client: &mut ApiClient,
+        reviews: &mut Vec<Review>,
+    ) -> Result<()> {
+        let total = api_client.total_reviews();
+
+        if let Some(total) = total.filter(|&total| total > LARGE_FETCH_WARNING) {
+            eprintln!(
+                "Warning: this app has {} reviews. Fetching them all takes about {} requests.",
+                total,
+                total.div_ceil(200)
+            );
+            if io::stdin().is_terminal() {
+                eprint!("Fetch every review anyway? [y/N] ");
+                let mut answer = String::new();
+                io::stdin().read_line(&mut answer)?;
+                if !answer.trim().eq_ignore_ascii_case("y") {
+                    return Ok(());
+                }
             }
         }
 
+        // Raw mode lets Esc through between pages without waiting for Enter
+        let interactive = io::stdin().is_terminal();
+        if interactive {
+            enable_raw_mode()?;
+        }
+        let fetched = Self::fetch_pages(api_client, reviews, total, interactive).await;
+        if interactive {
+            disable_raw_mode()?;
+        }
+        let cancelled = fetched?;
+        eprint!("\r\x1b[2K");
+        if cancelled {
+            eprintln!(
+                "Stopped fetching after {} reviews. Press 'l' to load more.",
+                reviews.len()
+            );
+        } else {
+            eprintln!("Fetched {} reviews.", reviews.len());
+        }
+
         Ok(())
     }
 
-    async fn handle_input(&mut self, key: KeyEvent) -> Result<Option<UIAction>> {
-        match self.state {
-            AppState::ViewingReviews => {
-                match key.code {
-                    KeyCode::Char('q') => return Ok(Some(UIAction::Quit)),
-                    KeyCode::Char('r') => return Ok(Some(UIAction::Refresh)),
-                    KeyCode::Char('l') => {
-                        if self.api_client.has_more_reviews() {
-                            return Ok(Some(UIAction::LoadMore));
-                        }
-                    }
-                    KeyCode::Up => {
-                        if let Some(selected) = self.selected_review {
-                            if selected > 0 {
-                                self.selected_review = Some(selected - 1);
-                                self.list_state.select(Some(selected - 1));
-                            }
-                        }
-                    }
-                    KeyCode::Down => {
-                        if let Some(selected) = self.selected_review {
-                            if selected + 1 < self.reviews.len() {
-                                self.selected_review = Some(selected + 1);
-                                self.list_state.select(Some(selected + 1));
-                            }
-                        }
-                    }
-                    KeyCode::Enter => {
-                        if let Some(review_idx) = self.selected_review {
-                            // Fetch response data for this review
-                            self.loading = true;
-                            let review_id = &self.reviews[review_idx].id;
-                            match self.api_client.get_review_response(review_id).await {
-                                Ok(response) => {
-                                    use std::io::Write;
-                                    let mut log_file = std::fs::OpenOptions::new()
-                                        .create(true)
-                                        .append(true)
-                                        .open("debug.log")
-                                        .unwrap_or_else(|_| {
-                                            std::fs::File::create("debug.log").unwrap()
-                                        });
-                                    writeln!(
-                                        log_file,
-                                        "DEBUG: UI received response: {:?}",
-                                        response.is_some()
-                                    )
-                                    .ok();
-                                    if let Some(ref resp) = response {
-                                        writeln!(
-                                            log_file,
-                                            "DEBUG: Response body preview: {}",
-                                            &resp.response_body[..resp.response_body.len().min(50)]
-                                        )
-                                        .ok();
-                                    }
-
-                                    self.reviews[review_idx].response = response;
-                                    self.state = AppState::WritingResponse;
-                                    self.input_mode = InputMode::Manual;
-                                    self.response_text.clear();
-                                    self.cursor_position = 0;
-                                    self.ai_generated_response = None;
-                                    self.error_message = None;
-                                }
-                                Err(e) => {
-                                    self.error_message =
-                                        Some(format!("Failed to fetch response data: {}", e));
-                                }
-                            }
-                            self.loading = false;
-                        }
-                    }
-                    KeyCode::Char('a') => {
-                        if let Some(review_idx) = self.selected_review {
-                            // First fetch response data for this review
-                            self.loading = true;
-                            let review_id = &self.reviews[review_idx].id;
-                            match self.api_client.get_review_response(review_id).await {
-                                Ok(response) => {
-                                    self.reviews[review_idx].response = response;
-                                    self.state = AppState::GeneratingAI;
-                                    self.input_mode = InputMode::AI;
-
-                                    // Generate AI response (placeholder)
-                                    let ai_response = self.generate_ai_response().await?;
-                                    self.ai_generated_response = Some(ai_response.clone());
-                                    self.response_text = ai_response;
-                                    self.cursor_position = self.response_text.len(); // Set cursor at end
-                                    self.loading = false;
-                                    self.state = AppState::WritingResponse;
-                                    self.error_message = None;
-                                }
-                                Err(e) => {
-                                    self.error_message =
-                                        Some(format!("Failed to fetch response data: {}", e));
-                                    self.loading = false;
-                                }
-                            }
-                        }
-                    }
-                    _ => {}
-                }
+    // Returns whether Esc stopped the fetch early
+    async fn fetch_pages(
+        api_client: &mut ApiClient,
+        reviews: &mut Vec<Review>,
+        total: Option<usize>,
+        interactive: bool,
+    ) -> Result<bool> {
+        let started = Instant::now();
+        let already_fetched = reviews.len();
+        let mut pages = 0;
+        while api_client.has_more_reviews() {
+            eprint!(
+                "\r\x1b[2K{}",
+                fetch_progress(
+                    reviews.len(),
+                    total,
+                    reviews.len() - already_fetched,
+                    pages,
+                    started.elapsed()
+                )
+            );
+            if interactive && esc_pressed()? {
+                return Ok(true);
             }
-            AppState::WritingResponse => {
-                match key.code {
-                    KeyCode::Esc => {
-                        self.state = AppState::ViewingReviews;
-                        self.response_text.clear();
-                        self.cursor_position = 0;
-                        self.ai_generated_response = None;
-                    }
-                    KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                        if !self.response_text.trim().is_empty() {
-                            self.state = AppState::ConfirmingResponse;
-                        }
+            let page = api_client.load_more_reviews().await.map_err(|e| {
+                anyhow!(
+                    "Fetching all reviews stopped after {}: {}",
+                    reviews.len(),
+                    e
+                )
+            })?;
+            reviews.extend(page);
+            pages += 1;
+        }
+
+        Ok(false)
+    }
+
+    // `offline` starts with an empty list instead of failing when reviews can't be fetched
+    pub async fn new(config: Config, mut api_client: ApiClient, offline: bool) -> Result<Self> {
+        // Initialize AI generator if OpenAI API key is available
+        let ai_config = AIConfig::for_config(&config)?;
+        let (ai_generator, ai_disabled_reason) =
+            Self::build_ai_generator(&ai_config, config.offline);
+
+        if config.require_ai {
+            if let Some(reason) = &ai_disabled_reason {
+                return Err(anyhow!(
+                    "AI responses are required (--require-ai) but could not be enabled: {}",
+                    reason
+                ));
+            }
+        }
+
+        let local_state = match &config.state_path {
+            Some(path) => LocalState::load(path)?,
+            None => LocalState::default(),
+        };
+
+        // Shown once at startup so a missing key doesn't go unnoticed
+        let mut startup_messages: Vec<String> = ai_disabled_reason
+            .map(|reason| {
+                format!(
+                    "AI responses are disabled: {}. Pressing 'a' will use a basic template instead.",
+                    reason
+                )
+            })
+            .into_iter()
+            .collect();
+        if ai_generator.is_some() {
+            startup_messages.extend(ai_config.limit_conflicts());
+        }
+        // Demo mode never reads or writes the cache
+        let review_cache = if config.reviews_file.is_some() {
+            None
+        } else {
+            cache::load_all(&api_client.configs()).unwrap_or_else(|e| {
+                startup_messages.push(format!("Ignoring the review cache: {}", e));
+                None
+            })
+        };
+        let cached_reviews = || {
+            review_cache
+                .as_ref()
+                .map(|cache| cache.reviews.clone())
+                .unwrap_or_default()
+        };
+        let mut cached_since = None;
+        let mut reviews = if let Some(path) = &config.reviews_file {
+            let reviews = review::load_reviews_file(path)?;
+            startup_messages.push(format!(
+                "Demo mode: showing {} reviews from {}. Responses are not submitted.",
+                reviews.len(),
+                path.display()
+            ));
+            reviews
+        } else if config.offline {
+            startup_messages.push(match &review_cache {
+                Some(cache) => format!(
+                    "Working offline with {} reviews cached {}. Responses are queued and submitted the next time rustpond starts online.",
+                    cache.reviews.len(),
+                    cache.fetched_at.format("%Y-%m-%d %H:%M UTC")
+                ),
+                None => "Working offline, but no reviews have been cached for this app yet. Run rustpond online once to cache them.".to_string(),
+            });
+            cached_reviews()
+        } else if let Some(cache) = review_cache
+            .as_ref()
+            .filter(|cache| !config.no_cache && !config.fetch_all && !cache.reviews.is_empty())
+        {
+            // Refreshed as soon as the list is on screen
+            cached_since = Some(cache.fetched_at);
+            cache.reviews.clone()
+        } else {
+            match api_client.get_reviews().await {
+                Ok(reviews) => reviews,
+                Err(e) if offline => {
+                    startup_messages.push(format!(
+                        "Working offline: {}. Showing cached reviews. Press 'r' to retry loading reviews.",
+                        e
+                    ));
+                    cached_reviews()
+                }
+                Err(e) => return Err(e),
+            }
+        };
+
+        if config.fetch_all && !offline {
+            Self::fetch_remaining_reviews(&mut api_client, &mut reviews).await?;
+        }
+
+        config.fetch_sort.sort(&mut reviews);
+        api_client.remember_platforms(&reviews);
+
+        // Deep links may point past the loaded pages, so fetch those directly
+        let mut selected_review = if reviews.is_empty() { None } else { Some(0) };
+        let mut reviews_layout = None;
+        if let Some(review_id) = &config.review_id {
+            let idx = match reviews.iter().position(|r| &r.id == review_id) {
+                Some(idx) => idx,
+                None if config.offline => {
+                    return Err(anyhow!("Review {} isn't in the offline cache", review_id));
+                }
+                None if config.reviews_file.is_some() => {
+                    return Err(anyhow!("Review {} isn't in the reviews file", review_id));
+                }
+                None => {
+                    reviews.push(api_client.get_review(review_id).await?);
+                    reviews.len() - 1
+                }
+            };
+            selected_review = Some(idx);
+            reviews_layout = Some(ReviewsLayout::DetailsOnly);
+        }
+
+        let mut list_state = ListState::default();
+        list_state.select(selected_review);
+
+        let mut ui = Self {
+            api_client,
+            ai_generator,
+            reviews,
+            selected_review,
+            state: AppState::ViewingReviews,
+            response_text: String::new(),
+            cursor_position: 0,
+            input_mode: InputMode::Manual,
+            undo_stack: Vec::new(),
+            ai_generated_response: None,
+            loading: false,
+            cached_since,
+            background_refresh: None,
+            responses_before_refresh: HashMap::new(),
+            error_message: if startup_messages.is_empty() {
+                None
+            } else {
+                Some(startup_messages.join("\n\n"))
+            },
+            list_state,
+            list_offset: 0,
+            list_rows: 1,
+            help_visible: !config.hide_help,
+            config,
+            palette_query: String::new(),
+            palette_selected: 0,
+            reply_in_original_language: false,
+            loading_message: "Generating AI response...",
+            fetch_progress: None,
+            reviews_layout,
+            reviews_width: 0,
+            local_state,
+            skip_filter: None,
+            moderation_view: false,
+            rating_changes_only: false,
+            group_by_reviewer: false,
+            expanded_reviewers: HashSet::new(),
+            last_refresh: Instant::now(),
+            auto_refresh_paused: false,
+            ai_config,
+            submit_error: None,
+            payload_preview: None,
+            text_expanded: false,
+            search_query: String::new(),
+            model_fallback_shown: false,
+            raw_json_scroll: 0,
+            session_responses: Vec::new(),
+            activity_selected: 0,
+            note_input: String::new(),
+            follow_up_input: String::new(),
+            follow_ups_selected: 0,
+            canned_responses: Vec::new(),
+            canned_selected: 0,
+            canned_response: false,
+            queued_overwrites: Vec::new(),
+            last_error: None,
+        };
+
+        if !offline && ui.cached_since.is_none() {
+            ui.cache_reviews();
+        }
+        // Responses queued offline wait for a run that may submit them
+        if !offline && !ui.config.read_only {
+            if let Some(message) = ui.submit_queued_responses().await {
+                ui.error_message = Some(match ui.error_message.take() {
+                    Some(previous) => format!("{}\n\n{}", previous, message),
+                    None => message,
+                });
+            }
+        }
+        let due = ui
+            .local_state
+            .due_follow_up_count(Local::now().date_naive());
+        if due > 0 {
+            let message = format!(
+                "{} follow-up reminder{} due. Press 'U' to see them",
+                due,
+                if due == 1 { " is" } else { "s are" }
+            );
+            ui.error_message = Some(match ui.error_message.take() {
+                Some(previous) => format!("{}\n\n{}", previous, message),
+                None => message,
+            });
+        }
+        Ok(ui)
+    }
+
+    fn save_local_state(&self) -> Result<()> {
+        match &self.config.state_path {
+            Some(path) => self.local_state.save(path),
+            None => Ok(()),
+        }
+    }
+
+    // Keeps the loaded reviews for `--offline` and the next start, along with
+    // today's response rate
+    fn cache_reviews(&mut self) {
+        let app_ids = cache_app_ids(&self.api_client);
+        if !self.reviews.is_empty() {
+            let responded = self.reviews.len() - self.unresponded_count();
+            self.local_state
+                .record_response_rate(app_ids, responded, self.reviews.len());
+        }
+        let mut result = Ok(());
+        if self.config.reviews_file.is_none() {
+            let configs = self.api_client.configs();
+            if let Ok(Some(previous)) = cache::load_all(&configs) {
+                self.local_state
+                    .record_changes(&previous.reviews, &self.reviews);
+            }
+            result = cache::save_all(&configs, &self.reviews);
+        }
+        if let Err(e) = result.and_then(|_| self.save_local_state()) {
+            self.report_error(format!("Failed to cache reviews: {}", e));
+        }
+    }
+
+    // Submits responses queued by `--offline` for the apps in use. Ones that
+    // fail stay queued for the next start.
+    async fn submit_queued_responses(&mut self) -> Option<String> {
+        let app_ids = cache_app_ids(&self.api_client);
+        let (queued, other_apps): (Vec<QueuedResponse>, Vec<QueuedResponse>) =
+            std::mem::take(&mut self.local_state.queued_responses)
+                .into_iter()
+                .partition(|queued| app_ids.contains(&queued.app_id));
+        self.local_state.queued_responses = other_apps;
+        if queued.is_empty() {
+            return None;
+        }
+
+        let total = queued.len();
+        let mut failed = 0;
+        let mut last_error = None;
+        let mut audit_warning = None;
+        for queued in queued {
+            let result = if queued.replace {
+                self.api_client
+                    .update_response(
+                        &queued.review_id,
+                        Some(&queued.app_id),
+                        &queued.response_body,
+                        queued.ai_usage,
+                    )
+                    .await
+            } else {
+                self.api_client
+                    .submit_response(
+                        &queued.review_id,
+                        Some(&queued.app_id),
+                        &queued.response_body,
+                        queued.ai_usage,
+                    )
+                    .await
+            };
+            match result {
+                Ok(warning) => {
+                    audit_warning = warning.or(audit_warning);
+                    if let Some(review) = self.reviews.iter_mut().find(|r| r.id == queued.review_id)
+                    {
+                        review.response = Some(ReviewResponse {
+                            id: String::new(),
+                            response_body: queued.response_body,
+                            last_modified_date: Utc::now(),
+                            state: ResponseState::Pending,
+                        });
                     }
-                    KeyCode::Enter => {
-                        // Regular Enter adds a new line at cursor position
-                        if let Some(limit) = self.get_character_limit() {
-                            if self.response_text.len() < limit {
-                                self.response_text.insert(self.cursor_position, '\n');
-                                self.cursor_position += 1;
-                            }
-                        } else {
-                            self.response_text.insert(self.cursor_position, '\n');
-                            self.cursor_position += 1;
-                        }
+                }
+                // Stays queued until the overwrite is confirmed
+                Err(e) if !queued.replace && e.is::<ResponseExists>() => {
+                    self.queued_overwrites.push(queued.clone());
+                    self.local_state.queued_responses.push(queued);
+                }
+                Err(e) => {
+                    failed += 1;
+                    last_error = Some(e.to_string());
+                    self.local_state.queued_responses.push(queued);
+                }
+            }
+        }
+        let save_result = self.save_local_state();
+
+        let answered = self.queued_overwrites.len();
+        let mut message = format!(
+            "Submitted {} of {} responses queued while offline",
+            total - failed - answered,
+            total
+        );
+        if answered > 0 {
+            message.push_str(&format!(
+                ". {} of the reviews already had a response on the store, so each waits for you to confirm replacing it",
+                answered
+            ));
+        }
+        if let Some(e) = last_error {
+            self.last_error = Some(format!("Failed to submit a queued response: {}", e));
+            message.push_str(&format!(
+                ". {} failed and stay queued for next time (last error: {})",
+                failed, e
+            ));
+        }
+        if let Err(e) = save_result {
+            message.push_str(&format!(". Failed to update the queue: {}", e));
+        }
+        if let Some(warning) = audit_warning {
+            message.push_str(&format!(". {}", warning));
+        }
+        self.confirm_next_queued_overwrite().await;
+        Some(message)
+    }
+
+    // Opens the overwrite prompt for the first of `queued_overwrites`, fetching
+    // its review when it isn't loaded. One that can't be fetched stays queued
+    // for the next start.
+    async fn confirm_next_queued_overwrite(&mut self) {
+        while let Some(queued) = self.queued_overwrites.first() {
+            let review_id = queued.review_id.clone();
+            let response_body = queued.response_body.clone();
+            let review_idx = match self.reviews.iter().position(|r| r.id == review_id) {
+                Some(idx) => {
+                    // Show the existing response next to the queued one
+                    if let Ok(Some(existing)) =
+                        self.api_client.get_review_response(&review_id).await
+                    {
+                        self.reviews[idx].response = Some(existing);
                     }
-                    KeyCode::Char(c) => {
-                        // Handle Option+Arrow key sequences that come as characters
-                        match c {
-                            'b' if key.modifiers.contains(KeyModifiers::ALT) => {
-                                // Option+Left (sometimes sent as Alt+b)
-                                self.cursor_position = self.find_prev_word_boundary();
-                            }
-                            'f' if key.modifiers.contains(KeyModifiers::ALT) => {
-                                // Option+Right (sometimes sent as Alt+f)
-                                self.cursor_position = self.find_next_word_boundary();
-                            }
-                            'w' if key.modifiers.contains(KeyModifiers::ALT) => {
-                                // Option+Backspace: Delete previous word (sometimes sent as Alt+w)
-                                let word_start = self.find_prev_word_boundary();
-                                if word_start < self.cursor_position {
-                                    self.response_text.drain(word_start..self.cursor_position);
-                                    self.cursor_position = word_start;
-                                }
-                            }
-                            'w' if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                                // Option+Backspace: Delete previous word (sent as Ctrl+w)
-                                let word_start = self.find_prev_word_boundary();
-                                if word_start < self.cursor_position {
-                                    self.response_text.drain(word_start..self.cursor_position);
-                                    self.cursor_position = word_start;
-                                }
-                            }
-                            'd' if key.modifiers.contains(KeyModifiers::ALT) => {
-                                // Option+d: Delete next word (Alt+d sequence)
-                                let word_end = self.find_next_word_boundary();
-                                if self.cursor_position < word_end {
-                                    self.response_text.drain(self.cursor_position..word_end);
-                                }
-                            }
-                            '\u{0017}' => {
-                                // Ctrl+W: Delete previous word (common terminal sequence for Option+Backspace)
-                                let word_start = self.find_prev_word_boundary();
-                                if word_start < self.cursor_position {
-                                    self.response_text.drain(word_start..self.cursor_position);
-                                    self.cursor_position = word_start;
+                    idx
+                }
+                None => match self.api_client.get_review(&review_id).await {
+                    Ok(review) => {
+                        self.reviews.push(review);
+                        self.reviews.len() - 1
+                    }
+                    Err(_) => {
+                        self.queued_overwrites.remove(0);
+                        continue;
+                    }
+                },
+            };
+            self.select_review(Some(review_idx));
+            self.response_text = response_body;
+            self.cursor_position = self.response_text.len();
+            self.state = AppState::ConfirmingOverwrite;
+            return;
+        }
+    }
+
+    // Sends the queued response being confirmed with `update_response`, or
+    // keeps the store's response and drops the queued one, then moves on to
+    // the next. A failed update stays queued.
+    async fn finish_queued_overwrite(&mut self, replace: bool) {
+        let queued = self.queued_overwrites.remove(0);
+        self.state = AppState::ViewingReviews;
+        self.response_text.clear();
+        self.cursor_position = 0;
+
+        let mut message = if replace {
+            match self
+                .api_client
+                .update_response(
+                    &queued.review_id,
+                    Some(&queued.app_id),
+                    &queued.response_body,
+                    queued.ai_usage,
+                )
+                .await
+            {
+                Ok(audit_warning) => {
+                    self.local_state
+                        .queued_responses
+                        .retain(|q| q.review_id != queued.review_id);
+                    if let Some(idx) = self.reviews.iter().position(|r| r.id == queued.review_id) {
+                        self.reviews[idx].response = Some(ReviewResponse {
+                            id: String::new(),
+                            response_body: queued.response_body,
+                            last_modified_date: Utc::now(),
+                            state: ResponseState::Pending,
+                        });
+                        self.record_session_response(idx, true);
+                    }
+                    match audit_warning {
+                        Some(warning) => format!("Response replaced successfully! {}", warning),
+                        None => "Response replaced successfully!".to_string(),
+                    }
+                }
+                Err(e) => {
+                    self.last_error = Some(format!("Failed to replace the response: {}", e));
+                    format!(
+                        "Failed to replace the response: {}. The queued response stays queued for next time",
+                        e
+                    )
+                }
+            }
+        } else {
+            self.local_state
+                .queued_responses
+                .retain(|q| q.review_id != queued.review_id);
+            "Kept the response on the store and dropped the queued one".to_string()
+        };
+        if let Err(e) = self.save_local_state() {
+            message.push_str(&format!(". Failed to update the queue: {}", e));
+        }
+        self.error_message = Some(message);
+        self.confirm_next_queued_overwrite().await;
+    }
+
+    pub async fn run(&mut self) -> Result<()> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(
+            stdout,
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableBracketedPaste
+        )?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        // Ctrl+C arrives as a key press in raw mode, but a SIGINT sent from
+        // outside, e.g. by `kill -INT`, would end the process without restoring
+        // the terminal. It's handled between events instead, so an in-flight
+        // submission finishes first.
+        let interrupted = Arc::new(AtomicBool::new(false));
+        let signal_task = {
+            let interrupted = interrupted.clone();
+            tokio::spawn(async move {
+                if tokio::signal::ctrl_c().await.is_ok() {
+                    interrupted.store(true, Ordering::Relaxed);
+                }
+            })
+        };
+
+        let result = self.run_app(&mut terminal, &interrupted).await;
+        signal_task.abort();
+
+        disable_raw_mode()?;
+        execute!(
+            terminal.backend_mut(),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            DisableBracketedPaste
+        )?;
+        terminal.show_cursor()?;
+
+        result
+    }
+
+    async fn run_app<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        interrupted: &AtomicBool,
+    ) -> Result<()> {
+        let mut last_tick = Instant::now();
+        let tick_rate = Duration::from_millis(250);
+
+        // Show the cached reviews first, then replace them with fresh ones
+        if self.cached_since.is_some() {
+            self.start_background_refresh();
+        }
+
+        loop {
+            if interrupted.load(Ordering::Relaxed) {
+                break;
+            }
+            terminal.draw(|f| self.ui(f))?;
+
+            let timeout = tick_rate
+                .checked_sub(last_tick.elapsed())
+                .unwrap_or_else(|| Duration::from_secs(0));
+
+            if crossterm::event::poll(timeout)? {
+                let action = match event::read()? {
+                    // Quits from any screen, like 'q' does from the list
+                    Event::Key(key)
+                        if key.code == KeyCode::Char('c')
+                            && key.modifiers.contains(KeyModifiers::CONTROL) =>
+                    {
+                        Some(UIAction::Quit)
+                    }
+                    Event::Key(key) => self.handle_input(key).await?,
+                    Event::Paste(pasted) if self.state == AppState::WritingResponse => {
+                        self.paste(&pasted);
+                        None
+                    }
+                    // Notes and follow-ups are a single line
+                    Event::Paste(pasted) if self.state == AppState::EditingNote => {
+                        let pasted = text::normalize_line_breaks(&pasted);
+                        self.note_input.push_str(&pasted.replace('\n', " "));
+                        None
+                    }
+                    Event::Paste(pasted) if self.state == AppState::Searching => {
+                        let pasted = text::normalize_line_breaks(&pasted);
+                        self.search_query.push_str(&pasted.replace('\n', " "));
+                        self.reselect_visible();
+                        None
+                    }
+                    Event::Paste(pasted) if self.state == AppState::EditingFollowUp => {
+                        let pasted = text::normalize_line_breaks(&pasted);
+                        self.follow_up_input.push_str(&pasted.replace('\n', " "));
+                        None
+                    }
+                    _ => None,
+                };
+                if let Some(action) = action {
+                    match action {
+                        UIAction::Quit => break,
+                        UIAction::Refresh | UIAction::HardRefresh | UIAction::LoadMore
+                            if self.blocked_offline() => {}
+                        UIAction::Refresh if self.moderation_view => {
+                            self.recheck_pending_responses().await;
+                        }
+                        UIAction::Refresh => self.refresh_reviews(terminal, false, false).await,
+                        UIAction::HardRefresh => self.refresh_reviews(terminal, false, true).await,
+                        UIAction::GenerateAI => self.generate_into_editor(terminal).await?,
+                        UIAction::ExternalEditor => self.edit_externally(terminal)?,
+                        UIAction::AdjustTone(adjustment) => {
+                            self.adjust_tone(terminal, adjustment).await?
+                        }
+                        // The client's pages still start from the first one
+                        UIAction::LoadMore if self.background_refresh.is_some() => {
+                            self.error_message = Some(
+                                "The newest reviews are still loading. Press 'l' again once they're in."
+                                    .to_string(),
+                            );
+                        }
+                        UIAction::LoadMore => {
+                            self.loading = true;
+                            match self.api_client.load_more_reviews().await {
+                                Ok(mut new_reviews) => {
+                                    self.config.fetch_sort.sort(&mut new_reviews);
+                                    self.reviews.extend(new_reviews);
+                                    self.error_message = None;
+                                    self.cache_reviews();
                                 }
-                            }
-                            '\u{007f}' if key.modifiers.contains(KeyModifiers::ALT) => {
-                                // Option+Backspace: Delete previous word (Alt+DEL sequence)
-                                let word_start = self.find_prev_word_boundary();
-                                if word_start < self.cursor_position {
-                                    self.response_text.drain(word_start..self.cursor_position);
-                                    self.cursor_position = word_start;
+                                Err(e) => {
+                                    self.report_error(format!(
+                                        "Failed to load more reviews: {}",
+                                        e
+                                    ));
                                 }
                             }
-                            _ => {
-                                // Check character limit before inserting
-                                if let Some(limit) = self.get_character_limit() {
-                                    if self.response_text.len() < limit {
-                                        self.response_text.insert(self.cursor_position, c);
-                                        self.cursor_position += 1;
-                                    }
-                                } else {
-                                    self.response_text.insert(self.cursor_position, c);
-                                    self.cursor_position += 1;
-                                }
+                            self.loading = false;
+                        }
+                    }
+                }
+            }
+
+            if last_tick.elapsed() >= tick_rate {
+                last_tick = Instant::now();
+                self.finish_background_refresh().await;
+                if self.auto_refresh_due() {
+                    self.refresh_reviews(terminal, true, false).await;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // With --quick-refresh only the newest page is fetched and merged into the
+    // loaded reviews, unless `hard` asks for every page
+    async fn refresh_reviews<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        keep_selection: bool,
+        hard: bool,
+    ) {
+        if let Some(task) = self.background_refresh.take() {
+            task.abort();
+        }
+        self.loading = true;
+        self.last_refresh = Instant::now();
+        let selected_id = self.selected_review.map(|idx| self.reviews[idx].id.clone());
+        let quick = self.config.quick_refresh && !hard;
+
+        let fetched = if quick {
+            self.api_client
+                .fetch_newest_reviews()
+                .await
+                .map(|reviews| (reviews, None))
+        } else if matches!(self.api_client, ApiClient::GooglePlay(_)) {
+            self.refresh_with_progress(terminal).await
+        } else {
+            self.api_client.refresh_all_reviews().await
+        };
+        self.fetch_progress = None;
+        match fetched {
+            Ok((mut reviews, warning)) => {
+                let mut new_count = 0;
+                if quick {
+                    (reviews, new_count) = self.merge_into_loaded(reviews);
+                }
+                self.config.fetch_sort.sort(&mut reviews);
+
+                self.reviews = reviews;
+                self.cached_since = None;
+                self.cache_reviews();
+                let kept = selected_id
+                    .filter(|_| keep_selection)
+                    .and_then(|id| self.reviews.iter().position(|r| r.id == id));
+                match kept {
+                    Some(idx) => {
+                        self.selected_review = Some(idx);
+                        self.reselect_visible();
+                    }
+                    None => self.select_review(self.visible_reviews().first().copied()),
+                }
+                if !keep_selection {
+                    self.error_message = quick.then(|| {
+                        format!(
+                            "Fetched the newest reviews ({} new). Press 'H' to re-fetch every page.",
+                            new_count
+                        )
+                    });
+                }
+                if let Some(warning) = warning {
+                    self.report_error(format!("{}. Press 'l' to try loading the rest.", warning));
+                }
+            }
+            Err(e) => {
+                self.report_error(format!("Failed to refresh reviews: {}", e));
+            }
+        }
+        self.loading = false;
+    }
+
+    // Fetches the newest page on a copy of the client, so the cached reviews
+    // can be browsed and answered in the meantime
+    fn start_background_refresh(&mut self) {
+        self.responses_before_refresh = self
+            .reviews
+            .iter()
+            .map(|review| (review.id.clone(), review.response.clone()))
+            .collect();
+        let mut api_client = self.api_client.clone();
+        self.background_refresh = Some(tokio::spawn(async move {
+            let fetched = api_client.get_reviews().await;
+            (api_client, fetched)
+        }));
+    }
+
+    // Swaps the fetched page in for the cached reviews once it's in, but only
+    // while the list is showing, so nothing changes under an open editor or
+    // prompt. Responses changed locally since the fetch started are newer than
+    // what it returned, so they're kept. Paging carries on from the copy of the
+    // client, which is past the first page.
+    async fn finish_background_refresh(&mut self) {
+        if self.state != AppState::ViewingReviews
+            || !self
+                .background_refresh
+                .as_ref()
+                .is_some_and(|task| task.is_finished())
+        {
+            return;
+        }
+        let Some(task) = self.background_refresh.take() else {
+            return;
+        };
+        let responses_before = std::mem::take(&mut self.responses_before_refresh);
+        let fetched = match task.await {
+            Ok((api_client, fetched)) => {
+                self.api_client.copy_paging_from(&api_client);
+                fetched
+            }
+            Err(e) => Err(anyhow!("{}", e)),
+        };
+        match fetched {
+            Ok(mut reviews) => {
+                let loaded: HashMap<&str, &Review> = self
+                    .reviews
+                    .iter()
+                    .map(|review| (review.id.as_str(), review))
+                    .collect();
+                for review in &mut reviews {
+                    let Some(loaded) = loaded.get(review.id.as_str()) else {
+                        continue;
+                    };
+                    // Reviews fetched on their own since are newer too
+                    if responses_before
+                        .get(&review.id)
+                        .is_none_or(|before| *before != loaded.response)
+                    {
+                        review.response = loaded.response.clone();
+                    }
+                }
+                self.api_client.remember_platforms(&reviews);
+                let selected_id = self.selected_review.map(|idx| self.reviews[idx].id.clone());
+                self.config.fetch_sort.sort(&mut reviews);
+                self.reviews = reviews;
+                self.cached_since = None;
+                self.cache_reviews();
+                match selected_id.and_then(|id| self.reviews.iter().position(|r| r.id == id)) {
+                    Some(idx) => {
+                        self.selected_review = Some(idx);
+                        self.reselect_visible();
+                    }
+                    None => self.select_review(self.visible_reviews().first().copied()),
+                }
+            }
+            Err(e) => {
+                self.report_error(format!("Failed to refresh reviews: {}", e));
+            }
+        }
+    }
+
+    // Google Play doesn't report a total, so every page can take a while on big
+    // apps. The pages are fetched here rather than by `refresh_all_reviews` so
+    // the same count and elapsed time as the --fetch-all startup fetch can be
+    // drawn between them. Esc stops early and keeps the pages fetched so far.
+    async fn refresh_with_progress<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+    ) -> Result<(Vec<Review>, Option<String>)> {
+        if let ApiClient::GooglePlay(client) = &mut self.api_client {
+            client.restart_pagination();
+        }
+        let started = Instant::now();
+        let mut reviews = Vec::new();
+        let mut pages = 0;
+        let mut warning = None;
+        while self.api_client.has_more_reviews() {
+            self.fetch_progress = Some(fetch_progress(
+                reviews.len(),
+                None,
+                reviews.len(),
+                pages,
+                started.elapsed(),
+            ));
+            terminal.draw(|f| self.ui(f))?;
+            if esc_pressed()? {
+                warning = Some(format!(
+                    "Refresh stopped after {} reviews, so older ones are missing",
+                    reviews.len()
+                ));
+                break;
+            }
+            match self.api_client.load_more_reviews().await {
+                Ok(page) => reviews.extend(page),
+                Err(e) if reviews.is_empty() => return Err(e),
+                Err(e) => {
+                    warning = Some(api::stopped_early(reviews.len(), &e));
+                    break;
+                }
+            }
+            pages += 1;
+        }
+        self.config.fetch_sort.sort(&mut reviews);
+
+        Ok((reviews, warning))
+    }
+
+    // Fetched reviews replace loaded ones with the same id, so new responses
+    // and changed ratings show up. Returns the merged list and how many
+    // reviews weren't loaded before.
+    fn merge_into_loaded(&self, fetched: Vec<Review>) -> (Vec<Review>, usize) {
+        let fetched_ids: HashSet<&str> = fetched.iter().map(|r| r.id.as_str()).collect();
+        let mut reviews: Vec<Review> = self
+            .reviews
+            .iter()
+            .filter(|review| !fetched_ids.contains(review.id.as_str()))
+            .cloned()
+            .collect();
+        let new_count = fetched.len() - (self.reviews.len() - reviews.len());
+        reviews.extend(fetched);
+        (reviews, new_count)
+    }
+
+    // Auto-refresh only runs while browsing the list, so it never replaces the
+    // reviews under an open editor, prompt or palette
+    fn auto_refresh_due(&self) -> bool {
+        match self.config.auto_refresh {
+            Some(interval) => {
+                !self.config.offline
+                    && self.config.reviews_file.is_none()
+                    && !self.auto_refresh_paused
+                    && !self.loading
+                    && self.background_refresh.is_none()
+                    && self.state == AppState::ViewingReviews
+                    && self.last_refresh.elapsed() >= interval
+            }
+            None => false,
+        }
+    }
+
+    fn auto_refresh_label(&self) -> Option<String> {
+        let interval = self.config.auto_refresh?;
+        if self.auto_refresh_paused {
+            return Some("auto-refresh paused, 'p' to resume".to_string());
+        }
+        let remaining = interval.saturating_sub(self.last_refresh.elapsed());
+        Some(format!("auto-refresh in {}s", remaining.as_secs()))
+    }
+
+    // Responses arrive with the review list, so only reviews that had none are
+    // re-checked in case a reply was posted since the list was loaded
+    async fn load_response(&mut self, review_idx: usize) -> Result<Option<ReviewResponse>> {
+        let review = &self.reviews[review_idx];
+        if review.response.is_some() || self.config.offline || self.config.reviews_file.is_some() {
+            return Ok(review.response.clone());
+        }
+        self.api_client.get_review_response(&review.id).await
+    }
+
+    // Re-fetches only the selected review's response, which is much quicker
+    // than reloading the whole list after submitting
+    async fn refresh_selected_response(&mut self) {
+        let Some(review_idx) = self.selected_review else {
+            return;
+        };
+
+        self.loading = true;
+        let review_id = self.reviews[review_idx].id.clone();
+        match self.api_client.get_review_response(&review_id).await {
+            Ok(Some(response)) => {
+                self.reviews[review_idx].response = Some(response);
+                self.error_message = Some("Response refreshed".to_string());
+            }
+            // A just-submitted response can take a while to show up in the
+            // store, so keep the locally tracked one until it does
+            Ok(None) if self.reviews[review_idx].response.is_some() => {
+                self.error_message =
+                    Some("The store hasn't published the submitted response yet".to_string());
+            }
+            Ok(None) => {
+                self.error_message = Some("This review has no developer response".to_string());
+            }
+            Err(e) => {
+                self.report_error(format!("Failed to refresh the response: {}", e));
+            }
+        }
+        self.loading = false;
+    }
+
+    // Indices into `self.reviews` that pass the current filters, in list order
+    fn filtered_reviews(&self) -> Vec<usize> {
+        let query = self.search_query.to_lowercase();
+        self.reviews
+            .iter()
+            .enumerate()
+            .filter(|(_, review)| match self.skip_filter {
+                Some(reason) => self.local_state.skip_reason(&review.id) == Some(reason),
+                None => true,
+            })
+            .filter(|(_, review)| !self.moderation_view || is_pending(review))
+            .filter(|(_, review)| {
+                !self.rating_changes_only
+                    || self.local_state.rating_changes.contains_key(&review.id)
+            })
+            .filter(|(_, review)| matches_search(review, &query))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    // The filtered reviews as listed. When grouping by reviewer, each nickname's
+    // newest review stands in for the group, followed by the rest if expanded.
+    fn visible_reviews(&self) -> Vec<usize> {
+        let filtered = self.filtered_reviews();
+        if !self.group_by_reviewer {
+            return filtered;
+        }
+
+        let mut grouped = Vec::with_capacity(filtered.len());
+        let mut seen = HashSet::new();
+        for &idx in &filtered {
+            let Some(nickname) = reviewer_key(&self.reviews[idx]) else {
+                grouped.push(idx);
+                continue;
+            };
+            if !seen.insert(nickname) {
+                continue;
+            }
+            grouped.push(idx);
+            if self.expanded_reviewers.contains(nickname) {
+                grouped.extend(filtered.iter().copied().filter(|&other| {
+                    other != idx && reviewer_key(&self.reviews[other]) == Some(nickname)
+                }));
+            }
+        }
+        grouped
+    }
+
+    // How many loaded reviews share each nickname
+    fn reviewer_counts(&self, indices: &[usize]) -> HashMap<&str, usize> {
+        let mut counts = HashMap::new();
+        for &idx in indices {
+            if let Some(nickname) = reviewer_key(&self.reviews[idx]) {
+                *counts.entry(nickname).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    fn toggle_reviewer_grouping(&mut self) {
+        self.group_by_reviewer = !self.group_by_reviewer;
+        // Keep an older review selected by expanding its group
+        if let Some(idx) = self.selected_review {
+            if !self.visible_reviews().contains(&idx) {
+                if let Some(nickname) = reviewer_key(&self.reviews[idx]) {
+                    self.expanded_reviewers.insert(nickname.to_string());
+                }
+            }
+        }
+        self.reselect_visible();
+    }
+
+    // Steps through the filtered reviews sharing the selected review's nickname,
+    // wrapping around. A collapsed group is expanded so the review is listed.
+    fn select_same_reviewer(&mut self, step: isize) {
+        let Some(review_idx) = self.selected_review else {
+            return;
+        };
+        let Some(nickname) = reviewer_key(&self.reviews[review_idx]).map(str::to_string) else {
+            self.error_message = Some(
+                "Reviews by placeholder names like \"A Google user\" can't be matched to one reviewer"
+                    .to_string(),
+            );
+            return;
+        };
+        let same: Vec<usize> = self
+            .filtered_reviews()
+            .into_iter()
+            .filter(|&idx| reviewer_key(&self.reviews[idx]) == Some(nickname.as_str()))
+            .collect();
+        let Some(position) = same.iter().position(|&idx| idx == review_idx) else {
+            return;
+        };
+        if same.len() < 2 {
+            self.error_message = Some(format!("No other loaded reviews by {}", nickname));
+            return;
+        }
+
+        let next = (position as isize + step).rem_euclid(same.len() as isize) as usize;
+        if self.group_by_reviewer {
+            self.expanded_reviewers.insert(nickname);
+        }
+        self.select_review(Some(same[next]));
+    }
+
+    fn toggle_reviewer_group(&mut self) {
+        if !self.group_by_reviewer {
+            self.error_message = Some("Press 'g' to group reviews by reviewer first".to_string());
+            return;
+        }
+        let Some(nickname) = self
+            .selected_review
+            .and_then(|idx| reviewer_key(&self.reviews[idx]))
+            .map(str::to_string)
+        else {
+            return;
+        };
+
+        if !self.expanded_reviewers.remove(&nickname) {
+            self.expanded_reviewers.insert(nickname);
+            return;
+        }
+        // Collapsing hides the selection unless it's the newest review, so
+        // move it to the one still listed
+        let visible = self.visible_reviews();
+        let head = visible
+            .iter()
+            .copied()
+            .find(|&idx| reviewer_key(&self.reviews[idx]) == Some(nickname.as_str()));
+        self.select_review(head);
+    }
+
+    // `selected_review` indexes `self.reviews`, while the list widget selects a
+    // row among the visible reviews
+    fn select_review(&mut self, review_idx: Option<usize>) {
+        if review_idx != self.selected_review {
+            self.text_expanded = false;
+        }
+        self.selected_review = review_idx;
+        let row = review_idx.and_then(|idx| self.visible_reviews().iter().position(|&v| v == idx));
+        self.list_state.select(row);
+    }
+
+    fn list_page_size(&self) -> usize {
+        self.config.list_page_size.unwrap_or(self.list_rows)
+    }
+
+    fn move_selection(&mut self, offset: isize) {
+        let visible = self.visible_reviews();
+        let current = self
+            .selected_review
+            .and_then(|idx| visible.iter().position(|&v| v == idx));
+        let next = match current {
+            Some(row) => row
+                .saturating_add_signed(offset)
+                .min(visible.len().saturating_sub(1)),
+            None => 0,
+        };
+        self.select_review(visible.get(next).copied());
+    }
+
+    fn cycle_skip_filter(&mut self) {
+        self.skip_filter = match self.skip_filter {
+            None => SKIP_REASONS.first().copied(),
+            Some(current) => SKIP_REASONS
+                .iter()
+                .position(|&reason| reason == current)
+                .and_then(|pos| SKIP_REASONS.get(pos + 1))
+                .copied(),
+        };
+        self.reselect_visible();
+    }
+
+    // Keeps the selection if it's still listed, otherwise selects the first row
+    fn reselect_visible(&mut self) {
+        let visible = self.visible_reviews();
+        match self.selected_review {
+            Some(idx) if visible.contains(&idx) => self.select_review(Some(idx)),
+            _ => self.select_review(visible.first().copied()),
+        }
+    }
+
+    fn toggle_moderation_view(&mut self) {
+        if !matches!(self.config.platform, Platform::Ios) {
+            self.error_message = Some("Only App Store responses wait for moderation".to_string());
+            return;
+        }
+        self.moderation_view = !self.moderation_view;
+        self.reselect_visible();
+    }
+
+    // Re-fetches the response of every review in the moderation view, so ones
+    // Apple has since published drop out of it
+    async fn recheck_pending_responses(&mut self) {
+        let pending: Vec<usize> = self.visible_reviews();
+        self.loading = true;
+
+        let mut published = 0;
+        let mut failed = 0;
+        for &idx in &pending {
+            let review_id = self.reviews[idx].id.clone();
+            match self.api_client.get_review_response(&review_id).await {
+                Ok(Some(response)) => {
+                    if matches!(response.state, ResponseState::Published) {
+                        published += 1;
+                    }
+                    self.reviews[idx].response = Some(response);
+                }
+                // Not visible to the API yet, so keep the local copy
+                Ok(None) => {}
+                Err(_) => failed += 1,
+            }
+        }
+
+        let mut message = format!(
+            "Re-checked {} responses: {} published, {} not yet published",
+            pending.len(),
+            published,
+            pending.len() - published - failed
+        );
+        if failed > 0 {
+            message.push_str(&format!(", {} failed to load", failed));
+        }
+        self.error_message = Some(message);
+        self.loading = false;
+        self.reselect_visible();
+    }
+
+    fn report_error(&mut self, message: String) {
+        self.last_error = Some(message.clone());
+        self.error_message = Some(message);
+    }
+
+    fn save_debug_bundle(&mut self) {
+        let review = self.selected_review.map(|idx| &self.reviews[idx]);
+        let bundle = debug::bundle(
+            &self.api_client.configs(),
+            self.last_error.as_deref(),
+            review,
+        );
+        self.error_message = Some(match debug::write_bundle(&bundle) {
+            Ok(path) => format!(
+                "Saved a debug bundle to {} (credentials and review text are redacted). Attach it to your bug report.",
+                path.display()
+            ),
+            Err(e) => e.to_string(),
+        });
+    }
+
+    fn show_raw_json(&mut self) {
+        let Some(review_idx) = self.selected_review else {
+            return;
+        };
+        if !self.config.debug {
+            self.error_message = Some(
+                "Start rustpond with --debug to keep the raw JSON for each review".to_string(),
+            );
+        } else if self.reviews[review_idx].raw.is_none() {
+            self.error_message = Some(
+                "No raw JSON for this review. It was cached or loaded without --debug, so press 'r' to reload it"
+                    .to_string(),
+            );
+        } else {
+            self.raw_json_scroll = 0;
+            self.state = AppState::ViewingRawJson;
+        }
+    }
+
+    fn start_note(&mut self) {
+        let Some(review_idx) = self.selected_review else {
+            return;
+        };
+        self.note_input = self
+            .local_state
+            .note(&self.reviews[review_idx].id)
+            .map(|note| note.text.clone())
+            .unwrap_or_default();
+        self.state = AppState::EditingNote;
+    }
+
+    fn save_note(&mut self) {
+        self.state = AppState::ViewingReviews;
+        let Some(review_idx) = self.selected_review else {
+            return;
+        };
+        let review_id = self.reviews[review_idx].id.clone();
+        let note = std::mem::take(&mut self.note_input);
+        self.local_state.set_note(&review_id, &note);
+        if let Err(e) = self.save_local_state() {
+            self.error_message = Some(format!("Failed to save the note: {}", e));
+        }
+    }
+
+    fn start_follow_up(&mut self) {
+        let Some(review_idx) = self.selected_review else {
+            return;
+        };
+        self.follow_up_input = self
+            .local_state
+            .follow_up(&self.reviews[review_idx].id)
+            .map(|follow_up| format!("{} {}", follow_up.due.format("%Y-%m-%d"), follow_up.note))
+            .unwrap_or_default();
+        self.state = AppState::EditingFollowUp;
+    }
+
+    // Invalid input keeps the popup open so it can be fixed
+    fn save_follow_up(&mut self) {
+        let Some(review_idx) = self.selected_review else {
+            self.state = AppState::ViewingReviews;
+            return;
+        };
+        let parsed = match state::parse_follow_up(&self.follow_up_input, Local::now().date_naive())
+        {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                self.error_message = Some(e.to_string());
+                return;
+            }
+        };
+
+        let review = &self.reviews[review_idx];
+        let follow_up = parsed.map(|(due, note)| FollowUp {
+            due,
+            note,
+            reviewer: review.reviewer_nickname.clone(),
+            snippet: snippet(
+                &[review.title.as_deref(), review.body.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            ),
+            created_at: Utc::now(),
+        });
+        let review_id = review.id.clone();
+        self.local_state.set_follow_up(&review_id, follow_up);
+        self.follow_up_input.clear();
+        self.error_message = None;
+        self.state = AppState::ViewingReviews;
+        if let Err(e) = self.save_local_state() {
+            self.error_message = Some(format!("Failed to save the follow-up: {}", e));
+        }
+    }
+
+    fn show_previous_version(&mut self) {
+        let Some(review_idx) = self.selected_review else {
+            return;
+        };
+        if self
+            .local_state
+            .review_edit(&self.reviews[review_idx].id)
+            .is_none()
+        {
+            self.error_message = Some(
+                "No edits to this review have been seen. Edits are noticed when a refresh finds different text than last time"
+                    .to_string(),
+            );
+            return;
+        }
+        self.state = AppState::ViewingPreviousVersion;
+    }
+
+    // Google Play replies can't be deleted, so that's said up front instead of
+    // after confirming
+    fn start_deletion(&mut self) {
+        let Some(review_idx) = self.selected_review else {
+            return;
+        };
+        let review = &self.reviews[review_idx];
+        if review.response.is_none() {
+            self.error_message =
+                Some("This review has no developer response to delete".to_string());
+            return;
+        }
+        if self.config.offline {
+            self.error_message = Some(
+                "Working offline (--offline): responses can't be deleted. Restart without --offline to delete it."
+                    .to_string(),
+            );
+            return;
+        }
+        if review.platform.unwrap_or(self.config.platform) == Platform::Android
+            && self.config.reviews_file.is_none()
+        {
+            self.error_message = Some(
+                "Google Play doesn't allow deleting replies through its API. Edit the reply with Enter, or delete it in the Play Console"
+                    .to_string(),
+            );
+            return;
+        }
+        self.state = AppState::ConfirmingDeletion;
+    }
+
+    async fn delete_response(&mut self) {
+        self.state = AppState::ViewingReviews;
+        let Some(review_idx) = self.selected_review else {
+            return;
+        };
+        // Demo reviews aren't real, so the response is only removed here
+        if self.config.reviews_file.is_some() {
+            self.reviews[review_idx].response = None;
+            self.error_message = Some(
+                "Demo mode (--reviews-file): the response was removed here but not deleted."
+                    .to_string(),
+            );
+            return;
+        }
+        let review_id = self.reviews[review_idx].id.clone();
+        let origin_app_id = self.reviews[review_idx].app_id.clone();
+        match self
+            .api_client
+            .delete_response(&review_id, origin_app_id.as_deref())
+            .await
+        {
+            Ok(()) => {
+                self.reviews[review_idx].response = None;
+                self.error_message = Some("Response deleted".to_string());
+            }
+            Err(e) => self.report_error(format!("Failed to delete response: {}", e)),
+        }
+    }
+
+    fn show_follow_ups(&mut self) {
+        if self.local_state.follow_ups.is_empty() {
+            self.error_message =
+                Some("No follow-up reminders. Press 'F' on a review to add one".to_string());
+            return;
+        }
+        self.follow_ups_selected = 0;
+        self.state = AppState::ViewingFollowUps;
+    }
+
+    fn selected_follow_up_id(&self) -> Option<String> {
+        self.local_state
+            .follow_ups_by_due()
+            .get(self.follow_ups_selected)
+            .map(|(review_id, _)| review_id.to_string())
+    }
+
+    fn jump_to_follow_up(&mut self) {
+        let Some(review_id) = self.selected_follow_up_id() else {
+            return;
+        };
+        self.state = AppState::ViewingReviews;
+        match self
+            .reviews
+            .iter()
+            .position(|review| review.id == review_id)
+        {
+            Some(idx) if self.visible_reviews().contains(&idx) => self.select_review(Some(idx)),
+            Some(_) => {
+                self.error_message =
+                    Some("That review is hidden by the current filter or grouping".to_string())
+            }
+            None => {
+                self.error_message =
+                    Some("That review isn't loaded. Press 'l' to load older reviews".to_string())
+            }
+        }
+    }
+
+    // Done reminders are removed rather than kept around
+    fn complete_follow_up(&mut self) {
+        let Some(review_id) = self.selected_follow_up_id() else {
+            return;
+        };
+        self.local_state.set_follow_up(&review_id, None);
+        if let Err(e) = self.save_local_state() {
+            self.error_message = Some(format!("Failed to save the follow-up: {}", e));
+        }
+        if self.local_state.follow_ups.is_empty() {
+            self.state = AppState::ViewingReviews;
+        } else {
+            self.follow_ups_selected = self
+                .follow_ups_selected
+                .min(self.local_state.follow_ups.len() - 1);
+        }
+    }
+
+    fn tag_selected_review(&mut self, reason: Option<&str>) {
+        let Some(review_idx) = self.selected_review else {
+            return;
+        };
+        let review_id = self.reviews[review_idx].id.clone();
+        self.local_state.set_skip_reason(&review_id, reason);
+
+        if let Some(path) = &self.config.state_path {
+            if let Err(e) = self.local_state.save(path) {
+                self.error_message = Some(format!("Failed to save skip reason: {}", e));
+                return;
+            }
+        }
+
+        // Skipping moves on to the next review; clearing a tag stays put
+        if reason.is_some() {
+            self.move_selection(1);
+        } else if !self.visible_reviews().contains(&review_idx) {
+            self.select_review(self.visible_reviews().first().copied());
+        }
+    }
+
+    fn unresponded_count(&self) -> usize {
+        self.reviews.iter().filter(|r| r.response.is_none()).count()
+    }
+
+    // Moves to the next review without a developer response, wrapping to the top
+    fn select_next_unresponded(&mut self) {
+        let visible = self.visible_reviews();
+        let start = self
+            .selected_review
+            .and_then(|idx| visible.iter().position(|&v| v == idx))
+            .map(|row| row + 1)
+            .unwrap_or(0);
+        let len = visible.len();
+        let next = (0..len)
+            .map(|offset| visible[(start + offset) % len])
+            .find(|&idx| self.reviews[idx].response.is_none());
+
+        match next {
+            Some(idx) => self.select_review(Some(idx)),
+            None => {
+                self.error_message = Some("Every loaded review has a response".to_string());
+            }
+        }
+    }
+
+    // Generation runs from `run_app` so the loading view is drawn first
+    fn start_ai_response(&mut self) -> Option<UIAction> {
+        self.state = AppState::GeneratingAI;
+        self.input_mode = InputMode::AI;
+        self.canned_response = false;
+        Some(UIAction::GenerateAI)
+    }
+
+    async fn generate_into_editor<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<()> {
+        self.loading_message = "Generating AI response...";
+        terminal.draw(|f| self.ui(f))?;
+        let (mut ai_response, failure) = self.generate_ai_response().await;
+        let from_ai = self.ai_generator.is_some() && failure.is_none();
+
+        // Android caps replies, so ask the model to shorten overlong output
+        // rather than leaving the trimming to the user
+        if let Some(limit) = self.editable_character_limit().filter(|_| from_ai) {
+            for _ in 0..MAX_SHORTEN_ATTEMPTS {
+                if text::char_count(&ai_response) <= limit {
+                    break;
+                }
+                self.loading_message = "Shortening response...";
+                terminal.draw(|f| self.ui(f))?;
+                match self.shorten_ai_response(&ai_response, limit).await {
+                    Some(Ok(shorter)) => ai_response = shorter,
+                    // Keep the overlong reply; the editor title flags it
+                    Some(Err(_)) | None => break,
+                }
+            }
+        }
+
+        // Fallback templates don't count as AI drafts
+        self.ai_generated_response = from_ai.then(|| ai_response.clone());
+        self.undo_stack.clear();
+        self.response_text = ai_response;
+        self.cursor_position = self.response_text.len(); // Set cursor at end
+        self.state = AppState::WritingResponse;
+        self.error_message = None;
+        // Shortening can't win against settings that push the other way, so say which
+        if let Some(limit) = self.editable_character_limit().filter(|_| from_ai) {
+            let conflicts = self.ai_config.limit_conflicts();
+            if text::char_count(&self.response_text) > limit && !conflicts.is_empty() {
+                self.error_message = Some(format!(
+                    "This draft is still over the {} character limit.\n\n{}",
+                    limit,
+                    conflicts.join("\n\n")
+                ));
+            }
+        }
+        if let Some(failure) = failure {
+            self.last_error = Some(failure.clone());
+            self.error_message = Some(format!(
+                "{}. Started from the template response instead.",
+                failure
+            ));
+        }
+        self.show_model_fallback();
+
+        Ok(())
+    }
+
+    // Tells the user once per generator that the configured model was swapped out
+    fn show_model_fallback(&mut self) {
+        if self.model_fallback_shown {
+            return;
+        }
+        if let Some(notice) = self.ai_generator.as_ref().and_then(|g| g.model_fallback()) {
+            self.error_message = Some(notice.to_string());
+            self.model_fallback_shown = true;
+        }
+    }
+
+    async fn adjust_tone<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        adjustment: ToneAdjustment,
+    ) -> Result<()> {
+        if self.ai_generator.is_none() {
+            self.error_message = Some("Tone adjustments need AI responses enabled".to_string());
+            return Ok(());
+        }
+        let Some(review_idx) = self.selected_review else {
+            return Ok(());
+        };
+
+        self.loading_message = adjustment.loading_message();
+        self.state = AppState::GeneratingAI;
+        terminal.draw(|f| self.ui(f))?;
+
+        let review = &self.reviews[review_idx];
+        let result = match &self.ai_generator {
+            Some(ai_generator) => {
+                ai_generator
+                    .rewrite_response(
+                        review,
+                        self.reply_language(review),
+                        &self.response_text,
+                        adjustment.instruction(),
+                    )
+                    .await
+            }
+            None => Err(anyhow!("AI responses are disabled")),
+        };
+
+        match result {
+            Ok(adjusted) => {
+                self.ai_generated_response = Some(adjusted.clone());
+                let previous = std::mem::replace(&mut self.response_text, adjusted);
+                self.undo_stack.push(previous);
+                self.cursor_position = self.response_text.len();
+            }
+            Err(e) => {
+                self.report_error(format!("Failed to adjust response: {}", e));
+            }
+        }
+        self.state = AppState::WritingResponse;
+        self.show_model_fallback();
+
+        Ok(())
+    }
+
+    async fn handle_reviews_input(&mut self, key: KeyEvent) -> Result<Option<UIAction>> {
+        match key.code {
+            code if self.config.read_only && RESPONDING_KEYS.contains(&code) => {
+                self.error_message = Some(
+                    "Read-only mode (--read-only): responses can't be written, submitted or deleted."
+                        .to_string(),
+                );
+            }
+            KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.palette_query.clear();
+                self.palette_selected = 0;
+                self.state = AppState::CommandPalette;
+            }
+            KeyCode::Char('t') => {
+                self.reply_in_original_language = !self.reply_in_original_language;
+            }
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.reload_ai_config();
+            }
+            KeyCode::Char('n') => self.select_next_unresponded(),
+            KeyCode::Char('v') => {
+                let current = self
+                    .reviews_layout
+                    .unwrap_or_else(|| ReviewsLayout::for_width(self.reviews_width));
+                self.reviews_layout = Some(current.next());
+            }
+            KeyCode::Char('q') => return Ok(Some(UIAction::Quit)),
+            KeyCode::Char('p') if self.config.auto_refresh.is_some() => {
+                self.auto_refresh_paused = !self.auto_refresh_paused;
+                // Resuming waits a full interval rather than refreshing at once
+                self.last_refresh = Instant::now();
+            }
+            KeyCode::Char('r') => return Ok(Some(UIAction::Refresh)),
+            KeyCode::Char('H') => return Ok(Some(UIAction::HardRefresh)),
+            KeyCode::Char('u') if !self.blocked_offline() => self.refresh_selected_response().await,
+            KeyCode::Char('l') if self.api_client.has_more_reviews() => {
+                return Ok(Some(UIAction::LoadMore));
+            }
+            KeyCode::Up => self.move_selection(-1),
+            KeyCode::Down => self.move_selection(1),
+            KeyCode::PageUp => self.move_selection(-(self.list_page_size() as isize)),
+            KeyCode::PageDown => self.move_selection(self.list_page_size() as isize),
+            KeyCode::Char('s') if self.selected_review.is_some() => {
+                self.state = AppState::TaggingSkip;
+            }
+            KeyCode::Char('f') => self.cycle_skip_filter(),
+            KeyCode::Char('m') => self.toggle_moderation_view(),
+            KeyCode::Char('c') => {
+                self.rating_changes_only = !self.rating_changes_only;
+                self.reselect_visible();
+            }
+            KeyCode::Char('g') => self.toggle_reviewer_grouping(),
+            KeyCode::Char('d') => self.save_debug_bundle(),
+            KeyCode::Char('j') => self.show_raw_json(),
+            KeyCode::Char('i') => self.state = AppState::ViewingStats,
+            KeyCode::Char('R') => self.show_activity(),
+            KeyCode::Char('h') => self.help_visible = !self.help_visible,
+            KeyCode::Char('N') => self.start_note(),
+            KeyCode::Char('F') => self.start_follow_up(),
+            KeyCode::Char('k') => self.start_canned_response(),
+            KeyCode::Char('U') => self.show_follow_ups(),
+            KeyCode::Char('E') => self.show_previous_version(),
+            KeyCode::Char('D') => self.start_deletion(),
+            KeyCode::Char('/') => self.state = AppState::Searching,
+            KeyCode::Esc if !self.search_query.is_empty() => {
+                self.search_query.clear();
+                self.reselect_visible();
+            }
+            KeyCode::Char(' ') => self.text_expanded = !self.text_expanded,
+            KeyCode::Char('e') => self.toggle_reviewer_group(),
+            KeyCode::Char('[') => self.select_same_reviewer(-1),
+            KeyCode::Char(']') => self.select_same_reviewer(1),
+            KeyCode::Enter => {
+                if let Some(review_idx) = self.selected_review {
+                    // Fetch response data for this review
+                    self.loading = true;
+                    match self.load_response(review_idx).await {
+                        Ok(response) => {
+                            use std::io::Write;
+                            let mut log_file = std::fs::OpenOptions::new()
+                                .create(true)
+                                .append(true)
+                                .open("debug.log")
+                                .unwrap_or_else(|_| std::fs::File::create("debug.log").unwrap());
+                            writeln!(
+                                log_file,
+                                "DEBUG: UI received response: {:?}",
+                                response.is_some()
+                            )
+                            .ok();
+                            if let Some(ref resp) = response {
+                                writeln!(
+                                    log_file,
+                                    "DEBUG: Response body preview: {}",
+                                    text::truncate_chars(&resp.response_body, 50)
+                                )
+                                .ok();
                             }
+
+                            self.reviews[review_idx].response = response;
+                            self.state = AppState::WritingResponse;
+                            self.input_mode = InputMode::Manual;
+                            self.canned_response = false;
+                            self.response_text.clear();
+                            self.cursor_position = 0;
+                            self.ai_generated_response = None;
+                            self.undo_stack.clear();
+                            self.error_message = None;
+                        }
+                        Err(e) => {
+                            self.report_error(format!("Failed to fetch response data: {}", e));
+                        }
+                    }
+                    self.loading = false;
+                }
+            }
+            KeyCode::Char('a') => {
+                if let Some(review_idx) = self.selected_review {
+                    if self.config.spam_rules.exclude_from_ai {
+                        if let Some(reasons) =
+                            self.config.spam_rules.classify(&self.reviews[review_idx])
+                        {
+                            self.error_message = Some(format!(
+                                "Not generating an AI response: this review looks like spam ({}). Press Enter to respond manually.",
+                                reasons.join(", ")
+                            ));
+                            return Ok(None);
                         }
                     }
-                    KeyCode::Left => {
-                        if key.modifiers.contains(KeyModifiers::ALT) {
-                            // Option+Left: Jump to previous word
-                            self.cursor_position = self.find_prev_word_boundary();
-                        } else if key.modifiers.contains(KeyModifiers::CONTROL) {
-                            // Cmd+Left: Jump to beginning of line (treat as Home)
-                            self.cursor_position = 0;
-                        } else if self.cursor_position > 0 {
-                            self.cursor_position -= 1;
+
+                    // First fetch response data for this review
+                    self.loading = true;
+                    match self.load_response(review_idx).await {
+                        Ok(response) => {
+                            let already_responded = response.is_some();
+                            self.reviews[review_idx].response = response;
+                            self.loading = false;
+
+                            if already_responded {
+                                // Same warning as the manual editor, but before spending an AI call
+                                self.state = AppState::ConfirmingReplacement;
+                            } else {
+                                return Ok(self.start_ai_response());
+                            }
                         }
-                    }
-                    KeyCode::Right => {
-                        if key.modifiers.contains(KeyModifiers::ALT) {
-                            // Option+Right: Jump to next word
-                            self.cursor_position = self.find_next_word_boundary();
-                        } else if key.modifiers.contains(KeyModifiers::CONTROL) {
-                            // Cmd+Right: Jump to end of line (treat as End)
-                            self.cursor_position = self.response_text.len();
-                        } else if self.cursor_position < self.response_text.len() {
-                            self.cursor_position += 1;
+                        Err(e) => {
+                            self.report_error(format!("Failed to fetch response data: {}", e));
+                            self.loading = false;
                         }
                     }
-                    KeyCode::Home => {
-                        self.cursor_position = 0;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(None)
+    }
+
+    fn binding_available(&self, binding: &KeyBinding) -> bool {
+        !(self.config.read_only && RESPONDING_KEYS.contains(&binding.code))
+    }
+
+    fn palette_matches(&self) -> Vec<&'static KeyBinding> {
+        let mut scored: Vec<(i32, &'static KeyBinding)> = REVIEW_KEYBINDINGS
+            .iter()
+            .filter(|binding| binding.palette && self.binding_available(binding))
+            .filter_map(|binding| {
+                fuzzy_score(&self.palette_query, binding.description).map(|score| (score, binding))
+            })
+            .collect();
+        scored.sort_by_key(|(score, _)| Reverse(*score));
+        scored.into_iter().map(|(_, binding)| binding).collect()
+    }
+
+    async fn handle_palette_input(&mut self, key: KeyEvent) -> Result<Option<UIAction>> {
+        let matches = self.palette_matches();
+
+        match key.code {
+            KeyCode::Esc => {
+                self.state = AppState::ViewingReviews;
+            }
+            KeyCode::Up => {
+                self.palette_selected = self.palette_selected.saturating_sub(1);
+            }
+            KeyCode::Down if self.palette_selected + 1 < matches.len() => {
+                self.palette_selected += 1;
+            }
+            KeyCode::Enter => {
+                if let Some(binding) = matches.get(self.palette_selected) {
+                    // Run the action exactly as if its key had been pressed in the list
+                    self.state = AppState::ViewingReviews;
+                    let key = KeyEvent::new(binding.code, binding.modifiers);
+                    return self.handle_reviews_input(key).await;
+                }
+            }
+            KeyCode::Backspace => {
+                self.palette_query.pop();
+                self.palette_selected = 0;
+            }
+            KeyCode::Char(c) => {
+                self.palette_query.push(c);
+                self.palette_selected = 0;
+            }
+            _ => {}
+        }
+
+        Ok(None)
+    }
+
+    async fn handle_input(&mut self, key: KeyEvent) -> Result<Option<UIAction>> {
+        if self.payload_preview.take().is_some() {
+            return Ok(None);
+        }
+
+        match self.state {
+            AppState::ViewingReviews => return self.handle_reviews_input(key).await,
+            AppState::CommandPalette => return self.handle_palette_input(key).await,
+            AppState::WritingResponse => {
+                if self.input_mode == InputMode::AI {
+                    if let Some(adjustment) = ToneAdjustment::from_key(&key) {
+                        return Ok(Some(UIAction::AdjustTone(adjustment)));
                     }
-                    KeyCode::End => {
+                }
+
+                if key.code == KeyCode::Char('z') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                    if let Some(previous) = self.undo_stack.pop() {
+                        self.response_text = previous;
                         self.cursor_position = self.response_text.len();
                     }
-                    KeyCode::Backspace => {
-                        if key.modifiers.contains(KeyModifiers::ALT) {
-                            // Option+Backspace: Delete previous word
-                            let word_start = self.find_prev_word_boundary();
-                            if word_start < self.cursor_position {
-                                self.response_text.drain(word_start..self.cursor_position);
-                                self.cursor_position = word_start;
-                            }
-                        } else if self.cursor_position > 0 {
-                            self.cursor_position -= 1;
-                            self.response_text.remove(self.cursor_position);
-                        }
+                    return Ok(None);
+                }
+
+                let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+                if (key.code == KeyCode::Char('e') && ctrl) || key.code == KeyCode::Char('\u{0005}')
+                {
+                    return Ok(Some(UIAction::ExternalEditor));
+                }
+
+                if let Some(command) = self.editor_command(&key) {
+                    self.apply_editor_command(command);
+                    return Ok(None);
+                }
+
+                match key.code {
+                    KeyCode::Esc => {
+                        self.state = AppState::ViewingReviews;
+                        self.response_text.clear();
+                        self.cursor_position = 0;
+                        self.ai_generated_response = None;
+                        self.undo_stack.clear();
                     }
-                    KeyCode::Delete => {
-                        if self.cursor_position < self.response_text.len() {
-                            self.response_text.remove(self.cursor_position);
-                        }
+                    KeyCode::Char('s')
+                        if key.modifiers.contains(KeyModifiers::CONTROL)
+                            && !self.response_text.trim().is_empty() =>
+                    {
+                        self.state = AppState::ConfirmingResponse;
+                    }
+                    KeyCode::Enter => {
+                        // Regular Enter adds a new line at cursor position
+                        self.insert_char('\n');
+                    }
+                    KeyCode::Char(c) => {
+                        self.insert_char(c);
+                    }
+                    KeyCode::Left if self.cursor_position > 0 => {
+                        self.cursor_position = self.prev_grapheme_boundary();
+                    }
+                    KeyCode::Right if self.cursor_position < self.response_text.len() => {
+                        self.cursor_position = self.next_grapheme_boundary();
+                    }
+                    // Deletes the whole cluster, so no stray ZWJ or skin tone is left
+                    KeyCode::Backspace if self.cursor_position > 0 => {
+                        let start = self.prev_grapheme_boundary();
+                        self.response_text.drain(start..self.cursor_position);
+                        self.cursor_position = start;
+                    }
+                    KeyCode::Delete if self.cursor_position < self.response_text.len() => {
+                        let end = self.next_grapheme_boundary();
+                        self.response_text.drain(self.cursor_position..end);
                     }
                     _ => {}
                 }
             }
             AppState::ConfirmingResponse => match key.code {
-                KeyCode::Char('y') | KeyCode::Char('Y') => {
+                KeyCode::Char('y') | KeyCode::Char('Y') if self.too_short_message().is_some() => {}
+                KeyCode::Char('y') | KeyCode::Char('Y') if self.config.print_payload => {
                     if let Some(review_idx) = self.selected_review {
-                        let review_id = &self.reviews[review_idx].id;
-                        match self
-                            .api_client
-                            .submit_response(review_id, &self.response_text)
-                            .await
-                        {
-                            Ok(()) => {
-                                self.error_message =
-                                    Some("Response submitted successfully!".to_string());
-                            }
+                        let review = &self.reviews[review_idx];
+                        match self.api_client.preview_submission(
+                            &review.id,
+                            review
+                                .response
+                                .as_ref()
+                                .map(|response| response.id.as_str()),
+                            &self.submission_text(),
+                        ) {
+                            Ok(preview) => self.payload_preview = Some(preview),
                             Err(e) => {
                                 self.error_message =
-                                    Some(format!("Failed to submit response: {}", e));
+                                    Some(format!("Failed to build the request: {}", e))
                             }
                         }
                     }
-                    self.state = AppState::ViewingReviews;
-                    self.response_text.clear();
-                    self.ai_generated_response = None;
+                    // Nothing was sent, so go back to the draft
+                    self.leave_confirmation();
+                }
+                KeyCode::Char('y') | KeyCode::Char('Y') => self.submit(false).await,
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.leave_confirmation();
+                }
+                _ => {}
+            },
+            AppState::ConfirmingOverwrite => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') if !self.queued_overwrites.is_empty() => {
+                    self.finish_queued_overwrite(true).await
                 }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc
+                    if !self.queued_overwrites.is_empty() =>
+                {
+                    self.finish_queued_overwrite(false).await
+                }
+                KeyCode::Char('y') | KeyCode::Char('Y') => self.submit(true).await,
                 KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.leave_confirmation();
+                }
+                _ => {}
+            },
+            AppState::ChoosingCannedResponse => match key.code {
+                KeyCode::Up => self.canned_selected = self.canned_selected.saturating_sub(1),
+                KeyCode::Down => {
+                    self.canned_selected = (self.canned_selected + 1)
+                        .min(self.canned_responses.len().saturating_sub(1));
+                }
+                KeyCode::Enter => self.choose_canned_response(self.canned_selected),
+                KeyCode::Char(c @ '1'..='9') => {
+                    self.choose_canned_response(c as usize - '1' as usize)
+                }
+                KeyCode::Esc | KeyCode::Char('q') => self.state = AppState::ViewingReviews,
+                _ => {}
+            },
+            AppState::SubmitFailed => match key.code {
+                KeyCode::Char('e') | KeyCode::Char('E') => {
+                    let result = self
+                        .email_fallback_url()
+                        .and_then(|url| mail::open_url(&url));
+                    self.error_message = Some(match result {
+                        Ok(()) => format!("Opened an email draft to {}", self.support_email()),
+                        Err(e) => format!("Failed to open your mail client: {}", e),
+                    });
+                    self.leave_submit_failed();
+                }
+                KeyCode::Char('b') | KeyCode::Char('B') if !self.canned_response => {
+                    self.submit_error = None;
                     self.state = AppState::WritingResponse;
                 }
+                KeyCode::Esc => self.leave_submit_failed(),
+                _ => {}
+            },
+            AppState::Searching => match key.code {
+                KeyCode::Enter => self.state = AppState::ViewingReviews,
+                KeyCode::Esc => {
+                    self.search_query.clear();
+                    self.reselect_visible();
+                    self.state = AppState::ViewingReviews;
+                }
+                KeyCode::Backspace => {
+                    self.search_query.pop();
+                    self.reselect_visible();
+                }
+                KeyCode::Up => self.move_selection(-1),
+                KeyCode::Down => self.move_selection(1),
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.search_query.push(c);
+                    self.reselect_visible();
+                }
+                _ => {}
+            },
+            AppState::ConfirmingDeletion => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => self.delete_response().await,
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.state = AppState::ViewingReviews;
+                }
+                _ => {}
+            },
+            AppState::ViewingPreviousVersion => match key.code {
+                KeyCode::Esc | KeyCode::Char('E') | KeyCode::Char('q') => {
+                    self.state = AppState::ViewingReviews;
+                }
+                _ => {}
+            },
+            AppState::ViewingRawJson => match key.code {
+                KeyCode::Up => self.raw_json_scroll = self.raw_json_scroll.saturating_sub(1),
+                KeyCode::Down => self.raw_json_scroll = self.raw_json_scroll.saturating_add(1),
+                KeyCode::PageUp => self.raw_json_scroll = self.raw_json_scroll.saturating_sub(10),
+                KeyCode::PageDown => self.raw_json_scroll = self.raw_json_scroll.saturating_add(10),
+                KeyCode::Home => self.raw_json_scroll = 0,
+                KeyCode::Esc | KeyCode::Char('j') | KeyCode::Char('q') => {
+                    self.state = AppState::ViewingReviews;
+                }
+                _ => {}
+            },
+            AppState::EditingFollowUp => match key.code {
+                KeyCode::Enter => self.save_follow_up(),
+                KeyCode::Esc => {
+                    self.follow_up_input.clear();
+                    self.state = AppState::ViewingReviews;
+                }
+                KeyCode::Backspace => {
+                    self.follow_up_input.pop();
+                }
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.follow_up_input.push(c);
+                }
+                _ => {}
+            },
+            AppState::ViewingFollowUps => match key.code {
+                KeyCode::Up => {
+                    self.follow_ups_selected = self.follow_ups_selected.saturating_sub(1)
+                }
+                KeyCode::Down => {
+                    self.follow_ups_selected = (self.follow_ups_selected + 1)
+                        .min(self.local_state.follow_ups.len().saturating_sub(1));
+                }
+                KeyCode::Enter => self.jump_to_follow_up(),
+                KeyCode::Char('x') => self.complete_follow_up(),
+                KeyCode::Esc | KeyCode::Char('U') | KeyCode::Char('q') => {
+                    self.state = AppState::ViewingReviews;
+                }
+                _ => {}
+            },
+            AppState::EditingNote => match key.code {
+                KeyCode::Enter => self.save_note(),
+                KeyCode::Esc => {
+                    self.note_input.clear();
+                    self.state = AppState::ViewingReviews;
+                }
+                KeyCode::Backspace => {
+                    self.note_input.pop();
+                }
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.note_input.push(c);
+                }
+                _ => {}
+            },
+            AppState::ViewingActivity => match key.code {
+                KeyCode::Up => self.activity_selected = self.activity_selected.saturating_sub(1),
+                KeyCode::Down => {
+                    self.activity_selected = (self.activity_selected + 1)
+                        .min(self.session_responses.len().saturating_sub(1));
+                }
+                KeyCode::Enter => self.jump_to_activity(),
+                KeyCode::Esc | KeyCode::Char('R') | KeyCode::Char('q') => {
+                    self.state = AppState::ViewingReviews;
+                }
+                _ => {}
+            },
+            AppState::ViewingStats => match key.code {
+                KeyCode::Esc | KeyCode::Char('i') | KeyCode::Char('q') => {
+                    self.state = AppState::ViewingReviews;
+                }
+                _ => {}
+            },
+            AppState::ConfirmingReplacement => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => {
+                    return Ok(self.start_ai_response());
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.state = AppState::ViewingReviews;
+                }
+                _ => {}
+            },
+            AppState::TaggingSkip => match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() => {
+                    let choice = c.to_digit(10).unwrap_or(0) as usize;
+                    if choice == 0 {
+                        self.state = AppState::ViewingReviews;
+                        self.tag_selected_review(None);
+                    } else if let Some(&reason) = SKIP_REASONS.get(choice - 1) {
+                        self.state = AppState::ViewingReviews;
+                        self.tag_selected_review(Some(reason));
+                    }
+                }
+                KeyCode::Esc => {
+                    self.state = AppState::ViewingReviews;
+                }
                 _ => {}
             },
             AppState::GeneratingAI => {
@@ -522,32 +3070,390 @@ impl ReviewUI {
         Ok(None)
     }
 
-    async fn generate_ai_response(&self) -> Result<String> {
-        if let Some(ai_generator) = &self.ai_generator {
-            if let Some(review_idx) = self.selected_review {
-                let review = &self.reviews[review_idx];
-                ai_generator.generate_response(review).await
-            } else {
-                Ok("Thank you for your feedback!".to_string())
+    // Sends the draft, or updates the existing response when `replace` is set
+    // or the review is already known to have one
+    async fn submit(&mut self, replace: bool) {
+        let Some(review_idx) = self.selected_review else {
+            return;
+        };
+        if self.config.read_only {
+            return;
+        }
+        let replace = replace || self.reviews[review_idx].response.is_some();
+        let review_id = self.reviews[review_idx].id.clone();
+        let origin_app_id = self.reviews[review_idx].app_id.clone();
+        let response_body = self.submission_text();
+        let ai_usage = self.ai_usage();
+        if self.config.offline {
+            self.queue_response(review_idx, response_body, ai_usage, replace);
+            return;
+        }
+        if self.config.reviews_file.is_some() {
+            self.record_demo_response(review_idx, response_body);
+            return;
+        }
+        let result = if replace {
+            self.api_client
+                .update_response(
+                    &review_id,
+                    origin_app_id.as_deref(),
+                    &response_body,
+                    ai_usage,
+                )
+                .await
+        } else {
+            self.api_client
+                .submit_response(
+                    &review_id,
+                    origin_app_id.as_deref(),
+                    &response_body,
+                    ai_usage,
+                )
+                .await
+        };
+
+        match result {
+            Ok(audit_warning) => {
+                // Track it locally so it no longer counts as unresponded
+                self.reviews[review_idx].response = Some(ReviewResponse {
+                    id: String::new(),
+                    response_body,
+                    last_modified_date: Utc::now(),
+                    state: ResponseState::Pending,
+                });
+                self.record_session_response(review_idx, replace);
+                self.error_message = Some(if replace {
+                    "Response replaced successfully!".to_string()
+                } else {
+                    "Response submitted successfully!".to_string()
+                });
+                if let Some(warning) = audit_warning {
+                    self.report_error(warning);
+                }
+                self.state = AppState::ViewingReviews;
+                self.response_text.clear();
+                self.ai_generated_response = None;
+                self.undo_stack.clear();
             }
+            Err(e) if !replace && e.is::<ResponseExists>() => {
+                // Show the existing response next to the draft before asking
+                if let Ok(Some(existing)) = self.api_client.get_review_response(&review_id).await {
+                    self.reviews[review_idx].response = Some(existing);
+                }
+                self.state = AppState::ConfirmingOverwrite;
+            }
+            Err(e) => {
+                // Keep the draft so it can still be sent by email
+                self.last_error = Some(format!("Failed to submit response: {}", e));
+                self.submit_error = Some(e.to_string());
+                self.state = AppState::SubmitFailed;
+            }
+        }
+    }
+
+    fn queue_response(
+        &mut self,
+        review_idx: usize,
+        response_body: String,
+        ai_usage: AiUsage,
+        replace: bool,
+    ) {
+        let review = &self.reviews[review_idx];
+        // With --both, queue under the app of the store the review came from.
+        // Reviews cached before app ids were recorded fall back to the platform.
+        let app_id = review.app_id.clone().unwrap_or_else(|| {
+            review
+                .platform
+                .and_then(|platform| {
+                    self.api_client
+                        .configs()
+                        .into_iter()
+                        .find(|config| config.platform == platform)
+                })
+                .unwrap_or(&self.config)
+                .app_id
+                .clone()
+        });
+        self.local_state.queue_response(QueuedResponse {
+            app_id,
+            review_id: review.id.clone(),
+            response_body,
+            ai_usage,
+            queued_at: Utc::now(),
+            replace,
+        });
+
+        self.error_message = Some(match self.save_local_state() {
+            Ok(()) => "Working offline: response queued. It's submitted the next time rustpond starts online.".to_string(),
+            Err(e) => format!("Failed to queue the response: {}", e),
+        });
+        self.state = AppState::ViewingReviews;
+        self.response_text.clear();
+        self.ai_generated_response = None;
+        self.undo_stack.clear();
+    }
+
+    // Call once the review's response holds what was sent
+    fn record_session_response(&mut self, review_idx: usize, replaced: bool) {
+        let review = &self.reviews[review_idx];
+        let body = review
+            .response
+            .as_ref()
+            .map(|response| response.response_body.as_str())
+            .unwrap_or_default();
+        self.session_responses.push(SessionResponse {
+            review_id: review.id.clone(),
+            reviewer: review.reviewer_nickname.clone(),
+            responded_at: Utc::now(),
+            snippet: snippet(body),
+            replaced,
+        });
+    }
+
+    fn show_activity(&mut self) {
+        if self.session_responses.is_empty() {
+            self.error_message = Some("No responses sent yet this session".to_string());
+            return;
+        }
+        self.activity_selected = 0;
+        self.state = AppState::ViewingActivity;
+    }
+
+    // Reviews are looked up by id, since a refresh may have reordered them
+    fn jump_to_activity(&mut self) {
+        let Some(entry) = self
+            .session_responses
+            .iter()
+            .rev()
+            .nth(self.activity_selected)
+        else {
+            return;
+        };
+        let review_id = entry.review_id.clone();
+        self.state = AppState::ViewingReviews;
+        match self
+            .reviews
+            .iter()
+            .position(|review| review.id == review_id)
+        {
+            Some(idx) if self.visible_reviews().contains(&idx) => self.select_review(Some(idx)),
+            Some(_) => {
+                self.error_message =
+                    Some("That review is hidden by the current filter or grouping".to_string())
+            }
+            None => {
+                self.error_message = Some("That review is no longer in the loaded list".to_string())
+            }
+        }
+    }
+
+    // Demo reviews aren't real, so the response is only shown as sent
+    fn record_demo_response(&mut self, review_idx: usize, response_body: String) {
+        self.reviews[review_idx].response = Some(ReviewResponse {
+            id: String::new(),
+            response_body,
+            last_modified_date: Utc::now(),
+            state: ResponseState::Pending,
+        });
+        self.record_session_response(review_idx, false);
+        self.error_message =
+            Some("Demo mode (--reviews-file): the response was not submitted.".to_string());
+        self.state = AppState::ViewingReviews;
+        self.response_text.clear();
+        self.cursor_position = 0;
+        self.ai_generated_response = None;
+        self.undo_stack.clear();
+    }
+
+    // Network actions explain themselves instead of failing with --offline
+    // or --reviews-file
+    fn blocked_offline(&mut self) -> bool {
+        if self.config.offline {
+            self.error_message = Some(
+                "Working offline (--offline): showing cached reviews. Restart without --offline to refresh."
+                    .to_string(),
+            );
+        } else if self.config.reviews_file.is_some() {
+            self.error_message = Some(
+                "Demo mode (--reviews-file): reviews come from the file and can't be refreshed."
+                    .to_string(),
+            );
+        }
+        self.config.offline || self.config.reviews_file.is_some()
+    }
+
+    fn start_canned_response(&mut self) {
+        let Some(review_idx) = self.selected_review else {
+            return;
+        };
+        let review = &self.reviews[review_idx];
+        if review.response.is_some() {
+            self.error_message = Some(
+                "This review already has a response. Press Enter to edit or replace it".to_string(),
+            );
+            return;
+        }
+        self.canned_responses = self.ai_config.approved_responses_for(review);
+        if self.canned_responses.is_empty() {
+            self.error_message = Some(format!(
+                "No approved responses for {}-star reviews. Add them under approved_responses in the --ai-config file",
+                review.rating
+            ));
+            return;
+        }
+        self.canned_selected = 0;
+        self.state = AppState::ChoosingCannedResponse;
+    }
+
+    // Skips the editor and goes straight to the confirmation screen
+    fn choose_canned_response(&mut self, idx: usize) {
+        let Some(response) = self.canned_responses.get(idx) else {
+            return;
+        };
+        self.response_text = response.clone();
+        self.cursor_position = self.response_text.len();
+        self.input_mode = InputMode::Manual;
+        self.ai_generated_response = None;
+        self.undo_stack.clear();
+        self.canned_response = true;
+        self.error_message = None;
+        self.state = AppState::ConfirmingResponse;
+    }
+
+    // Approved responses can't be edited, so declining drops them
+    fn leave_confirmation(&mut self) {
+        if self.canned_response {
+            self.canned_response = false;
+            self.leave_submit_failed();
         } else {
-            // Fallback to simple response if no AI available
-            if let Some(review_idx) = self.selected_review {
-                let review = &self.reviews[review_idx];
-                let response = format!(
-                    "Thank you for your {}-star review{}! We appreciate your feedback and are constantly working to improve our app.",
-                    review.rating,
-                    if let Some(title) = &review.title {
-                        format!(" about \"{}\"", title)
-                    } else {
-                        String::new()
-                    }
-                );
-                Ok(response)
-            } else {
-                Ok("Thank you for your feedback!".to_string())
+            self.state = AppState::WritingResponse;
+        }
+    }
+
+    fn leave_submit_failed(&mut self) {
+        self.submit_error = None;
+        self.state = AppState::ViewingReviews;
+        self.response_text.clear();
+        self.ai_generated_response = None;
+        self.undo_stack.clear();
+    }
+
+    // Drafts an email with the review and the rejected response, so it can be
+    // handled outside the store
+    fn email_fallback_url(&self) -> Result<String> {
+        let review = &self.reviews[self
+            .selected_review
+            .ok_or_else(|| anyhow!("No review selected"))?];
+
+        let subject = format!(
+            "Review response for {} ({}-star review by {})",
+            self.config.app_id, review.rating, review.reviewer_nickname
+        );
+        let body = format!(
+            "The store rejected this response: {}\n\n\
+             Review id: {}\n\
+             Rating: {}/5\n\
+             Reviewer: {}\n\
+             Date: {}\n\
+             Territory: {}\n\
+             Title: {}\n\
+             Review: {}\n\n\
+             Drafted response:\n{}\n",
+            self.submit_error.as_deref().unwrap_or("unknown error"),
+            review.id,
+            review.rating,
+            review.reviewer_nickname,
+            self.detail_date(&review.created_date, review),
+            review.territory,
+            review.title.as_deref().unwrap_or("(No title)"),
+            review.body.as_deref().unwrap_or("(No review text)"),
+            self.submission_text()
+        );
+
+        Ok(mail::mailto_url(self.support_email(), &subject, &body))
+    }
+
+    // The selected review's territory can override the support address
+    fn support_email(&self) -> &str {
+        match self.selected_review {
+            Some(idx) => self.ai_config.support_email_for(&self.reviews[idx]),
+            None => &self.ai_config.support_email,
+        }
+    }
+
+    // Language the AI should reply in. Translated reviews can be answered in
+    // either the reviewer's original language or the translation language.
+    fn reply_language<'a>(&'a self, review: &'a Review) -> Option<&'a str> {
+        if self.reply_in_original_language && review.original_text.is_some() {
+            review.language.as_deref()
+        } else {
+            self.config.translation_language.as_deref()
+        }
+    }
+
+    async fn shorten_ai_response(&self, previous: &str, limit: usize) -> Option<Result<String>> {
+        let ai_generator = self.ai_generator.as_ref()?;
+        let review = &self.reviews[self.selected_review?];
+        Some(
+            ai_generator
+                .shorten_response(review, self.reply_language(review), previous, limit)
+                .await,
+        )
+    }
+
+    // Tries the AI up to `--ai-attempts` times within `--ai-timeout-secs`, so 'a'
+    // always ends in something editable. When the AI gave up, the template
+    // response comes back with the reason.
+    async fn generate_ai_response(&self) -> (String, Option<String>) {
+        let Some(review_idx) = self.selected_review else {
+            return ("Thank you for your feedback!".to_string(), None);
+        };
+        let review = &self.reviews[review_idx];
+        let Some(ai_generator) = &self.ai_generator else {
+            return (self.template_response(review), None);
+        };
+
+        let attempts = self.config.ai_attempts;
+        let generate = async {
+            let mut last_error = None;
+            for _ in 0..attempts {
+                match ai_generator
+                    .generate_response(review, self.reply_language(review))
+                    .await
+                {
+                    Ok(response) => return Ok(response),
+                    Err(e) => last_error = Some(e),
+                }
             }
+            Err(last_error.unwrap_or_else(|| anyhow!("no attempts were made")))
+        };
+
+        let failure = match tokio::time::timeout(self.config.ai_timeout, generate).await {
+            Ok(Ok(response)) => return (response, None),
+            Ok(Err(e)) if attempts == 1 => format!("The AI response failed: {}", e),
+            Ok(Err(e)) => format!("The AI response failed {} times: {}", attempts, e),
+            Err(_) => format!(
+                "The AI response took longer than {} seconds",
+                self.config.ai_timeout.as_secs()
+            ),
+        };
+        (self.template_response(review), Some(failure))
+    }
+
+    // A configured fallback for the rating, or a generic thank-you
+    fn template_response(&self, review: &Review) -> String {
+        if let Some(response) = self.ai_config.fallback_response(review) {
+            return response;
         }
+        format!(
+            "Thank you for your {}-star review{}! We appreciate your feedback and are constantly working to improve our app.",
+            review.rating,
+            if let Some(title) = &review.title {
+                format!(" about \"{}\"", title)
+            } else {
+                String::new()
+            }
+        )
     }
 
     fn ui<B: Backend>(&mut self, f: &mut Frame<B>) {
@@ -558,6 +3464,88 @@ impl ReviewUI {
             AppState::WritingResponse => self.draw_response_view(f, size),
             AppState::ConfirmingResponse => self.draw_confirmation_view(f, size),
             AppState::GeneratingAI => self.draw_loading_view(f, size),
+            AppState::CommandPalette => {
+                self.draw_reviews_view(f, size);
+                self.draw_command_palette(f, size);
+            }
+            AppState::ConfirmingReplacement => {
+                self.draw_reviews_view(f, size);
+                self.draw_replacement_prompt(f, size);
+            }
+            AppState::TaggingSkip => {
+                self.draw_reviews_view(f, size);
+                self.draw_skip_prompt(f, size);
+            }
+            AppState::SubmitFailed => {
+                self.draw_reviews_view(f, size);
+                self.draw_submit_failed_prompt(f, size);
+            }
+            AppState::ConfirmingOverwrite => {
+                self.draw_response_view(f, size);
+                self.draw_overwrite_prompt(f, size);
+            }
+            AppState::ViewingRawJson => {
+                self.draw_reviews_view(f, size);
+                self.draw_raw_json(f, size);
+            }
+            AppState::ViewingPreviousVersion => {
+                self.draw_reviews_view(f, size);
+                self.draw_previous_version(f, size);
+            }
+            AppState::ConfirmingDeletion => {
+                self.draw_reviews_view(f, size);
+                self.draw_deletion_prompt(f, size);
+            }
+            AppState::Searching => {
+                self.draw_reviews_view(f, size);
+                self.draw_search_box(f, size);
+            }
+            AppState::ViewingStats => {
+                self.draw_reviews_view(f, size);
+                self.draw_stats(f, size);
+            }
+            AppState::ViewingActivity => {
+                self.draw_reviews_view(f, size);
+                self.draw_activity(f, size);
+            }
+            AppState::EditingNote => {
+                self.draw_reviews_view(f, size);
+                self.draw_note_prompt(f, size);
+            }
+            AppState::ChoosingCannedResponse => {
+                self.draw_reviews_view(f, size);
+                self.draw_canned_responses(f, size);
+            }
+            AppState::EditingFollowUp => {
+                self.draw_reviews_view(f, size);
+                self.draw_follow_up_prompt(f, size);
+            }
+            AppState::ViewingFollowUps => {
+                self.draw_reviews_view(f, size);
+                self.draw_follow_ups(f, size);
+            }
+        }
+
+        if let Some(preview) = &self.payload_preview {
+            let popup_area = centered_rect(80, 70, size);
+            f.render_widget(Clear, popup_area);
+            let preview_paragraph = Paragraph::new(preview.as_ref())
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Request preview (--print-payload, not sent) - press any key"),
+                )
+                .wrap(Wrap { trim: false });
+            f.render_widget(preview_paragraph, popup_area);
+        }
+
+        if let Some(progress) = &self.fetch_progress {
+            let popup_area = centered_rect(60, 20, size);
+            f.render_widget(Clear, popup_area);
+            let progress_paragraph = Paragraph::new(progress.as_ref())
+                .block(Block::default().borders(Borders::ALL).title("Refreshing"))
+                .wrap(Wrap { trim: true });
+            f.render_widget(progress_paragraph, popup_area);
         }
 
         // Draw error message if present
@@ -575,65 +3563,292 @@ impl ReviewUI {
     }
 
     fn draw_reviews_view<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
-        // Create a layout that properly separates content from help
+        // Create a layout that properly separates content from help. A hidden
+        // help panel gives its rows to the reviews.
+        let help_height = if self.help_visible { 8 } else { 0 };
         let main_chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Min(10),   // Main content area (reviews)
-                Constraint::Length(8), // Help section (fixed height)
+                Constraint::Min(10),             // Main content area (reviews)
+                Constraint::Length(help_height), // Help section (fixed height)
             ])
             .split(area);
 
         // Split the main content area for reviews
-        let content_chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(main_chunks[0]);
+        self.reviews_width = area.width;
+        let layout = self
+            .reviews_layout
+            .unwrap_or_else(|| ReviewsLayout::for_width(area.width));
+        let (list_area, details_area) = match layout {
+            ReviewsLayout::ListOnly => (Some(main_chunks[0]), None),
+            ReviewsLayout::DetailsOnly => (None, Some(main_chunks[0])),
+            ReviewsLayout::Split => {
+                let list_percent = if area.width >= WIDE_TERMINAL_WIDTH {
+                    30
+                } else {
+                    50
+                };
+                let content_chunks = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([
+                        Constraint::Percentage(list_percent),
+                        Constraint::Percentage(100 - list_percent),
+                    ])
+                    .split(main_chunks[0]);
+                (Some(content_chunks[0]), Some(content_chunks[1]))
+            }
+        };
 
-        // Reviews list
-        let reviews: Vec<ListItem> = self
-            .reviews
+        // Reviews list. Scroll one row at a time, only when the selection
+        // leaves the screen.
+        let visible = self.visible_reviews();
+        self.list_rows = list_area
+            .map(|area| area.height.saturating_sub(2).max(1) as usize)
+            .unwrap_or(self.list_rows);
+        let selected_row = self
+            .list_state
+            .selected()
+            .filter(|&row| row < visible.len());
+        if let Some(row) = selected_row {
+            if row < self.list_offset {
+                self.list_offset = row;
+            } else if row >= self.list_offset + self.list_rows {
+                self.list_offset = row + 1 - self.list_rows;
+            }
+        }
+        self.list_offset = self
+            .list_offset
+            .min(visible.len().saturating_sub(self.list_rows));
+        let filtered = self.filtered_reviews();
+        let group_counts = self.reviewer_counts(&filtered);
+        let mut listed_reviewers = HashSet::new();
+        let today = Local::now().date_naive();
+        // Older reviews in an expanded group are indented under the newest.
+        // This needs every row before the window, but only looks at nicknames.
+        let group_members: Vec<bool> = visible
             .iter()
-            .enumerate()
-            .map(|(_i, review)| {
-                let rating_stars = "⭐".repeat(review.rating as usize);
-                let content = format!(
-                    "{} {} - {}",
+            .map(|&idx| {
+                self.group_by_reviewer
+                    && reviewer_key(&self.reviews[idx])
+                        .is_some_and(|nickname| !listed_reviewers.insert(nickname))
+            })
+            .collect();
+        let window_end = (self.list_offset + self.list_rows).min(visible.len());
+        let reviews: Vec<ListItem> = visible[self.list_offset..window_end]
+            .iter()
+            .zip(&group_members[self.list_offset..window_end])
+            .map(|(&idx, &is_group_member)| {
+                let review = &self.reviews[idx];
+                let nickname = reviewer_key(review);
+                let group_size = nickname
+                    .and_then(|nickname| group_counts.get(nickname))
+                    .copied()
+                    .unwrap_or(1);
+                let rating_stars = review.stars();
+                let spam_marker = if self.config.spam_rules.classify(review).is_some() {
+                    "🚩 "
+                } else {
+                    ""
+                };
+                let sensitive_marker = if self.config.sensitive_terms.matches(review).is_empty() {
+                    ""
+                } else {
+                    "⚠ "
+                };
+                let platform_badge = review
+                    .platform
+                    .map(|platform| format!("[{}] ", platform.badge()))
+                    .unwrap_or_default();
+                let rating_change = self
+                    .local_state
+                    .rating_changes
+                    .get(&review.id)
+                    .map(|change| format!(" ({}★ → {}★)", change.from, change.to))
+                    .unwrap_or_default();
+                let mut content = format!(
+                    "{}{}{}{}{}{} {} - {}",
+                    if is_group_member { "  ↳ " } else { "" },
+                    platform_badge,
+                    sensitive_marker,
+                    spam_marker,
                     rating_stars,
+                    rating_change,
                     review.reviewer_nickname,
-                    review.created_date.format("%Y-%m-%d")
+                    self.list_date(&review.created_date, review)
                 );
+                if self.group_by_reviewer && !is_group_member && group_size > 1 {
+                    content.push_str(&format!(" ({} reviews)", group_size));
+                }
+                if let Some(reason) = self.local_state.skip_reason(&review.id) {
+                    content.push_str(&format!(" [skipped: {}]", reason));
+                }
+                if self.local_state.review_edit(&review.id).is_some() {
+                    content.push_str(" ✎ edited");
+                }
+                if self.local_state.note(&review.id).is_some() {
+                    content.push_str(" 📝");
+                }
+                if let Some(follow_up) = self.local_state.follow_up(&review.id) {
+                    content.push_str(if follow_up.due <= today {
+                        " ⏰ due"
+                    } else {
+                        " ⏰"
+                    });
+                }
                 ListItem::new(content)
             })
             .collect();
 
+        let mut filter_text = match self.skip_filter {
+            Some(reason) => format!(", showing skipped: {}", reason),
+            None => String::new(),
+        };
+        if let Some(label) = self.auto_refresh_label() {
+            filter_text.push_str(&format!(", {}", label));
+        }
+        if self.moderation_view {
+            filter_text.push_str(&format!(
+                ", {} awaiting moderation, 'r' to re-check",
+                visible.len()
+            ));
+        }
+        if visible.len() > self.list_rows {
+            filter_text.push_str(&format!(
+                ", rows {}-{} of {}",
+                self.list_offset + 1,
+                window_end,
+                visible.len()
+            ));
+        }
+        if self.rating_changes_only {
+            filter_text.push_str(", rating changed only");
+        }
+        if !self.search_query.is_empty() {
+            filter_text.push_str(&format!(
+                ", {} matching \"{}\", Esc to clear",
+                visible.len(),
+                self.search_query
+            ));
+        }
+        if self.group_by_reviewer {
+            filter_text.push_str(", grouped by nickname (heuristic), 'e' to expand");
+        }
+        if !self.help_visible {
+            filter_text.push_str(", 'h' for help");
+        }
         let reviews_list = List::new(reviews)
-            .block(Block::default().borders(Borders::ALL).title("Reviews"))
-            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
-            .highlight_symbol(">> ");
+            .block(Block::default().borders(Borders::ALL).title(format!(
+                "{}{}{}Reviews ({} unresponded, 'n' for next{})",
+                if self.config.offline {
+                    "[OFFLINE] "
+                } else if self.config.reviews_file.is_some() {
+                    "[DEMO] "
+                } else {
+                    ""
+                },
+                if self.config.read_only {
+                    "[READ-ONLY] "
+                } else {
+                    ""
+                },
+                match self.cached_since {
+                    Some(fetched_at) => format!(
+                        "[CACHED {}{}] ",
+                        fetched_at.format("%Y-%m-%d %H:%M UTC"),
+                        if self.loading || self.background_refresh.is_some() {
+                            ", refreshing"
+                        } else {
+                            ""
+                        }
+                    ),
+                    None => String::new(),
+                },
+                self.unresponded_count(),
+                filter_text
+            )))
+            .highlight_style(self.config.highlight_style)
+            .highlight_symbol(&self.config.highlight_symbol);
 
-        f.render_stateful_widget(reviews_list, content_chunks[0], &mut self.list_state);
+        // The selection and scroll offset are kept while the list is hidden
+        if let Some(list_area) = list_area {
+            let mut window_state = ListState::default();
+            window_state.select(selected_row.map(|row| row - self.list_offset));
+            f.render_stateful_widget(reviews_list, list_area, &mut window_state);
+        }
 
         // Review details
-        if let Some(review_idx) = self.selected_review {
+        if let (Some(review_idx), Some(details_area)) = (self.selected_review, details_area) {
             let review = &self.reviews[review_idx];
-            let rating_stars = "⭐".repeat(review.rating as usize);
+            let rating_stars = review.stars();
+            let search = self.search_query.to_lowercase();
 
             let mut text = vec![
                 Spans::from(vec![Span::styled(
                     format!("Rating: {}", rating_stars),
                     Style::default().fg(Color::Yellow),
                 )]),
-                Spans::from(vec![Span::raw(format!(
-                    "Reviewer: {}",
-                    review.reviewer_nickname
-                ))]),
+                Spans::from(
+                    std::iter::once(Span::raw("Reviewer: "))
+                        .chain(highlight_matches(
+                            &review.reviewer_nickname,
+                            &search,
+                            Style::default(),
+                        ))
+                        .collect::<Vec<_>>(),
+                ),
+            ];
+
+            if let Some(change) = self.local_state.rating_changes.get(&review.id) {
+                text.push(Spans::from(vec![Span::styled(
+                    format!(
+                        "Rating changed: {}★ → {}★ (noticed {})",
+                        change.from,
+                        change.to,
+                        self.list_date(&change.detected_at, review)
+                    ),
+                    Style::default().fg(if change.to > change.from {
+                        Color::Green
+                    } else {
+                        Color::Red
+                    }),
+                )]));
+            }
+
+            if let Some(edit) = self.local_state.review_edit(&review.id) {
+                text.push(Spans::from(vec![Span::styled(
+                    format!(
+                        "✎ Edited by the reviewer (noticed {}), 'E' to show the previous version",
+                        self.list_date(&edit.detected_at, review)
+                    ),
+                    Style::default().fg(Color::Magenta),
+                )]));
+            }
+
+            let same_nickname = reviewer_key(review)
+                .map(|nickname| {
+                    self.reviews
+                        .iter()
+                        .filter(|other| reviewer_key(other) == Some(nickname))
+                        .count()
+                })
+                .unwrap_or(1);
+            if same_nickname > 1 {
+                text.push(Spans::from(vec![Span::styled(
+                    format!(
+                        "{} loaded reviews use this nickname (possibly different people), '['/']' to step through them",
+                        same_nickname
+                    ),
+                    Style::default().fg(Color::Cyan),
+                )]));
+            }
+
+            text.extend([
                 Spans::from(vec![Span::raw(format!(
                     "Date: {}",
-                    review.created_date.format("%Y-%m-%d %H:%M")
+                    self.detail_date(&review.created_date, review)
                 ))]),
                 Spans::from(vec![Span::raw(format!("Territory: {}", review.territory))]),
-            ];
+            ]);
 
             // Add version info if available
             if let Some(version) = &review.version {
@@ -643,22 +3858,128 @@ impl ReviewUI {
                 ))]));
             }
 
+            if review.thumbs_up.is_some() || review.thumbs_down.is_some() {
+                text.push(Spans::from(vec![Span::raw(format!(
+                    "Helpful: 👍 {}  👎 {}",
+                    review.thumbs_up.unwrap_or(0),
+                    review.thumbs_down.unwrap_or(0)
+                ))]));
+            }
+
+            if let Some(reasons) = self.config.spam_rules.classify(review) {
+                text.push(Spans::from(vec![Span::styled(
+                    format!("🚩 Likely spam: {}", reasons.join(", ")),
+                    Style::default().fg(Color::Red),
+                )]));
+            }
+
+            let sensitive = self.config.sensitive_terms.matches(review);
+            if !sensitive.is_empty() {
+                text.push(Spans::from(vec![Span::styled(
+                    format!("⚠ Contains sensitive terms: {}", sensitive.join(", ")),
+                    Style::default().fg(Color::Yellow),
+                )]));
+            }
+
+            if let Some(skipped) = self.local_state.skipped.get(&review.id) {
+                text.push(Spans::from(vec![Span::styled(
+                    format!(
+                        "Skipped: {} ({})",
+                        skipped.reason,
+                        self.list_date(&skipped.skipped_at, review)
+                    ),
+                    Style::default().fg(Color::Magenta),
+                )]));
+            }
+
+            if let Some(note) = self.local_state.note(&review.id) {
+                text.push(Spans::from(vec![Span::styled(
+                    format!(
+                        "📝 Note ({}): {}",
+                        self.list_date(&note.updated_at, review),
+                        note.text
+                    ),
+                    Style::default().fg(Color::Cyan),
+                )]));
+            }
+
+            if let Some(follow_up) = self.local_state.follow_up(&review.id) {
+                let due = follow_up.due <= Local::now().date_naive();
+                text.push(Spans::from(vec![Span::styled(
+                    format!(
+                        "⏰ Follow up {}{}{}",
+                        follow_up.due.format(&self.config.date_format),
+                        if due { " (due)" } else { "" },
+                        if follow_up.note.is_empty() {
+                            String::new()
+                        } else {
+                            format!(": {}", follow_up.note)
+                        }
+                    ),
+                    Style::default().fg(if due { Color::Red } else { Color::Cyan }),
+                )]));
+            }
+
             text.push(Spans::from(vec![Span::raw("")]));
 
             if let Some(title) = &review.title {
+                let bold = Style::default().add_modifier(Modifier::BOLD);
+                text.push(Spans::from(
+                    std::iter::once(Span::styled("Title: ", bold))
+                        .chain(highlight_matches(title, &search, bold))
+                        .collect::<Vec<_>>(),
+                ));
+            }
+
+            if let Some(body) = &review.body {
+                text.push(Spans::from(vec![Span::raw("")]));
                 text.push(Spans::from(vec![Span::styled(
-                    format!("Title: {}", title),
+                    if review.original_text.is_some() {
+                        "Review (translated):"
+                    } else {
+                        "Review:"
+                    },
                     Style::default().add_modifier(Modifier::BOLD),
                 )]));
+                let (preview, full_length) = self.preview_text(body);
+                text.push(Spans::from(highlight_matches(
+                    &preview,
+                    &search,
+                    Style::default(),
+                )));
+                if let Some(length) = full_length {
+                    text.push(expand_hint(length));
+                }
             }
 
-            if let Some(body) = &review.body {
+            if let Some(original) = &review.original_text {
                 text.push(Spans::from(vec![Span::raw("")]));
                 text.push(Spans::from(vec![Span::styled(
-                    "Review:",
+                    format!(
+                        "Original ({}):",
+                        review.language.as_deref().unwrap_or("unknown language")
+                    ),
                     Style::default().add_modifier(Modifier::BOLD),
                 )]));
-                text.push(Spans::from(vec![Span::raw(body)]));
+                let (preview, full_length) = self.preview_text(original);
+                text.push(Spans::from(vec![Span::raw(preview)]));
+                if let Some(length) = full_length {
+                    text.push(expand_hint(length));
+                }
+                text.push(Spans::from(vec![Span::styled(
+                    format!(
+                        "AI replies in: {} (press 't' to switch)",
+                        if self.reply_in_original_language {
+                            review.language.as_deref().unwrap_or("original language")
+                        } else {
+                            self.config
+                                .translation_language
+                                .as_deref()
+                                .unwrap_or("translation language")
+                        }
+                    ),
+                    Style::default().fg(Color::Cyan),
+                )]));
             }
 
             // Show existing response if available
@@ -677,10 +3998,31 @@ impl ReviewUI {
                 text.push(Spans::from(vec![Span::styled(
                     format!(
                         "Responded: {}",
-                        response.last_modified_date.format("%Y-%m-%d %H:%M")
+                        self.detail_date(&response.last_modified_date, review)
                     ),
                     Style::default().fg(Color::Gray),
                 )]));
+                let state_color = match response.state {
+                    ResponseState::Published => Color::Gray,
+                    ResponseState::Pending => Color::Yellow,
+                    ResponseState::Other(_) => Color::Red,
+                };
+                text.push(Spans::from(vec![Span::styled(
+                    format!("Status: {}", response.state.label()),
+                    Style::default().fg(state_color),
+                )]));
+            } else if self.local_state.is_queued(&review.id) {
+                text.push(Spans::from(vec![Span::raw("")]));
+                text.push(Spans::from(vec![Span::styled(
+                    "⏳ Response queued, submitted the next time rustpond starts online",
+                    Style::default().fg(Color::Yellow),
+                )]));
+            } else if self.config.read_only {
+                text.push(Spans::from(vec![Span::raw("")]));
+                text.push(Spans::from(vec![Span::styled(
+                    "No developer response yet",
+                    Style::default().fg(Color::Yellow),
+                )]));
             } else {
                 text.push(Spans::from(vec![Span::raw("")]));
                 text.push(Spans::from(vec![Span::styled(
@@ -697,21 +4039,30 @@ impl ReviewUI {
                 )
                 .wrap(Wrap { trim: true });
 
-            f.render_widget(review_detail, content_chunks[1]);
+            f.render_widget(review_detail, details_area);
+        }
+
+        if !self.help_visible {
+            return;
         }
 
         // Instructions in separate area (opaque background)
-        let help_text = vec![
-            Spans::from("Controls:"),
-            Spans::from("↑/↓ - Navigate reviews"),
-            Spans::from("Enter - Write manual response"),
-            Spans::from("'a' - Generate AI response"),
-            Spans::from("'r' - Refresh reviews"),
-            Spans::from("'l' - Load more reviews (Android)"),
-            Spans::from("'q' - Quit"),
-        ];
+        let mut help_spans = vec![Span::styled(
+            "Controls: ",
+            Style::default().add_modifier(Modifier::BOLD),
+        )];
+        for binding in REVIEW_KEYBINDINGS
+            .iter()
+            .filter(|binding| self.binding_available(binding))
+        {
+            help_spans.push(Span::styled(
+                binding.label,
+                Style::default().fg(Color::White),
+            ));
+            help_spans.push(Span::raw(format!(" - {}   ", binding.description)));
+        }
 
-        let help_paragraph = Paragraph::new(help_text)
+        let help_paragraph = Paragraph::new(Spans::from(help_spans))
             .block(Block::default().borders(Borders::ALL).title("Help"))
             .style(Style::default().fg(Color::Gray).bg(Color::Black))
             .wrap(Wrap { trim: true });
@@ -727,15 +4078,23 @@ impl ReviewUI {
                     Constraint::Length(8), // Original review
                     Constraint::Length(6), // Existing response (if any)
                     Constraint::Min(8),    // Your response input
+                    Constraint::Length(3), // Editor keys
                 ]
                 .as_ref(),
             )
             .split(area);
 
+        let editor_help = Paragraph::new(self.editor_help_text())
+            .block(Block::default().borders(Borders::ALL).title("Editor Keys"))
+            .style(Style::default().fg(Color::Gray))
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(editor_help, chunks[3]);
+
         // Show current review at the top
         if let Some(review_idx) = self.selected_review {
             let review = &self.reviews[review_idx];
-            let rating_stars = "⭐".repeat(review.rating as usize);
+            let rating_stars = review.stars();
 
             let review_text = vec![
                 Spans::from(vec![Span::styled(
@@ -750,9 +4109,9 @@ impl ReviewUI {
                     review.title.as_deref().unwrap_or("(No title)"),
                     Style::default().add_modifier(Modifier::BOLD),
                 )]),
-                Spans::from(vec![Span::raw(
+                Spans::from(vec![Span::raw(self.display_text(
                     review.body.as_deref().unwrap_or("(No review text)"),
-                )]),
+                ))]),
             ];
 
             let review_paragraph = Paragraph::new(review_text)
@@ -779,7 +4138,7 @@ impl ReviewUI {
                     Spans::from(vec![Span::styled(
                         format!(
                             "Sent: {}",
-                            response.last_modified_date.format("%Y-%m-%d %H:%M")
+                            self.detail_date(&response.last_modified_date, review)
                         ),
                         Style::default().fg(Color::Gray),
                     )]),
@@ -793,118 +4152,669 @@ impl ReviewUI {
                     )
                     .wrap(Wrap { trim: true });
 
-                f.render_widget(response_paragraph, chunks[1]);
+                f.render_widget(response_paragraph, chunks[1]);
+
+                // Response input (smaller since existing response is shown)
+                let input_title = if let Some(budget) = self.character_budget_label() {
+                    format!(
+                        "⚠️  Update/Replace Response ({} - Ctrl+S to submit, Esc to cancel)",
+                        budget
+                    )
+                } else {
+                    "⚠️  Update/Replace Response (Ctrl+S to submit, Esc to cancel)".to_string()
+                };
+                let display_text = self.format_text_with_cursor();
+                let response_input = Paragraph::new(display_text.as_ref())
+                    .block(Block::default().borders(Borders::ALL).title(input_title))
+                    .wrap(Wrap { trim: true });
+
+                f.render_widget(response_input, chunks[2]);
+            } else {
+                // No existing response - show larger input area
+                let empty_text = vec![Spans::from(vec![Span::styled(
+                    "✅ No existing response - you can write a new one",
+                    Style::default().fg(Color::Green),
+                )])];
+
+                let no_response_paragraph = Paragraph::new(empty_text)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .title("Response Status"),
+                    )
+                    .wrap(Wrap { trim: true });
+
+                f.render_widget(no_response_paragraph, chunks[1]);
+
+                let input_title = match self.input_mode {
+                    InputMode::Manual => {
+                        if let Some(budget) = self.character_budget_label() {
+                            format!(
+                                "Write Response ({} - Ctrl+S to submit, Esc to cancel)",
+                                budget
+                            )
+                        } else {
+                            "Write Response (Ctrl+S to submit, Esc to cancel)".to_string()
+                        }
+                    },
+                    InputMode::AI => {
+                        if let Some(budget) = self.character_budget_label() {
+                            format!("AI Generated Response ({} - Edit if needed, Alt+1/2/3 shorter/warmer/apologetic, Ctrl+Z undo, Ctrl+S to submit, Esc to cancel)", budget)
+                        } else {
+                            "AI Generated Response (Edit if needed, Alt+1/2/3 shorter/warmer/apologetic, Ctrl+Z undo, Ctrl+S to submit, Esc to cancel)".to_string()
+                        }
+                    }
+                };
+
+                let display_text = self.format_text_with_cursor();
+                let response_input = Paragraph::new(display_text.as_ref())
+                    .block(Block::default().borders(Borders::ALL).title(input_title))
+                    .wrap(Wrap { trim: true });
+
+                f.render_widget(response_input, chunks[2]);
+            }
+        }
+    }
+
+    fn draw_confirmation_view<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let popup_area = centered_rect(80, 60, area);
+        f.render_widget(Clear, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Min(5),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(popup_area);
+
+        // Confirmation prompt, replaced by the reason when submission is blocked
+        let (prompt, prompt_style) = match self.too_short_message() {
+            Some(message) => (message, Style::default().fg(Color::Red)),
+            None if self.canned_response => (
+                "Submit this approved response? (y/n, n discards it)".to_string(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            None => (
+                "Submit this response? (y/n)".to_string(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        };
+        let confirmation = Paragraph::new(prompt)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Confirm Response"),
+            )
+            .style(prompt_style)
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(confirmation, chunks[0]);
+
+        // Response preview, including anything appended on submit. Lines aren't
+        // trimmed so breaks and indentation look the way the store shows them.
+        let preview_text = self.submission_text();
+        let line_count = preview_text.lines().count();
+        let response_preview = Paragraph::new(preview_text.as_ref())
+            .block(Block::default().borders(Borders::ALL).title(format!(
+                "Response Preview ({} {}, line breaks are kept as shown)",
+                line_count,
+                if line_count == 1 { "line" } else { "lines" }
+            )))
+            .wrap(Wrap { trim: false });
+
+        f.render_widget(response_preview, chunks[1]);
+
+        // Instructions
+        let instructions = Paragraph::new(if self.config.print_payload {
+            "Press 'y' to preview the request without sending it, 'n' or Esc to go back"
+        } else {
+            "Press 'y' to submit, 'n' or Esc to go back"
+        })
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().fg(Color::Gray));
+
+        f.render_widget(instructions, chunks[2]);
+    }
+
+    fn draw_command_palette<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let popup_area = centered_rect(60, 50, area);
+        f.render_widget(Clear, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(3)].as_ref())
+            .split(popup_area);
+
+        let query = Paragraph::new(format!("> {}█", self.palette_query)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Command Palette (Enter to run, Esc to close)"),
+        );
+
+        f.render_widget(query, chunks[0]);
+
+        let matches = self.palette_matches();
+        let items: Vec<ListItem> = matches
+            .iter()
+            .map(|binding| {
+                ListItem::new(Spans::from(vec![
+                    Span::raw(binding.description),
+                    Span::styled(
+                        format!("  ({})", binding.label),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]))
+            })
+            .collect();
+
+        let mut palette_state = ListState::default();
+        if !matches.is_empty() {
+            palette_state.select(Some(self.palette_selected));
+        }
+
+        let actions = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title("Actions"))
+            .highlight_style(self.config.highlight_style)
+            .highlight_symbol(&self.config.highlight_symbol);
+
+        f.render_stateful_widget(actions, chunks[1], &mut palette_state);
+    }
+
+    fn draw_replacement_prompt<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let popup_area = centered_rect(60, 20, area);
+        f.render_widget(Clear, popup_area);
+
+        let prompt = Paragraph::new(vec![
+            Spans::from(Span::styled(
+                "⚠️  This review already has a response — generate a replacement? (y/n)",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            Spans::from(""),
+            Spans::from(Span::styled(
+                "Press 'y' to generate an AI replacement, 'n' or Esc to cancel",
+                Style::default().fg(Color::Gray),
+            )),
+        ])
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Already Responded"),
+        )
+        .wrap(Wrap { trim: true });
+
+        f.render_widget(prompt, popup_area);
+    }
+
+    fn draw_overwrite_prompt<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let popup_area = centered_rect(60, 20, area);
+        f.render_widget(Clear, popup_area);
+
+        let (warning, instructions) = if self.queued_overwrites.is_empty() {
+            (
+                "⚠️  A response already exists; edit it instead?",
+                "Press 'y' to replace the existing response with your draft, 'n' or Esc to keep editing",
+            )
+        } else {
+            (
+                "⚠️  This review was answered while your response was queued",
+                "Press 'y' to replace the existing response with the queued one, 'n' or Esc to keep it and drop the queued response",
+            )
+        };
+        let prompt = Paragraph::new(vec![
+            Spans::from(Span::styled(
+                warning,
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            Spans::from(""),
+            Spans::from(Span::styled(instructions, Style::default().fg(Color::Gray))),
+        ])
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Already Responded"),
+        )
+        .wrap(Wrap { trim: true });
+
+        f.render_widget(prompt, popup_area);
+    }
+
+    // One line at the bottom of the screen, so the filtered list stays visible
+    fn draw_search_box<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let height = 3.min(area.height);
+        let box_area = Rect::new(area.x, area.bottom() - height, area.width, height);
+        f.render_widget(Clear, box_area);
 
-                // Response input (smaller since existing response is shown)
-                let input_title = if let Some(limit) = self.get_character_limit() {
-                    format!("⚠️  Update/Replace Response ({}/{} chars - Ctrl+S to submit, Esc to cancel)", 
-                           self.response_text.len(), limit)
-                } else {
-                    "⚠️  Update/Replace Response (Ctrl+S to submit, Esc to cancel)".to_string()
-                };
-                let display_text = self.format_text_with_cursor();
-                let response_input = Paragraph::new(display_text.as_ref())
-                    .block(Block::default().borders(Borders::ALL).title(input_title))
-                    .wrap(Wrap { trim: true });
+        let search = Paragraph::new(Spans::from(format!("/{}█", self.search_query))).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Search nickname, title and text (Enter to keep, Esc to clear)"),
+        );
+        f.render_widget(search, box_area);
+    }
 
-                f.render_widget(response_input, chunks[2]);
-            } else {
-                // No existing response - show larger input area
-                let empty_text = vec![Spans::from(vec![Span::styled(
-                    "✅ No existing response - you can write a new one",
-                    Style::default().fg(Color::Green),
-                )])];
+    fn draw_deletion_prompt<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let popup_area = centered_rect(60, 25, area);
+        f.render_widget(Clear, popup_area);
 
-                let no_response_paragraph = Paragraph::new(empty_text)
-                    .block(
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .title("Response Status"),
-                    )
-                    .wrap(Wrap { trim: true });
+        let response = self
+            .selected_review
+            .and_then(|idx| self.reviews[idx].response.as_ref())
+            .map(|response| snippet(&response.response_body))
+            .unwrap_or_default();
+        let prompt = Paragraph::new(vec![
+            Spans::from(Span::styled(
+                "⚠️  Delete the developer response to this review? (y/n)",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            Spans::from(""),
+            Spans::from(format!("\"{}\"", response)),
+            Spans::from(""),
+            Spans::from(Span::styled(
+                "The reviewer will no longer see it on the App Store. Press 'y' to delete, 'n' or Esc to cancel",
+                Style::default().fg(Color::Gray),
+            )),
+        ])
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Delete Response"),
+        )
+        .wrap(Wrap { trim: true });
 
-                f.render_widget(no_response_paragraph, chunks[1]);
+        f.render_widget(prompt, popup_area);
+    }
 
-                let input_title = match self.input_mode {
-                    InputMode::Manual => {
-                        if let Some(limit) = self.get_character_limit() {
-                            format!("Write Response ({}/{} chars - Ctrl+S to submit, Esc to cancel)", 
-                                   self.response_text.len(), limit)
-                        } else {
-                            "Write Response (Ctrl+S to submit, Esc to cancel)".to_string()
-                        }
-                    },
-                    InputMode::AI => {
-                        if let Some(limit) = self.get_character_limit() {
-                            format!("AI Generated Response ({}/{} chars - Edit if needed, Ctrl+S to submit, Esc to cancel)", 
-                                   self.response_text.len(), limit)
-                        } else {
-                            "AI Generated Response (Edit if needed, Ctrl+S to submit, Esc to cancel)".to_string()
-                        }
-                    }
-                };
+    // Parsed fields above the raw JSON, to spot fields that didn't parse as expected
+    fn draw_previous_version<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let Some(review) = self.selected_review.map(|idx| &self.reviews[idx]) else {
+            return;
+        };
+        let Some(edit) = self.local_state.review_edit(&review.id) else {
+            return;
+        };
+        let popup_area = centered_rect(70, 60, area);
+        f.render_widget(Clear, popup_area);
 
-                let display_text = self.format_text_with_cursor();
-                let response_input = Paragraph::new(display_text.as_ref())
-                    .block(Block::default().borders(Borders::ALL).title(input_title))
-                    .wrap(Wrap { trim: true });
+        let mut lines = vec![
+            Spans::from(Span::styled(
+                format!("Rating: {}", "⭐".repeat(edit.rating.clamp(0, 5) as usize)),
+                Style::default().fg(Color::Yellow),
+            )),
+            Spans::from(Span::styled(
+                edit.title.as_deref().unwrap_or("(No title)").to_string(),
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Spans::from(""),
+        ];
+        lines.extend(
+            edit.text
+                .as_deref()
+                .unwrap_or("(No review text)")
+                .lines()
+                .map(|line| Spans::from(line.to_string())),
+        );
 
-                f.render_widget(response_input, chunks[2]);
-            }
-        }
+        let title = format!(
+            "Before the edit noticed {} (Esc to close)",
+            self.list_date(&edit.detected_at, review)
+        );
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, popup_area);
     }
 
-    fn draw_confirmation_view<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
-        let popup_area = centered_rect(80, 60, area);
+    fn draw_raw_json<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let Some(review) = self.selected_review.map(|idx| &self.reviews[idx]) else {
+            return;
+        };
+        let popup_area = centered_rect(85, 85, area);
         f.render_widget(Clear, popup_area);
 
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(
-                [
-                    Constraint::Length(3),
-                    Constraint::Min(5),
-                    Constraint::Length(3),
-                ]
-                .as_ref(),
-            )
-            .split(popup_area);
+        let heading = Style::default().add_modifier(Modifier::BOLD);
+        let mut lines = vec![
+            Spans::from(Span::styled("Parsed", heading)),
+            Spans::from(format!("id: {}", review.id)),
+            Spans::from(format!("rating: {}", review.rating)),
+            Spans::from(format!("reviewer: {}", review.reviewer_nickname)),
+            Spans::from(format!("created: {}", review.created_date.to_rfc3339())),
+            Spans::from(format!("territory: {}", review.territory)),
+            Spans::from(format!("version: {:?}", review.version)),
+            Spans::from(format!("language: {:?}", review.language)),
+            Spans::from(format!(
+                "response: {}",
+                review
+                    .response
+                    .as_ref()
+                    .map(|response| response.state.label())
+                    .unwrap_or_else(|| "none".to_string())
+            )),
+            Spans::from(""),
+            Spans::from(Span::styled("Raw", heading)),
+        ];
+        let raw = review
+            .raw
+            .as_ref()
+            .and_then(|raw| serde_json::to_string_pretty(raw).ok())
+            .unwrap_or_default();
+        lines.extend(raw.lines().map(|line| Spans::from(line.to_string())));
 
-        // Confirmation prompt
-        let confirmation = Paragraph::new("Submit this response? (y/n)")
+        let paragraph = Paragraph::new(lines)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Confirm Response"),
+                    .title("Review JSON (↑/↓ or PgUp/PgDn to scroll, Esc to close)"),
             )
-            .style(Style::default().add_modifier(Modifier::BOLD));
+            .wrap(Wrap { trim: false })
+            .scroll((self.raw_json_scroll, 0));
+        f.render_widget(paragraph, popup_area);
+    }
 
-        f.render_widget(confirmation, chunks[0]);
+    // The current rate comes from the loaded reviews, so responses sent this
+    // session count straight away. The trend compares it with the newest
+    // snapshot that's at least a week old.
+    fn draw_stats<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let popup_area = centered_rect(60, 60, area);
+        f.render_widget(Clear, popup_area);
+
+        let total = self.reviews.len();
+        let responded = total - self.unresponded_count();
+        let percent = if total == 0 {
+            0.0
+        } else {
+            responded as f64 * 100.0 / total as f64
+        };
+
+        let app_ids = cache_app_ids(&self.api_client);
+        let history = self.local_state.response_rate_history(&app_ids);
+        let week_ago = Utc::now() - chrono::Duration::days(7);
+        let previous = history
+            .iter()
+            .rev()
+            .find(|snapshot| snapshot.taken_at <= week_ago);
+        let trend = match previous {
+            Some(previous) if percent.round() > previous.percent().round() => {
+                format!(", up from {:.0}% last week", previous.percent())
+            }
+            Some(previous) if percent.round() < previous.percent().round() => {
+                format!(", down from {:.0}% last week", previous.percent())
+            }
+            Some(_) => ", the same as last week".to_string(),
+            None => String::new(),
+        };
+
+        let heading = Style::default().add_modifier(Modifier::BOLD);
+        let mut lines = vec![
+            Spans::from(Span::styled(
+                format!("Response rate: {:.0}%{}", percent, trend),
+                heading,
+            )),
+            Spans::from(format!(
+                "{} of {} loaded reviews have a response",
+                responded, total
+            )),
+            Spans::from(""),
+            Spans::from(Span::styled("History", heading)),
+        ];
+        if history.is_empty() {
+            lines.push(Spans::from(
+                "No snapshots yet. One is kept each day reviews are loaded.",
+            ));
+        }
+        for snapshot in history.iter().rev().take(10) {
+            lines.push(Spans::from(format!(
+                "{}  {:.0}% ({}/{})",
+                self.config.display_timezone.format(
+                    &snapshot.taken_at,
+                    "",
+                    &self.config.date_format
+                ),
+                snapshot.percent(),
+                snapshot.responded,
+                snapshot.total
+            )));
+        }
+        lines.push(Spans::from(""));
+        lines.push(Spans::from(Span::styled(
+            "Rates only cover the reviews loaded at the time. Use --fetch-all for every review.",
+            Style::default().fg(Color::Gray),
+        )));
 
-        // Response preview
-        let response_preview = Paragraph::new(self.response_text.as_ref())
+        let paragraph = Paragraph::new(lines)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Response Preview"),
+                    .title("Statistics (Esc to close)"),
             )
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, popup_area);
+    }
+
+    fn draw_activity<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let popup_area = centered_rect(70, 60, area);
+        f.render_widget(Clear, popup_area);
+
+        let items: Vec<ListItem> = self
+            .session_responses
+            .iter()
+            .rev()
+            .map(|entry| {
+                ListItem::new(vec![
+                    Spans::from(vec![
+                        Span::styled(
+                            self.config
+                                .display_timezone
+                                .format(&entry.responded_at, "", "%H:%M"),
+                            Style::default().fg(Color::Gray),
+                        ),
+                        Span::raw(format!(
+                            "  {}{}",
+                            entry.reviewer,
+                            if entry.replaced { " (replaced)" } else { "" }
+                        )),
+                    ]),
+                    Spans::from(Span::styled(
+                        format!("       {}", entry.snippet),
+                        Style::default().fg(Color::Gray),
+                    )),
+                ])
+            })
+            .collect();
+
+        let mut activity_state = ListState::default();
+        activity_state.select(Some(self.activity_selected));
+
+        let activity = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(format!(
+                "Responded this session: {} (Enter to jump to the review, Esc to close)",
+                self.session_responses.len()
+            )))
+            .highlight_style(self.config.highlight_style)
+            .highlight_symbol(&self.config.highlight_symbol);
+        f.render_stateful_widget(activity, popup_area, &mut activity_state);
+    }
+
+    fn draw_note_prompt<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let popup_area = centered_rect(60, 25, area);
+        f.render_widget(Clear, popup_area);
+
+        let prompt = Paragraph::new(vec![
+            Spans::from(format!("{}█", self.note_input)),
+            Spans::from(""),
+            Spans::from(Span::styled(
+                "Only stored on this machine, never sent to the store. Enter to save, Esc to cancel. Save an empty note to delete it.",
+                Style::default().fg(Color::Gray),
+            )),
+        ])
+        .block(Block::default().borders(Borders::ALL).title("Review Note"))
+        .wrap(Wrap { trim: false });
+
+        f.render_widget(prompt, popup_area);
+    }
+
+    // A paragraph rather than a list, so long responses wrap
+    fn draw_canned_responses<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let popup_area = centered_rect(70, 60, area);
+        f.render_widget(Clear, popup_area);
+
+        let indent = " ".repeat(self.config.highlight_symbol.chars().count());
+        let mut lines = Vec::new();
+        for (idx, response) in self.canned_responses.iter().enumerate() {
+            let (marker, style) = if idx == self.canned_selected {
+                (
+                    self.config.highlight_symbol.as_str(),
+                    self.config.highlight_style,
+                )
+            } else {
+                (indent.as_str(), Style::default())
+            };
+            lines.push(Spans::from(Span::styled(
+                format!("{}{}. {}", marker, idx + 1, response),
+                style,
+            )));
+            lines.push(Spans::from(""));
+        }
+
+        let title = "Approved Responses (Enter or 1-9 to review before sending, Esc to cancel)";
+        let prompt = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .wrap(Wrap { trim: false });
+        f.render_widget(prompt, popup_area);
+    }
+
+    fn draw_follow_up_prompt<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let popup_area = centered_rect(60, 25, area);
+        f.render_widget(Clear, popup_area);
+
+        let prompt = Paragraph::new(vec![
+            Spans::from(format!("{}█", self.follow_up_input)),
+            Spans::from(""),
+            Spans::from(Span::styled(
+                "Due date as YYYY-MM-DD or +N days, then an optional note, e.g. \"+7 asked them to email support\". Enter to save, Esc to cancel. Save it empty to remove the reminder.",
+                Style::default().fg(Color::Gray),
+            )),
+        ])
+        .block(Block::default().borders(Borders::ALL).title("Follow-up Reminder"))
+        .wrap(Wrap { trim: false });
+
+        f.render_widget(prompt, popup_area);
+    }
+
+    fn draw_follow_ups<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let popup_area = centered_rect(70, 60, area);
+        f.render_widget(Clear, popup_area);
+
+        let today = Local::now().date_naive();
+        let follow_ups = self.local_state.follow_ups_by_due();
+        let items: Vec<ListItem> = follow_ups
+            .iter()
+            .map(|(_, follow_up)| {
+                let due = follow_up.due <= today;
+                ListItem::new(vec![
+                    Spans::from(vec![
+                        Span::styled(
+                            follow_up.due.format(&self.config.date_format).to_string(),
+                            Style::default().fg(if due { Color::Red } else { Color::Gray }),
+                        ),
+                        Span::raw(format!(
+                            "  {}{}",
+                            follow_up.reviewer,
+                            if follow_up.note.is_empty() {
+                                String::new()
+                            } else {
+                                format!(": {}", follow_up.note)
+                            }
+                        )),
+                    ]),
+                    Spans::from(Span::styled(
+                        format!("       {}", follow_up.snippet),
+                        Style::default().fg(Color::Gray),
+                    )),
+                ])
+            })
+            .collect();
+
+        let mut follow_ups_state = ListState::default();
+        follow_ups_state.select(Some(self.follow_ups_selected));
+
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(format!(
+                "Follow-ups: {} due of {} (Enter to jump to the review, 'x' when done, Esc to close)",
+                self.local_state.due_follow_up_count(today),
+                follow_ups.len()
+            )))
+            .highlight_style(self.config.highlight_style)
+            .highlight_symbol(&self.config.highlight_symbol);
+        f.render_stateful_widget(list, popup_area, &mut follow_ups_state);
+    }
+
+    fn draw_skip_prompt<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let popup_area = centered_rect(50, 40, area);
+        f.render_widget(Clear, popup_area);
+
+        let mut lines = vec![
+            Spans::from(Span::styled(
+                "Skip this review to handle it later. Why?",
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Spans::from(""),
+        ];
+        for (i, reason) in SKIP_REASONS.iter().enumerate() {
+            lines.push(Spans::from(format!("{} - {}", i + 1, reason)));
+        }
+        lines.push(Spans::from("0 - Clear skip reason"));
+        lines.push(Spans::from(""));
+        lines.push(Spans::from(Span::styled(
+            "Esc to cancel",
+            Style::default().fg(Color::Gray),
+        )));
+
+        let prompt = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title("Skip Review"))
             .wrap(Wrap { trim: true });
 
-        f.render_widget(response_preview, chunks[1]);
+        f.render_widget(prompt, popup_area);
+    }
 
-        // Instructions
-        let instructions = Paragraph::new("Press 'y' to submit, 'n' or Esc to go back")
-            .block(Block::default().borders(Borders::ALL))
-            .style(Style::default().fg(Color::Gray));
+    fn draw_submit_failed_prompt<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let popup_area = centered_rect(60, 30, area);
+        f.render_widget(Clear, popup_area);
 
-        f.render_widget(instructions, chunks[2]);
+        let prompt = Paragraph::new(vec![
+            Spans::from(Span::styled(
+                format!(
+                    "Failed to submit response: {}",
+                    self.submit_error.as_deref().unwrap_or("unknown error")
+                ),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            Spans::from(""),
+            Spans::from(format!(
+                "Press 'e' to email the review and your draft to {}",
+                self.support_email()
+            )),
+            Spans::from("Press 'b' to go back to editing, Esc to discard the draft"),
+        ])
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Submission Failed"),
+        )
+        .wrap(Wrap { trim: true });
+
+        f.render_widget(prompt, popup_area);
     }
 
     fn draw_loading_view<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
         let popup_area = centered_rect(40, 20, area);
         f.render_widget(Clear, popup_area);
 
-        let loading_text = Paragraph::new("Generating AI response...")
+        let loading_text = Paragraph::new(self.loading_message)
             .block(Block::default().borders(Borders::ALL).title("Please Wait"))
             .style(Style::default().add_modifier(Modifier::BOLD));
 
@@ -915,7 +4825,73 @@ impl ReviewUI {
 enum UIAction {
     Quit,
     Refresh,
+    // Re-fetch every page even with --quick-refresh
+    HardRefresh,
     LoadMore,
+    GenerateAI,
+    AdjustTone(ToneAdjustment),
+    // Hand the draft to $VISUAL/$EDITOR
+    ExternalEditor,
+}
+
+// One line of --fetch-all progress. With a known total (iOS) it's a bar with an
+// ETA from the average page time so far, otherwise a count and elapsed time.
+fn fetch_progress(
+    fetched: usize,
+    total: Option<usize>,
+    fetched_in_pages: usize,
+    pages: u32,
+    elapsed: Duration,
+) -> String {
+    const BAR_WIDTH: usize = 30;
+    let Some(total) = total.filter(|&total| total > 0) else {
+        return format!(
+            "Fetched {} reviews in {} (Esc to stop)",
+            fetched,
+            format_duration(elapsed)
+        );
+    };
+
+    let filled = (fetched * BAR_WIDTH / total).min(BAR_WIDTH);
+    let eta = if pages == 0 || fetched_in_pages == 0 {
+        "estimating".to_string()
+    } else {
+        let per_page = elapsed / pages;
+        let page_size = fetched_in_pages.div_ceil(pages as usize);
+        let remaining_pages = total.saturating_sub(fetched).div_ceil(page_size);
+        format!("ETA {}", format_duration(per_page * remaining_pages as u32))
+    };
+    format!(
+        "[{}{}] {}/{} reviews ({}%), {} (Esc to stop)",
+        "#".repeat(filled),
+        "-".repeat(BAR_WIDTH - filled),
+        fetched,
+        total,
+        (fetched * 100 / total).min(100),
+        eta
+    )
+}
+
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 60 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+// Drains pending terminal events, looking for Esc or Ctrl+C
+fn esc_pressed() -> Result<bool> {
+    let mut pressed = false;
+    while event::poll(Duration::ZERO)? {
+        if let Event::Key(key) = event::read()? {
+            pressed |= key.code == KeyCode::Esc
+                || (key.code == KeyCode::Char('c')
+                    && key.modifiers.contains(KeyModifiers::CONTROL));
+        }
+    }
+    Ok(pressed)
 }
 
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
@@ -937,3 +4913,428 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         ])
         .split(popup_layout[1])[1]
 }
+
+// Scores `candidate` against a fuzzy `query`: every query character must
+// appear in order. Consecutive matches and matches at word starts score higher.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut prev_match: Option<usize> = None;
+
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let idx = (pos..candidate.len()).find(|&i| candidate[i] == q)?;
+
+        score += 1;
+        if idx > 0 && prev_match == Some(idx - 1) {
+            score += 5;
+        }
+        if idx == 0 || !candidate[idx - 1].is_alphanumeric() {
+            score += 3;
+        }
+
+        prev_match = Some(idx);
+        pos = idx + 1;
+    }
+
+    Some(score)
+}
+
+// Case-insensitive, like the search filter. `query` is already lowercase.
+fn matches_search(review: &Review, query: &str) -> bool {
+    query.is_empty()
+        || [
+            Some(review.reviewer_nickname.as_str()),
+            review.title.as_deref(),
+            review.body.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|field| field.to_lowercase().contains(query))
+}
+
+// Spans for `text` with every match of the lowercase `query` highlighted.
+// Text where lowercasing changes a character's length is left plain, since
+// the match offsets wouldn't line up with the original.
+fn highlight_matches(text: &str, query: &str, style: Style) -> Vec<Span<'static>> {
+    let aligned = text.chars().all(|c| {
+        let mut lower = c.to_lowercase();
+        matches!((lower.next(), lower.next()), (Some(l), None) if l.len_utf8() == c.len_utf8())
+    });
+    if query.is_empty() || !aligned {
+        return vec![Span::styled(text.to_string(), style)];
+    }
+
+    let lower = text.to_lowercase();
+    let mut spans = Vec::new();
+    let mut last = 0;
+    for (start, matched) in lower.match_indices(query) {
+        let end = start + matched.len();
+        spans.push(Span::styled(text[last..start].to_string(), style));
+        spans.push(Span::styled(
+            text[start..end].to_string(),
+            style.patch(SEARCH_MATCH_STYLE),
+        ));
+        last = end;
+    }
+    spans.push(Span::styled(text[last..].to_string(), style));
+    spans
+}
+
+fn expand_hint(length: usize) -> Spans<'static> {
+    Spans::from(vec![Span::styled(
+        format!("Press Space to show all {} characters", length),
+        Style::default().fg(Color::DarkGray),
+    )])
+}
+
+// The start of `text` on one line
+fn snippet(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match text.char_indices().nth(SNIPPET_CHARS) {
+        Some((cut, _)) => format!("{}…", &text[..cut]),
+        None => text,
+    }
+}
+
+// Clusters like "\r\n" are whitespace too
+fn is_whitespace_grapheme(grapheme: &str) -> bool {
+    grapheme.chars().all(char::is_whitespace)
+}
+
+// The editor command may carry arguments, e.g. EDITOR="code --wait"
+fn run_external_editor(path: &Path) -> Result<()> {
+    let editor = ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or("vi");
+
+    let status = std::process::Command::new(program)
+        .args(words)
+        .arg(path)
+        .status()
+        .map_err(|e| anyhow!("Failed to start the editor '{}': {}", editor, e))?;
+    if !status.success() {
+        return Err(anyhow!(
+            "The editor '{}' exited with {}, so the draft wasn't changed",
+            editor,
+            status
+        ));
+    }
+    Ok(())
+}
+
+// The apps a review cache or queued response belongs to
+fn cache_app_ids(api_client: &ApiClient) -> Vec<String> {
+    api_client
+        .configs()
+        .iter()
+        .map(|config| config.app_id.clone())
+        .collect()
+}
+
+// The nickname reviews are grouped by. Placeholder names for anonymous
+// reviewers would lump unrelated people together, so they're never grouped.
+fn reviewer_key(review: &Review) -> Option<&str> {
+    let nickname = review.reviewer_nickname.trim();
+    match nickname {
+        "" | "Anonymous" | "A Google user" => None,
+        _ => Some(nickname),
+    }
+}
+
+fn is_pending(review: &Review) -> bool {
+    matches!(
+        review.response.as_ref().map(|response| &response.state),
+        Some(ResponseState::Pending)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FAMILY: &str = "👨\u{200d}👩\u{200d}👧";
+
+    // A demo-mode UI with the editor open on the first demo review
+    async fn editor(extra_args: &[&str]) -> ReviewUI {
+        let state_file =
+            std::env::temp_dir().join(format!("rustpond-test-{}.json", Uuid::new_v4()));
+        let args = [
+            "rustpond",
+            "--reviews-file",
+            concat!(env!("CARGO_MANIFEST_DIR"), "/demo/reviews.json"),
+            "--state-file",
+            state_file.to_str().unwrap(),
+        ];
+        let matches = crate::build_cli().get_matches_from(args.iter().chain(extra_args).copied());
+        let config = Config::from_args(&matches, &|_| None).unwrap();
+        let mut ui = ReviewUI::new(config.clone(), ApiClient::new(config), false)
+            .await
+            .unwrap();
+        fs::remove_file(&state_file).ok();
+        ui.state = AppState::WritingResponse;
+        ui
+    }
+
+    async fn press(ui: &mut ReviewUI, code: KeyCode) {
+        ui.handle_input(KeyEvent::new(code, KeyModifiers::NONE))
+            .await
+            .unwrap();
+    }
+
+    async fn type_text(ui: &mut ReviewUI, typed: &str) {
+        for c in typed.chars() {
+            press(ui, KeyCode::Char(c)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn arrows_move_over_a_zwj_family_emoji_as_one() {
+        let mut ui = editor(&[]).await;
+        ui.paste(&format!("a{}b", FAMILY));
+        let end = ui.response_text.len();
+
+        press(&mut ui, KeyCode::Left).await;
+        assert_eq!(ui.cursor_position, end - 1);
+        press(&mut ui, KeyCode::Left).await;
+        assert_eq!(ui.cursor_position, 1);
+        assert_eq!(ui.format_text_with_cursor(), format!("a█{}b", FAMILY));
+        press(&mut ui, KeyCode::Right).await;
+        assert_eq!(ui.cursor_position, end - 1);
+    }
+
+    #[tokio::test]
+    async fn backspace_deletes_a_whole_zwj_family_emoji() {
+        let mut ui = editor(&[]).await;
+        ui.paste(&format!("a{}b", FAMILY));
+        press(&mut ui, KeyCode::Left).await;
+
+        press(&mut ui, KeyCode::Backspace).await;
+        assert_eq!(ui.response_text, "ab");
+        assert_eq!(ui.cursor_position, 1);
+    }
+
+    #[tokio::test]
+    async fn combining_accent_moves_and_deletes_with_its_letter() {
+        let mut ui = editor(&[]).await;
+        type_text(&mut ui, "cafe\u{301}!").await;
+
+        press(&mut ui, KeyCode::Left).await;
+        press(&mut ui, KeyCode::Left).await;
+        assert_eq!(ui.cursor_position, 3);
+        press(&mut ui, KeyCode::Delete).await;
+        assert_eq!(ui.response_text, "caf!");
+
+        type_text(&mut ui, "e\u{301}").await;
+        assert_eq!(ui.response_text, "cafe\u{301}!");
+        assert_eq!(ui.cursor_position, 6);
+        press(&mut ui, KeyCode::Backspace).await;
+        assert_eq!(ui.response_text, "caf!");
+        assert_eq!(ui.cursor_position, 3);
+    }
+
+    #[tokio::test]
+    async fn cursor_before_a_typed_combining_mark_is_drawn_before_its_cluster() {
+        let mut ui = editor(&[]).await;
+        type_text(&mut ui, "e\u{301}").await;
+        ui.cursor_position = 1;
+        assert_eq!(ui.format_text_with_cursor(), "█e\u{301}");
+    }
+
+    #[tokio::test]
+    async fn typed_emoji_is_crossed_and_deleted_whole() {
+        let mut ui = editor(&[]).await;
+        type_text(&mut ui, "a😀b").await;
+        assert_eq!(ui.cursor_position, 6);
+
+        press(&mut ui, KeyCode::Left).await;
+        press(&mut ui, KeyCode::Left).await;
+        assert_eq!(ui.cursor_position, 1);
+        press(&mut ui, KeyCode::Right).await;
+        assert_eq!(ui.cursor_position, 5);
+
+        press(&mut ui, KeyCode::Backspace).await;
+        assert_eq!(ui.response_text, "ab");
+        assert_eq!(ui.cursor_position, 1);
+    }
+
+    // 300 accented letters are 600 bytes in NFC and 900 in NFD, both well
+    // past 350 bytes but under Google Play's 350 characters
+    async fn edit_300_accented_letters(letter: &str) {
+        let mut ui = editor(&["--android"]).await;
+        ui.paste(&letter.repeat(300));
+        assert_eq!(ui.cursor_position, letter.len() * 300);
+
+        for _ in 0..150 {
+            press(&mut ui, KeyCode::Left).await;
+        }
+        assert_eq!(ui.cursor_position, letter.len() * 150);
+        press(&mut ui, KeyCode::Char('x')).await;
+        assert_eq!(ui.cursor_position, letter.len() * 150 + 1);
+        assert_eq!(
+            ui.response_text,
+            format!("{}x{}", letter.repeat(150), letter.repeat(150))
+        );
+        assert_eq!(ui.character_budget_label().unwrap(), "301/350 chars");
+
+        for _ in 0..150 {
+            press(&mut ui, KeyCode::Right).await;
+        }
+        assert_eq!(ui.cursor_position, ui.response_text.len());
+        press(&mut ui, KeyCode::Backspace).await;
+        assert_eq!(ui.cursor_position, letter.len() * 299 + 1);
+        assert_eq!(ui.response_text.len(), ui.cursor_position);
+        assert_eq!(ui.character_budget_label().unwrap(), "300/350 chars");
+    }
+
+    #[tokio::test]
+    async fn android_reply_of_300_nfc_accented_letters_stays_editable() {
+        edit_300_accented_letters("\u{e9}").await;
+    }
+
+    #[tokio::test]
+    async fn android_reply_of_300_nfd_accented_letters_stays_editable() {
+        edit_300_accented_letters("e\u{301}").await;
+    }
+
+    fn search_review(nickname: &str, title: &str, body: &str) -> Review {
+        serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "rating": 3,
+            "title": title,
+            "body": body,
+            "reviewer_nickname": nickname,
+            "created_date": "2024-01-01T00:00:00Z",
+            "territory": "USA",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn search_matches_nickname_title_and_body_ignoring_case() {
+        let review = search_review("AppFan42", "Great Update", "Sync is FAST now");
+        for query in ["", "appfan", "great update", "fast"] {
+            assert!(matches_search(&review, query), "{}", query);
+        }
+        assert!(!matches_search(&review, "slow"));
+        // Queries are lowercased by the caller
+        assert!(!matches_search(&review, "FAST"));
+    }
+
+    fn span_texts(spans: &[Span]) -> Vec<(String, bool)> {
+        spans
+            .iter()
+            .map(|span| (span.content.to_string(), span.style == SEARCH_MATCH_STYLE))
+            .collect()
+    }
+
+    #[test]
+    fn highlight_marks_every_match_keeping_original_case() {
+        let spans = highlight_matches("Fast app, so FAST", "fast", Style::default());
+        assert_eq!(
+            span_texts(&spans),
+            [
+                (String::new(), false),
+                ("Fast".to_string(), true),
+                (" app, so ".to_string(), false),
+                ("FAST".to_string(), true),
+                (String::new(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_without_a_query_is_plain() {
+        let spans = highlight_matches("Fast app", "", Style::default());
+        assert_eq!(span_texts(&spans), [("Fast app".to_string(), false)]);
+    }
+
+    #[test]
+    fn highlight_leaves_text_plain_when_lowercasing_changes_its_length() {
+        // 'İ' lowercases to two characters and 'ẞ' to a shorter 'ß', so the
+        // offsets in the lowercase text would split or miss characters
+        for text in ["İstanbul is fast", "GROẞ and fast"] {
+            let spans = highlight_matches(text, "fast", Style::default());
+            assert_eq!(span_texts(&spans), [(text.to_string(), false)], "{}", text);
+        }
+    }
+
+    #[tokio::test]
+    async fn offline_response_to_an_answered_review_is_queued_as_a_replacement() {
+        // --offline can't be combined with --reviews-file on the command line
+        let mut ui = editor(&[]).await;
+        ui.config.offline = true;
+        for review_id in ["demo-1", "demo-3"] {
+            let idx = ui.reviews.iter().position(|r| r.id == review_id).unwrap();
+            ui.select_review(Some(idx));
+            ui.response_text = "Thanks!".to_string();
+            ui.submit(false).await;
+        }
+        if let Some(path) = &ui.config.state_path {
+            fs::remove_file(path).ok();
+        }
+
+        let queued: Vec<(&str, bool)> = ui
+            .local_state
+            .queued_responses
+            .iter()
+            .map(|queued| (queued.review_id.as_str(), queued.replace))
+            .collect();
+        assert_eq!(queued, [("demo-1", false), ("demo-3", true)]);
+    }
+
+    #[tokio::test]
+    async fn background_refresh_keeps_responses_changed_since_it_started() {
+        let mut ui = editor(&[]).await;
+        ui.state = AppState::ViewingReviews;
+        let idx = |ui: &ReviewUI, id: &str| ui.reviews.iter().position(|r| r.id == id).unwrap();
+        let mut fetched = ui.reviews.clone();
+        let answered_elsewhere = ReviewResponse {
+            id: "elsewhere".to_string(),
+            response_body: "Answered in App Store Connect".to_string(),
+            last_modified_date: Utc::now(),
+            state: ResponseState::Published,
+        };
+        fetched[idx(&ui, "demo-2")].response = Some(answered_elsewhere.clone());
+
+        ui.responses_before_refresh = ui
+            .reviews
+            .iter()
+            .map(|review| (review.id.clone(), review.response.clone()))
+            .collect();
+        let api_client = ui.api_client.clone();
+        ui.background_refresh = Some(tokio::spawn(async move { (api_client, Ok(fetched)) }));
+
+        // Submitted and deleted while the page was loading
+        let submitted = ReviewResponse {
+            id: String::new(),
+            response_body: "Thanks!".to_string(),
+            last_modified_date: Utc::now(),
+            state: ResponseState::Pending,
+        };
+        let demo_1 = idx(&ui, "demo-1");
+        ui.reviews[demo_1].response = Some(submitted.clone());
+        let demo_3 = idx(&ui, "demo-3");
+        ui.reviews[demo_3].response = None;
+
+        while !ui.background_refresh.as_ref().unwrap().is_finished() {
+            tokio::task::yield_now().await;
+        }
+        ui.finish_background_refresh().await;
+        if let Some(path) = &ui.config.state_path {
+            fs::remove_file(path).ok();
+        }
+
+        assert!(ui.background_refresh.is_none());
+        assert_eq!(ui.reviews[idx(&ui, "demo-1")].response, Some(submitted));
+        assert_eq!(ui.reviews[idx(&ui, "demo-3")].response, None);
+        assert_eq!(
+            ui.reviews[idx(&ui, "demo-2")].response,
+            Some(answered_elsewhere)
+        );
+    }
+}